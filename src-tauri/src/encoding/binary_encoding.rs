@@ -0,0 +1,252 @@
+use base64::engine::general_purpose::{STANDARD, URL_SAFE, URL_SAFE_NO_PAD};
+use base64::{DecodeError, Engine};
+use data_encoding::{DecodeKind, BASE32, HEXLOWER, HEXLOWER_PERMISSIVE};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize, PartialEq, Clone, Copy)]
+pub enum BinaryEncoding {
+    Base64,
+    Base64Url,
+    Base64UrlNoPad,
+    Base32,
+    Base58,
+    Hex,
+}
+
+/// Data to encode, either text (encoded as UTF-8) or raw bytes
+#[derive(Debug, Deserialize, Serialize)]
+pub enum DataInput {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+impl DataInput {
+    pub fn into_bytes(self) -> Vec<u8> {
+        match self {
+            DataInput::Text(text) => text.into_bytes(),
+            DataInput::Bytes(bytes) => bytes,
+        }
+    }
+}
+
+/// Decoded bytes along with their text representation when they are valid UTF-8
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DecodedData {
+    pub bytes: Vec<u8>,
+    pub text: Option<String>,
+}
+
+impl From<Vec<u8>> for DecodedData {
+    fn from(bytes: Vec<u8>) -> Self {
+        let text = String::from_utf8(bytes.clone()).ok();
+        DecodedData { bytes, text }
+    }
+}
+
+impl BinaryEncoding {
+    pub const ALL: [BinaryEncoding; 6] = [
+        BinaryEncoding::Base64,
+        BinaryEncoding::Base64Url,
+        BinaryEncoding::Base64UrlNoPad,
+        BinaryEncoding::Base32,
+        BinaryEncoding::Base58,
+        BinaryEncoding::Hex,
+    ];
+
+    /// The human readable name of the encoding used in error messages, e.g. "Base64URL"
+    pub fn name(&self) -> &'static str {
+        match self {
+            BinaryEncoding::Base64 => "Base64",
+            BinaryEncoding::Base64Url => "Base64URL",
+            BinaryEncoding::Base64UrlNoPad => "Base64URL (no padding)",
+            BinaryEncoding::Base32 => "Base32",
+            BinaryEncoding::Base58 => "Base58",
+            BinaryEncoding::Hex => "Hex",
+        }
+    }
+
+    pub fn encode(&self, bytes: &[u8]) -> String {
+        match self {
+            BinaryEncoding::Base64 => STANDARD.encode(bytes),
+            BinaryEncoding::Base64Url => URL_SAFE.encode(bytes),
+            BinaryEncoding::Base64UrlNoPad => URL_SAFE_NO_PAD.encode(bytes),
+            BinaryEncoding::Base32 => BASE32.encode(bytes),
+            BinaryEncoding::Base58 => bs58::encode(bytes).into_string(),
+            BinaryEncoding::Hex => HEXLOWER.encode(bytes),
+        }
+    }
+
+    /// Decodes `input` into bytes.
+    ///
+    /// Whitespace (e.g. line breaks in wrapped Base64) is ignored, Base32 and hex are case
+    /// insensitive and hex may be prefixed with "0x". Positions in error messages refer to
+    /// the input with whitespace removed.
+    pub fn decode(&self, input: &str) -> Result<Vec<u8>, String> {
+        let input = input.split_ascii_whitespace().collect::<String>();
+        let name = self.name();
+
+        if input.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if let Some((position, character)) = input.char_indices().find(|(_, c)| !c.is_ascii()) {
+            return Err(invalid_character(name, character, position));
+        }
+
+        match self {
+            BinaryEncoding::Base64 => decode_base64(&STANDARD, name, &input),
+            BinaryEncoding::Base64Url => decode_base64(&URL_SAFE, name, &input),
+            BinaryEncoding::Base64UrlNoPad => {
+                if let Some(position) = input.find('=') {
+                    return Err(format!(
+                        "Unexpected '=' padding at position {}. {} must not be padded",
+                        position, name
+                    ));
+                }
+                decode_base64(&URL_SAFE_NO_PAD, name, &input)
+            }
+            BinaryEncoding::Base32 => BASE32
+                .decode(input.to_ascii_uppercase().as_bytes())
+                .map_err(|e| data_encoding_error(name, &input, e)),
+            BinaryEncoding::Base58 => bs58::decode(&input).into_vec().map_err(|e| match e {
+                bs58::decode::Error::InvalidCharacter { character, index } => {
+                    invalid_character(name, character, index)
+                }
+                e => format!("Invalid {}: {}", name, e),
+            }),
+            BinaryEncoding::Hex => {
+                let hex = input
+                    .strip_prefix("0x")
+                    .or_else(|| input.strip_prefix("0X"))
+                    .unwrap_or(&input);
+                HEXLOWER_PERMISSIVE
+                    .decode(hex.as_bytes())
+                    .map_err(|e| data_encoding_error(name, hex, e))
+            }
+        }
+    }
+}
+
+fn decode_base64(engine: &impl Engine, name: &str, input: &str) -> Result<Vec<u8>, String> {
+    engine.decode(input).map_err(|e| match e {
+        DecodeError::InvalidByte(position, byte) => invalid_character(name, byte as char, position),
+        DecodeError::InvalidLength(length) => format!(
+            "Invalid {} length. {} characters cannot be decoded, check the input is complete",
+            name, length
+        ),
+        DecodeError::InvalidLastSymbol(position, byte) => format!(
+            "Invalid {} character '{}' at position {}. The input has non-zero trailing bits",
+            name, byte as char, position
+        ),
+        DecodeError::InvalidPadding => format!(
+            "Invalid {} padding. The input must be padded with '=' to a multiple of 4 characters",
+            name
+        ),
+    })
+}
+
+fn data_encoding_error(name: &str, input: &str, error: data_encoding::DecodeError) -> String {
+    let position = error.position;
+
+    match error.kind {
+        DecodeKind::Symbol => match input[position..].chars().next() {
+            Some(character) => invalid_character(name, character, position),
+            None => format!("Invalid {} input at position {}", name, position),
+        },
+        DecodeKind::Length => format!(
+            "Invalid {} length. {} characters cannot be decoded, check the input is complete",
+            name,
+            input.len()
+        ),
+        DecodeKind::Trailing => format!(
+            "Invalid {} character at position {}. The input has non-zero trailing bits",
+            name, position
+        ),
+        DecodeKind::Padding => format!("Invalid {} padding at position {}", name, position),
+    }
+}
+
+fn invalid_character(name: &str, character: char, position: usize) -> String {
+    format!(
+        "Invalid {} character '{}' at position {}",
+        name, character, position
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_and_decode_round_trip() {
+        let cases = [
+            (BinaryEncoding::Base64, "aGk/Pz8+Pg=="),
+            (BinaryEncoding::Base64Url, "aGk_Pz8-Pg=="),
+            (BinaryEncoding::Base64UrlNoPad, "aGk_Pz8-Pg"),
+            (BinaryEncoding::Base32, "NBUT6PZ7HY7A===="),
+            (BinaryEncoding::Base58, "4xVPJ1JiCu"),
+            (BinaryEncoding::Hex, "68693f3f3f3e3e"),
+        ];
+
+        for (encoding, encoded) in cases {
+            assert_eq!(encoding.encode(b"hi???>>"), encoded, "{:?}", encoding);
+            assert_eq!(
+                encoding.decode(encoded).unwrap(),
+                b"hi???>>",
+                "{:?}",
+                encoding
+            );
+        }
+    }
+
+    #[test]
+    fn test_decode_is_lenient_with_whitespace_case_and_prefix() {
+        assert_eq!(
+            BinaryEncoding::Base64.decode("aGVs\nbG8=\n").unwrap(),
+            b"hello"
+        );
+        assert_eq!(BinaryEncoding::Base32.decode("nbswy3dp").unwrap(), b"hello");
+        assert_eq!(
+            BinaryEncoding::Hex.decode("0x68 65 6C 6C 6F").unwrap(),
+            b"hello"
+        );
+    }
+
+    #[test]
+    fn test_decode_reports_invalid_characters() {
+        assert_eq!(
+            BinaryEncoding::Base64.decode("aGk_Pz8-Pg==").unwrap_err(),
+            "Invalid Base64 character '_' at position 3"
+        );
+        assert_eq!(
+            BinaryEncoding::Base58.decode("4fv0").unwrap_err(),
+            "Invalid Base58 character '0' at position 3"
+        );
+        assert_eq!(
+            BinaryEncoding::Hex.decode("68zz").unwrap_err(),
+            "Invalid Hex character 'z' at position 2"
+        );
+        assert_eq!(
+            BinaryEncoding::Base32.decode("NBSWé").unwrap_err(),
+            "Invalid Base32 character 'é' at position 4"
+        );
+    }
+
+    #[test]
+    fn test_decode_reports_invalid_padding_and_length() {
+        assert_eq!(
+            BinaryEncoding::Base64Url.decode("aGk_Pz8-Pg").unwrap_err(),
+            "Invalid Base64URL padding. The input must be padded with '=' to a multiple of 4 characters"
+        );
+        assert_eq!(
+            BinaryEncoding::Base64UrlNoPad
+                .decode("aGk_Pz8-Pg==")
+                .unwrap_err(),
+            "Unexpected '=' padding at position 10. Base64URL (no padding) must not be padded"
+        );
+        assert_eq!(
+            BinaryEncoding::Hex.decode("686").unwrap_err(),
+            "Invalid Hex length. 3 characters cannot be decoded, check the input is complete"
+        );
+    }
+}
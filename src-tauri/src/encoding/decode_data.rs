@@ -0,0 +1,48 @@
+use crate::encoding::{BinaryEncoding, DecodedData};
+
+/// Decodes a string encoded with a binary-to-text encoding.
+///
+/// The decoded bytes are always returned, along with their text representation when they
+/// are valid UTF-8. Whitespace in the input is ignored.
+///
+/// # Arguments
+/// * `input` - The encoded string
+/// * `encoding` - The encoding the input uses
+///
+/// # Returns
+/// * `Ok(DecodedData)` - The decoded bytes and text
+/// * `Err(String)` - An error message describing the invalid character, padding or length
+///
+/// ```
+#[tauri::command]
+pub fn decode_data(input: &str, encoding: BinaryEncoding) -> Result<DecodedData, String> {
+    encoding.decode(input).map(DecodedData::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decodes_text() {
+        let decoded = decode_data("aGVsbG8=", BinaryEncoding::Base64).unwrap();
+        assert_eq!(decoded.bytes, b"hello");
+        assert_eq!(decoded.text.as_deref(), Some("hello"));
+    }
+
+    #[test]
+    fn test_decodes_bytes_that_are_not_text() {
+        let decoded = decode_data("ff00fe", BinaryEncoding::Hex).unwrap();
+        assert_eq!(decoded.bytes, vec![0xff, 0x00, 0xfe]);
+        assert_eq!(decoded.text, None);
+    }
+
+    #[test]
+    fn test_given_invalid_input_returns_error() {
+        let result = decode_data("NBSWY3D!", BinaryEncoding::Base32);
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid Base32 character '!' at position 7"
+        );
+    }
+}
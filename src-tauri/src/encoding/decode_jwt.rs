@@ -1,49 +1,91 @@
-use jwt::header::HeaderContentType;
-use jwt::{AlgorithmType, Claims, Header, Token, Unverified};
+use crate::encoding::is_jwe;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jwt::{AlgorithmType, Token, Unverified};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
+/// The JOSE header of a token. `alg`, `typ` and `kid` are read for convenience while every
+/// other parameter (e.g. `cty`, `x5t`, `jku`, `crit` or custom parameters) is kept as sent,
+/// in the order it was sent.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DecodedJwtHeader {
-    alg: AlgorithmType,
-    typ: HeaderContentType,
+    /// The algorithm as sent, including ones that cannot be verified like `EdDSA`
+    pub alg: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub typ: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    #[serde(flatten)]
+    pub parameters: Map<String, Value>,
+}
+
+impl DecodedJwtHeader {
+    /// The algorithm of the token, or `None` if tokens signed with it cannot be verified
+    pub fn algorithm(&self) -> Option<AlgorithmType> {
+        serde_json::from_value(Value::String(self.alg.clone())).ok()
+    }
+}
+
+/// The raw base64url encoded segments of a token
+#[derive(Debug, Deserialize, Serialize)]
+pub struct JwtSegments {
+    pub header: String,
+    pub claims: String,
+    pub signature: String,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DecodedJwt {
-    header: DecodedJwtHeader,
-    claims: Claims,
+    pub header: DecodedJwtHeader,
+    /// The claims exactly as sent, e.g. `aud` may be a string or an array and dates may
+    /// have fractions of a second
+    pub claims: Map<String, Value>,
+    pub segments: JwtSegments,
+    pub signature: Vec<u8>,
 }
 
 /// Decodes a JWT token without verifying its signature.
 ///
-/// This function parses a JWT token string and extracts its header and claims
-/// without validating the signature. It's useful for inspecting token contents
-/// but should not be used for authentication purposes.
+/// This function parses a JWT token string and extracts its full header, claims,
+/// raw segments and signature bytes without validating the signature. It's useful
+/// for inspecting token contents but should not be used for authentication purposes.
 ///
 /// # Arguments
 /// * `token` - A string slice containing the JWT token to decode
 ///
 /// # Returns
-/// * `Ok(DecodedJwt)` - A struct containing the decoded header and claims
+/// * `Ok(DecodedJwt)` - A struct containing the decoded header, claims, segments and signature
 /// * `Err(String)` - An error message if decoding fails
 ///
 /// ```
 #[tauri::command]
 pub fn decode_jwt(token: &str) -> Result<DecodedJwt, String> {
-    let parsed_token = Token::<Header, Claims, Unverified>::parse_unverified(token)
-        .map_err(|e| format!("Failed to decode JWT token. Please check that the token is valid and properly formatted. Error: {}", e.to_string()))?;
+    if is_jwe(token) {
+        return Err("This token is an encrypted JWT (JWE). Inspect its header or decrypt it with a key instead".to_string());
+    }
+
+    let parsed_token = Token::<DecodedJwtHeader, Map<String, Value>, Unverified>::parse_unverified(token)
+        .map_err(|e| format!("Failed to decode JWT token. Please check that the token is valid and properly formatted. Error: {}", e))?;
+
+    // Parsing guarantees the token has exactly three segments
+    let segments = token.split('.').collect::<Vec<&str>>();
 
-    let header = DecodedJwtHeader {
-        alg: parsed_token.header().algorithm.into(),
-        typ: parsed_token
-            .header()
-            .content_type
-            .unwrap_or(HeaderContentType::JsonWebToken),
-    };
+    let signature = URL_SAFE_NO_PAD
+        .decode(segments[2])
+        .map_err(|e| format!("Failed to decode JWT signature: {}", e))?;
+
+    let (header, claims) = parsed_token.into();
 
     Ok(DecodedJwt {
         header,
-        claims: parsed_token.claims().clone(),
+        claims,
+        segments: JwtSegments {
+            header: segments[0].to_string(),
+            claims: segments[1].to_string(),
+            signature: segments[2].to_string(),
+        },
+        signature,
     })
 }
 
@@ -51,8 +93,9 @@ pub fn decode_jwt(token: &str) -> Result<DecodedJwt, String> {
 mod tests {
     use super::*;
     use hmac::{Hmac, Mac};
-    use jwt::header::HeaderType;
-    use jwt::{Header, RegisteredClaims, SignWithKey, Token};
+    use jwt::header::{HeaderContentType, HeaderType};
+    use jwt::{Claims, Header, RegisteredClaims, SignWithKey, Token};
+    use serde_json::json;
     use sha2::Sha256;
     use std::collections::BTreeMap;
 
@@ -90,6 +133,15 @@ mod tests {
             .to_string()
     }
 
+    fn create_unsigned_token(header: &str, claims: &str) -> String {
+        format!(
+            "{}.{}.{}",
+            URL_SAFE_NO_PAD.encode(header),
+            URL_SAFE_NO_PAD.encode(claims),
+            URL_SAFE_NO_PAD.encode(b"signature")
+        )
+    }
+
     #[test]
     fn test_given_an_invalid_token_returns_error() {
         let result = decode_jwt("invalid.token");
@@ -102,19 +154,85 @@ mod tests {
         let token = create_valid_token();
         let decoded = decode_jwt(&token).expect("Invalid JWT");
 
-        assert_eq!(decoded.header.alg, AlgorithmType::Hs256);
+        assert_eq!(decoded.header.alg, "HS256");
+        assert_eq!(decoded.header.algorithm(), Some(AlgorithmType::Hs256));
         assert_eq!(
-            decoded.claims.registered.issuer.as_deref(),
+            decoded.claims.get("iss").and_then(|v| v.as_str()),
             Some("example-issuer")
         );
-        assert_eq!(decoded.claims.registered.issued_at, Some(1609372800));
         assert_eq!(
-            decoded
-                .claims
-                .private
-                .get("custom_key")
-                .and_then(|v| v.as_str()),
+            decoded.claims.get("iat").and_then(|v| v.as_u64()),
+            Some(1609372800)
+        );
+        assert_eq!(
+            decoded.claims.get("custom_key").and_then(|v| v.as_str()),
             Some("custom_value")
         );
     }
+
+    #[test]
+    fn test_given_a_valid_token_returns_segments_and_signature() {
+        let token = create_valid_token();
+        let decoded = decode_jwt(&token).expect("Invalid JWT");
+        let segments = token.split('.').collect::<Vec<&str>>();
+
+        assert_eq!(decoded.segments.header, segments[0]);
+        assert_eq!(decoded.segments.claims, segments[1]);
+        assert_eq!(decoded.segments.signature, segments[2]);
+        assert_eq!(decoded.signature.len(), 32);
+        assert_eq!(decoded.header.typ.as_deref(), Some("JWT"));
+        assert_eq!(
+            decoded.header.parameters.get("cty"),
+            Some(&Value::String("JWT".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_given_custom_header_parameters_preserves_them() {
+        let header = r#"{"alg":"RS256","typ":"at+jwt","kid":"key-1","x5t":"dGh1bWJwcmludA","jku":"https://example.com/jwks.json","crit":["exp"],"custom":{"nested":true}}"#;
+        let token = create_unsigned_token(header, r#"{"sub":"1234567890"}"#);
+
+        let decoded = decode_jwt(&token).expect("Invalid JWT");
+
+        assert_eq!(decoded.header.alg, "RS256");
+        assert_eq!(decoded.header.typ.as_deref(), Some("at+jwt"));
+        assert_eq!(decoded.header.kid.as_deref(), Some("key-1"));
+        assert_eq!(decoded.signature, b"signature");
+        assert_eq!(serde_json::to_string(&decoded.header).unwrap(), header);
+    }
+
+    #[test]
+    fn test_given_claims_of_any_shape_preserves_them() {
+        let claims = r#"{"sub":"1234567890","aud":["a","b"],"exp":1700000000.5,"nbf":1699990000,"roles":null}"#;
+        let token = create_unsigned_token(r#"{"alg":"HS256"}"#, claims);
+
+        let decoded = decode_jwt(&token).expect("Invalid JWT");
+
+        assert_eq!(decoded.claims.get("aud"), Some(&json!(["a", "b"])));
+        assert_eq!(decoded.claims.get("exp"), Some(&json!(1700000000.5)));
+        assert_eq!(serde_json::to_string(&decoded.claims).unwrap(), claims);
+    }
+
+    #[test]
+    fn test_given_unknown_algorithm_keeps_it() {
+        let token = create_unsigned_token(r#"{"alg":"EdDSA","crv":"Ed25519"}"#, r#"{"sub":"1"}"#);
+
+        let decoded = decode_jwt(&token).expect("Invalid JWT");
+
+        assert_eq!(decoded.header.alg, "EdDSA");
+        assert_eq!(decoded.header.algorithm(), None);
+        assert_eq!(
+            decoded.header.parameters.get("crv"),
+            Some(&json!("Ed25519"))
+        );
+    }
+
+    #[test]
+    fn test_given_an_encrypted_token_returns_error() {
+        let result = decode_jwt("eyJhbGciOiJkaXIiLCJlbmMiOiJBMjU2R0NNIn0..aXY.Y2lwaGVydGV4dA.dGFn");
+        assert_eq!(
+            result.unwrap_err(),
+            "This token is an encrypted JWT (JWE). Inspect its header or decrypt it with a key instead"
+        );
+    }
 }
@@ -0,0 +1,161 @@
+use crate::encoding::{decode_jwt, decrypt_content, DecodedJwt, Jwe, JweDecryptionKey, JweHeader};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DecryptedJwe {
+    pub header: JweHeader,
+    pub payload: String,
+    pub nested: Option<DecodedJwt>,
+}
+
+/// Decrypts an encrypted JWT (JWE) and decodes its payload.
+///
+/// The key management algorithm in the protected header decides how the key is interpreted:
+/// `dir` and A128KW/A192KW/A256KW expect the symmetric key (raw or as an "oct" JWK),
+/// RSA1_5/RSA-OAEP/RSA-OAEP-256 expect a PEM encoded RSA private key and the ECDH-ES
+/// algorithms expect a PEM encoded EC private key. Content encrypted with A128GCM, A192GCM,
+/// A256GCM, A128CBC-HS256, A192CBC-HS384 and A256CBC-HS512 is supported.
+///
+/// When the payload is itself a signed JWT (a nested JWS) it is passed to the JWT decoder.
+///
+/// # Arguments
+/// * `token` - A string slice containing the five segment JWE token
+/// * `key` - The symmetric key or PEM encoded private key used to decrypt the token
+///
+/// # Returns
+/// * `Ok(DecryptedJwe)` - The protected header, decrypted payload and decoded nested JWT, if any
+/// * `Err(String)` - An error message if the token cannot be parsed or decrypted
+///
+/// ```
+#[tauri::command]
+pub fn decrypt_jwe(token: &str, key: &str) -> Result<DecryptedJwe, String> {
+    let jwe = Jwe::parse(token)?;
+
+    if let Some(zip) = &jwe.header.zip {
+        return Err(format!(
+            "Compressed JWE payloads (zip: {}) are not supported",
+            zip
+        ));
+    }
+
+    let decryption_key = JweDecryptionKey::from_key(&jwe.header, key)?;
+    let content_key = decryption_key.content_encryption_key(&jwe)?;
+    let plaintext = decrypt_content(&jwe, &content_key)?;
+
+    let payload = String::from_utf8(plaintext)
+        .map_err(|_| "Decrypted JWE payload is not valid UTF-8".to_string())?;
+
+    // A "JWT" content type marks a nested token, otherwise the payload is usually the claims
+    let nested = match jwe.header.cty.as_deref() {
+        Some(content_type) if content_type.eq_ignore_ascii_case("JWT") => {
+            Some(decode_jwt(&payload)?)
+        }
+        _ => decode_jwt(&payload).ok(),
+    };
+
+    Ok(DecryptedJwe {
+        header: jwe.header,
+        payload,
+        nested,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::test_keys::*;
+
+    const CLAIMS: &str = r#"{"iss":"example-issuer","sub":"example-subject"}"#;
+    const SYMMETRIC_128: &str = "0123456789abcdef";
+    const SYMMETRIC_256: &str = "0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn test_given_symmetric_keys_returns_payload() {
+        let cases = [
+            (JWE_DIR_A256GCM, SYMMETRIC_256),
+            (JWE_A256KW_A256CBC_HS512, SYMMETRIC_256),
+            (
+                JWE_A256KW_A256CBC_HS512,
+                r#"{"kty":"oct","k":"MDEyMzQ1Njc4OWFiY2RlZjAxMjM0NTY3ODlhYmNkZWY"}"#,
+            ),
+        ];
+
+        for (token, key) in cases {
+            let decrypted = decrypt_jwe(token, key).expect("Failed to decrypt JWE");
+            assert_eq!(decrypted.payload, CLAIMS);
+            assert!(decrypted.nested.is_none());
+        }
+    }
+
+    #[test]
+    fn test_given_private_keys_returns_payload() {
+        let cases = [
+            (JWE_RSA_OAEP_256_A256GCM, RSA_PRIVATE_KEY),
+            (JWE_RSA_OAEP_A192GCM, RSA_PRIVATE_KEY),
+            (JWE_RSA1_5_A192CBC_HS384, RSA_PRIVATE_KEY),
+            (JWE_ECDH_ES_A128GCM, P256_PRIVATE_KEY),
+            (JWE_ECDH_ES_A128GCM, P256_SEC1_PRIVATE_KEY),
+            (JWE_ECDH_ES_A128KW_A128CBC_HS256, P521_PRIVATE_KEY),
+        ];
+
+        for (token, key) in cases {
+            let decrypted = decrypt_jwe(token, key).expect("Failed to decrypt JWE");
+            assert_eq!(decrypted.payload, CLAIMS, "{}", decrypted.header.alg);
+        }
+    }
+
+    #[test]
+    fn test_given_nested_jws_returns_decoded_token() {
+        let cases = [
+            (JWE_A128KW_A128CBC_HS256_NESTED, SYMMETRIC_128),
+            (JWE_ECDH_ES_A256KW_A256GCM_NESTED, P384_PRIVATE_KEY),
+        ];
+
+        for (token, key) in cases {
+            let decrypted = decrypt_jwe(token, key).expect("Failed to decrypt JWE");
+            let nested = decrypted.nested.expect("Missing nested JWT");
+
+            assert_eq!(decrypted.header.cty.as_deref(), Some("JWT"));
+            assert_eq!(nested.header.typ.as_deref(), Some("JWT"));
+            assert_eq!(
+                nested.claims.get("iss").and_then(|v| v.as_str()),
+                Some("example-issuer")
+            );
+        }
+    }
+
+    #[test]
+    fn test_given_wrong_key_returns_error() {
+        let result = decrypt_jwe(JWE_DIR_A256GCM, "fedcba9876543210fedcba9876543210");
+        assert!(result
+            .unwrap_err()
+            .starts_with("Failed to decrypt JWE token"));
+
+        let result = decrypt_jwe(JWE_A256KW_A256CBC_HS512, SYMMETRIC_128);
+        assert_eq!(
+            result.unwrap_err(),
+            "A256KW requires a 32 byte key but got 16 bytes"
+        );
+
+        let result = decrypt_jwe(JWE_ECDH_ES_A128GCM, P384_PRIVATE_KEY);
+        assert!(result
+            .unwrap_err()
+            .starts_with("Failed to parse P-256 private key"));
+    }
+
+    #[test]
+    fn test_given_tampered_ciphertext_returns_error() {
+        for (token, key) in [
+            (JWE_DIR_A256GCM, SYMMETRIC_256),
+            (JWE_A256KW_A256CBC_HS512, SYMMETRIC_256),
+        ] {
+            let mut segments = token.split('.').map(String::from).collect::<Vec<String>>();
+            segments[3] = format!("AAAA{}", &segments[3][4..]);
+
+            let result = decrypt_jwe(&segments.join("."), key);
+            assert!(result
+                .unwrap_err()
+                .starts_with("Failed to decrypt JWE token"));
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::encoding::{BinaryEncoding, DecodedData};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DetectedEncoding {
+    pub encoding: BinaryEncoding,
+    pub decoded: DecodedData,
+}
+
+/// Detects which binary-to-text encodings a pasted string could use.
+///
+/// Every encoding the input decodes with is returned, most likely first. Encodings with
+/// a narrower alphabet rank higher (e.g. "cafe" is more likely hex than Base64), characters
+/// specific to an alphabet such as '+' or '_' are taken into account and candidates that
+/// decode to readable text are preferred.
+///
+/// # Arguments
+/// * `input` - The encoded string
+///
+/// # Returns
+/// * `Ok(Vec<DetectedEncoding>)` - The matching encodings and decoded data, most likely first
+/// * `Err(String)` - An error message if the input is empty or matches no encoding
+///
+/// ```
+#[tauri::command]
+pub fn detect_encoding(input: &str) -> Result<Vec<DetectedEncoding>, String> {
+    let input = input.trim();
+
+    if input.is_empty() {
+        return Err("Nothing to detect, the input is empty".to_string());
+    }
+
+    let mut candidates = BinaryEncoding::ALL
+        .into_iter()
+        .filter_map(|encoding| {
+            let bytes = encoding.decode(input).ok()?;
+            let score = likelihood(encoding, input, &bytes);
+            Some((
+                score,
+                DetectedEncoding {
+                    encoding,
+                    decoded: bytes.into(),
+                },
+            ))
+        })
+        .collect::<Vec<(u32, DetectedEncoding)>>();
+
+    if candidates.is_empty() {
+        return Err("The input does not match any supported encoding".to_string());
+    }
+
+    // Stable sort so ties keep the order of BinaryEncoding::ALL
+    candidates.sort_by(|(a, _), (b, _)| b.cmp(a));
+
+    Ok(candidates
+        .into_iter()
+        .map(|(_, candidate)| candidate)
+        .collect())
+}
+
+/// Scores how likely `input` is to use `encoding`, given it decodes to `bytes`
+fn likelihood(encoding: BinaryEncoding, input: &str, bytes: &[u8]) -> u32 {
+    let has_any = |characters: &[char]| input.contains(characters);
+
+    let mut score = match encoding {
+        BinaryEncoding::Hex => 40,
+        BinaryEncoding::Base32 => 30,
+        BinaryEncoding::Base64 | BinaryEncoding::Base64Url => 20,
+        BinaryEncoding::Base64UrlNoPad => 15,
+        BinaryEncoding::Base58 => 10,
+    };
+
+    score += match encoding {
+        BinaryEncoding::Base64 if has_any(&['+', '/']) => 20,
+        BinaryEncoding::Base64Url | BinaryEncoding::Base64UrlNoPad if has_any(&['-', '_']) => 20,
+        BinaryEncoding::Base64 | BinaryEncoding::Base64Url | BinaryEncoding::Base32
+            if input.ends_with('=') =>
+        {
+            5
+        }
+        BinaryEncoding::Hex if input.starts_with("0x") || input.starts_with("0X") => 20,
+        _ => 0,
+    };
+
+    let readable = std::str::from_utf8(bytes)
+        .is_ok_and(|text| text.chars().all(|c| !c.is_control() || c.is_whitespace()));
+
+    if readable {
+        score += 50;
+    }
+
+    score
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detect(input: &str) -> Vec<BinaryEncoding> {
+        detect_encoding(input)
+            .unwrap()
+            .into_iter()
+            .map(|candidate| candidate.encoding)
+            .collect()
+    }
+
+    #[test]
+    fn test_detects_the_most_likely_encoding() {
+        assert_eq!(detect("68656c6c6f")[0], BinaryEncoding::Hex);
+        assert_eq!(detect("deadbeef")[0], BinaryEncoding::Hex);
+        assert_eq!(detect("NBSWY3DP")[0], BinaryEncoding::Base32);
+        assert_eq!(detect("aGVsbG8=")[0], BinaryEncoding::Base64);
+        assert_eq!(detect("aGk/Pz8+Pg==")[0], BinaryEncoding::Base64);
+        assert_eq!(detect("aGk_Pz8-Pg==")[0], BinaryEncoding::Base64Url);
+        assert_eq!(detect("aGk_Pz8-Pg")[0], BinaryEncoding::Base64UrlNoPad);
+        assert_eq!(detect("Cn8eVZg")[0], BinaryEncoding::Base58);
+    }
+
+    #[test]
+    fn test_returns_every_matching_encoding() {
+        let detected = detect("aGVsbG8=");
+        assert_eq!(
+            detected,
+            vec![BinaryEncoding::Base64, BinaryEncoding::Base64Url]
+        );
+    }
+
+    #[test]
+    fn test_given_no_matching_encoding_returns_error() {
+        assert_eq!(
+            detect_encoding("not valid!").unwrap_err(),
+            "The input does not match any supported encoding"
+        );
+        assert!(detect_encoding("  ").is_err());
+    }
+}
@@ -0,0 +1,40 @@
+use crate::encoding::{BinaryEncoding, DataInput};
+
+/// Encodes text or raw bytes with a binary-to-text encoding.
+///
+/// Text is encoded as UTF-8 before being converted to standard Base64, URL-safe Base64
+/// with or without padding, Base32, Base58 or lowercase hex.
+///
+/// # Arguments
+/// * `input` - The text or bytes to encode
+/// * `encoding` - The encoding to use
+///
+/// # Returns
+/// The encoded string
+///
+/// ```
+#[tauri::command]
+pub fn encode_data(input: DataInput, encoding: BinaryEncoding) -> String {
+    encoding.encode(&input.into_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encodes_text_and_bytes() {
+        assert_eq!(
+            encode_data(DataInput::Text("hello".to_string()), BinaryEncoding::Base64),
+            "aGVsbG8="
+        );
+        assert_eq!(
+            encode_data(DataInput::Bytes(vec![0, 0, 255]), BinaryEncoding::Base58),
+            "115Q"
+        );
+        assert_eq!(
+            encode_data(DataInput::Bytes(vec![0xde, 0xad]), BinaryEncoding::Hex),
+            "dead"
+        );
+    }
+}
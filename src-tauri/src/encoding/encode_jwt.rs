@@ -0,0 +1,169 @@
+use crate::encoding::JwtSigningKey;
+use jwt::header::JoseHeader;
+use jwt::{AlgorithmType, SignWithKey, Token};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct EncodeJwtHeader {
+    pub alg: AlgorithmType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub typ: Option<String>,
+}
+
+impl JoseHeader for EncodeJwtHeader {
+    fn algorithm_type(&self) -> AlgorithmType {
+        self.alg
+    }
+
+    fn key_id(&self) -> Option<&str> {
+        self.kid.as_deref()
+    }
+}
+
+/// Encodes and signs a JWT token from a header, arbitrary JSON claims and a key.
+///
+/// The algorithm in the header decides how the key is interpreted: HS256/384/512 expect
+/// the shared secret, while RS*, PS* and ES* expect a PEM encoded private key.
+///
+/// # Arguments
+/// * `header` - The token header containing the algorithm and optional key id and type
+/// * `claims` - A JSON object containing the registered and private claims
+/// * `key` - The HMAC secret or PEM encoded private key used to sign the token
+///
+/// # Returns
+/// * `Ok(String)` - The signed token in compact serialization
+/// * `Err(String)` - An error message if the claims are not an object or signing fails
+///
+/// ```
+#[tauri::command]
+pub fn encode_jwt(header: EncodeJwtHeader, claims: Value, key: &str) -> Result<String, String> {
+    let claims: Map<String, Value> = match claims {
+        Value::Object(claims) => claims,
+        _ => return Err("Claims must be a JSON object".to_string()),
+    };
+
+    let signing_key = JwtSigningKey::from_key(header.alg, key)?;
+
+    let token = Token::new(header, claims)
+        .sign_with_key(&signing_key)
+        .map_err(|e| format!("Failed to sign JWT token: {}", e))?;
+
+    Ok(token.as_str().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::test_keys::*;
+    use crate::encoding::{decode_jwt, verify_jwt, SignatureVerdict};
+    use serde_json::json;
+
+    fn create_header(alg: AlgorithmType) -> EncodeJwtHeader {
+        EncodeJwtHeader {
+            alg,
+            kid: Some("example-key-id".to_string()),
+            typ: Some("JWT".to_string()),
+        }
+    }
+
+    fn create_claims() -> Value {
+        json!({
+            "iss": "example-issuer",
+            "sub": "example-subject",
+            "iat": 1609372800,
+            "custom_key": "custom_value",
+            "roles": ["admin", "user"]
+        })
+    }
+
+    #[test]
+    fn test_given_hmac_secret_returns_token_that_round_trips() {
+        let token = encode_jwt(
+            create_header(AlgorithmType::Hs256),
+            create_claims(),
+            "mock-secret",
+        )
+        .expect("Failed to encode JWT");
+
+        assert_eq!(token.split('.').count(), 3);
+
+        let decoded = decode_jwt(&token).expect("Failed to decode JWT");
+        assert_eq!(decoded.header.algorithm(), Some(AlgorithmType::Hs256));
+        assert_eq!(decoded.header.kid.as_deref(), Some("example-key-id"));
+        assert_eq!(decoded.claims.get("iss"), Some(&json!("example-issuer")));
+        assert_eq!(decoded.claims.get("iat"), Some(&json!(1609372800)));
+        assert_eq!(decoded.claims.get("roles"), Some(&json!(["admin", "user"])));
+    }
+
+    #[test]
+    fn test_given_private_keys_returns_tokens_verifiable_with_public_keys() {
+        let cases = [
+            (AlgorithmType::Hs384, "mock-secret", "mock-secret"),
+            (AlgorithmType::Hs512, "mock-secret", "mock-secret"),
+            (AlgorithmType::Rs256, RSA_PRIVATE_KEY, RSA_PUBLIC_KEY),
+            (AlgorithmType::Rs384, RSA_PRIVATE_KEY, RSA_PUBLIC_KEY),
+            (AlgorithmType::Rs512, RSA_PRIVATE_KEY, RSA_PUBLIC_KEY),
+            (AlgorithmType::Ps256, RSA_PRIVATE_KEY, RSA_PUBLIC_KEY),
+            (AlgorithmType::Ps384, RSA_PRIVATE_KEY, RSA_PUBLIC_KEY),
+            (AlgorithmType::Ps512, RSA_PRIVATE_KEY, RSA_PUBLIC_KEY),
+            (AlgorithmType::Es256, P256_PRIVATE_KEY, P256_PUBLIC_KEY),
+            (AlgorithmType::Es256, P256_SEC1_PRIVATE_KEY, P256_PUBLIC_KEY),
+            (AlgorithmType::Es384, P384_PRIVATE_KEY, P384_PUBLIC_KEY),
+            (AlgorithmType::Es512, P521_PRIVATE_KEY, P521_PUBLIC_KEY),
+        ];
+
+        for (algorithm, private_key, public_key) in cases {
+            let token = encode_jwt(create_header(algorithm), create_claims(), private_key)
+                .expect("Failed to encode JWT");
+
+            let verified = verify_jwt(&token, public_key).expect("Failed to verify JWT");
+            assert_eq!(verified.verdict, SignatureVerdict::Valid, "{:?}", algorithm);
+            assert_eq!(verified.decoded.header.algorithm(), Some(algorithm));
+        }
+    }
+
+    #[test]
+    fn test_header_includes_key_id_and_type() {
+        let token = encode_jwt(
+            create_header(AlgorithmType::Hs256),
+            create_claims(),
+            "mock-secret",
+        )
+        .unwrap();
+
+        // {"alg":"HS256","kid":"example-key-id","typ":"JWT"}
+        assert!(token
+            .starts_with("eyJhbGciOiJIUzI1NiIsImtpZCI6ImV4YW1wbGUta2V5LWlkIiwidHlwIjoiSldUIn0."));
+    }
+
+    #[test]
+    fn test_given_non_object_claims_returns_error() {
+        let result = encode_jwt(
+            create_header(AlgorithmType::Hs256),
+            json!([1, 2]),
+            "mock-secret",
+        );
+        assert_eq!(result.unwrap_err(), "Claims must be a JSON object");
+    }
+
+    #[test]
+    fn test_given_mismatched_key_returns_error() {
+        let result = encode_jwt(
+            create_header(AlgorithmType::Es384),
+            create_claims(),
+            P256_PRIVATE_KEY,
+        );
+        assert!(result
+            .unwrap_err()
+            .starts_with("Failed to parse P-384 private key"));
+    }
+
+    #[test]
+    fn test_given_none_algorithm_returns_error() {
+        let result = encode_jwt(create_header(AlgorithmType::None), create_claims(), "");
+        assert!(result.is_err());
+    }
+}
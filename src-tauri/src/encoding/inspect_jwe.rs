@@ -0,0 +1,78 @@
+use crate::encoding::{Jwe, JweHeader, JweSegments};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct InspectedJwe {
+    pub header: JweHeader,
+    pub segments: JweSegments,
+}
+
+/// Inspects an encrypted JWT (JWE) without decrypting it.
+///
+/// This function parses a token in JWE compact serialization and returns its protected
+/// header, including the key management (`alg`) and content encryption (`enc`) algorithms
+/// and key id, along with its raw segments. The payload stays encrypted.
+///
+/// # Arguments
+/// * `token` - A string slice containing the five segment JWE token
+///
+/// # Returns
+/// * `Ok(InspectedJwe)` - A struct containing the protected header and raw segments
+/// * `Err(String)` - An error message if the token is not a valid JWE
+///
+/// ```
+#[tauri::command]
+pub fn inspect_jwe(token: &str) -> Result<InspectedJwe, String> {
+    let jwe = Jwe::parse(token)?;
+
+    Ok(InspectedJwe {
+        header: jwe.header,
+        segments: jwe.segments,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::test_keys::*;
+
+    #[test]
+    fn test_given_a_jwe_returns_protected_header_and_segments() {
+        let inspected = inspect_jwe(JWE_RSA_OAEP_256_A256GCM).expect("Invalid JWE");
+
+        assert_eq!(inspected.header.alg, "RSA-OAEP-256");
+        assert_eq!(inspected.header.enc, "A256GCM");
+        assert_eq!(inspected.header.kid.as_deref(), Some("rsa-key"));
+        assert_eq!(
+            inspected.segments.protected,
+            JWE_RSA_OAEP_256_A256GCM.split('.').next().unwrap()
+        );
+        assert!(!inspected.segments.encrypted_key.is_empty());
+    }
+
+    #[test]
+    fn test_given_ecdh_jwe_preserves_ephemeral_key() {
+        let inspected = inspect_jwe(JWE_ECDH_ES_A128GCM).expect("Invalid JWE");
+
+        assert_eq!(inspected.header.alg, "ECDH-ES");
+        assert_eq!(inspected.header.parameters["apu"], "QWxpY2U");
+        assert_eq!(
+            inspected
+                .header
+                .ephemeral_public_key()
+                .unwrap()
+                .crv
+                .as_deref(),
+            Some("P-256")
+        );
+    }
+
+    #[test]
+    fn test_given_a_jws_returns_error() {
+        let result = inspect_jwe("eyJhbGciOiJIUzI1NiJ9.e30.c2lnbmF0dXJl");
+        assert_eq!(
+            result.unwrap_err(),
+            "Failed to decode JWE token. Expected 5 segments but found 3"
+        );
+    }
+}
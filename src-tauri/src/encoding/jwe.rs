@@ -0,0 +1,109 @@
+use crate::encoding::Jwk;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The protected header of an encrypted token. `alg`, `enc`, `kid`, `cty` and `zip` are typed
+/// while every other parameter (e.g. `epk`, `apu` or custom parameters) is kept as sent.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct JweHeader {
+    pub alg: String,
+    pub enc: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cty: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zip: Option<String>,
+    #[serde(flatten)]
+    pub parameters: Map<String, Value>,
+}
+
+impl JweHeader {
+    /// The ephemeral public key used by the ECDH-ES key agreement algorithms
+    pub fn ephemeral_public_key(&self) -> Result<Jwk, String> {
+        let epk = self
+            .parameters
+            .get("epk")
+            .ok_or_else(|| format!("Missing 'epk' header parameter for {}", self.alg))?;
+
+        serde_json::from_value(epk.clone()).map_err(|e| format!("Invalid 'epk' parameter: {}", e))
+    }
+
+    /// Decodes a base64url encoded header parameter such as `apu` or `apv`, empty when absent
+    pub fn binary_parameter(&self, name: &str) -> Result<Vec<u8>, String> {
+        match self.parameters.get(name) {
+            Some(Value::String(value)) => URL_SAFE_NO_PAD
+                .decode(value)
+                .map_err(|e| format!("Invalid '{}' parameter: {}", name, e)),
+            Some(_) => Err(format!("Invalid '{}' parameter: expected a string", name)),
+            None => Ok(Vec::new()),
+        }
+    }
+}
+
+/// The raw base64url encoded segments of a JWE in compact serialization
+#[derive(Debug, Deserialize, Serialize)]
+pub struct JweSegments {
+    pub protected: String,
+    pub encrypted_key: String,
+    pub iv: String,
+    pub ciphertext: String,
+    pub tag: String,
+}
+
+/// A parsed, still encrypted, JWE
+#[derive(Debug)]
+pub struct Jwe {
+    pub header: JweHeader,
+    pub segments: JweSegments,
+    pub encrypted_key: Vec<u8>,
+    pub iv: Vec<u8>,
+    pub ciphertext: Vec<u8>,
+    pub tag: Vec<u8>,
+}
+
+impl Jwe {
+    /// Parses a token in JWE compact serialization without decrypting it
+    pub fn parse(token: &str) -> Result<Jwe, String> {
+        let segments = token.trim().split('.').collect::<Vec<&str>>();
+
+        let [protected, encrypted_key, iv, ciphertext, tag] = segments[..] else {
+            return Err(format!(
+                "Failed to decode JWE token. Expected 5 segments but found {}",
+                segments.len()
+            ));
+        };
+
+        let header_json = decode_segment("protected header", protected)?;
+        let header = serde_json::from_slice(&header_json)
+            .map_err(|e| format!("Failed to parse JWE header: {}", e))?;
+
+        Ok(Jwe {
+            header,
+            encrypted_key: decode_segment("encrypted key", encrypted_key)?,
+            iv: decode_segment("initialization vector", iv)?,
+            ciphertext: decode_segment("ciphertext", ciphertext)?,
+            tag: decode_segment("authentication tag", tag)?,
+            segments: JweSegments {
+                protected: protected.to_string(),
+                encrypted_key: encrypted_key.to_string(),
+                iv: iv.to_string(),
+                ciphertext: ciphertext.to_string(),
+                tag: tag.to_string(),
+            },
+        })
+    }
+}
+
+/// Whether a token uses the five segment JWE compact serialization
+pub fn is_jwe(token: &str) -> bool {
+    token.trim().split('.').count() == 5
+}
+
+fn decode_segment(name: &str, segment: &str) -> Result<Vec<u8>, String> {
+    URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|e| format!("Failed to decode JWE {}: {}", name, e))
+}
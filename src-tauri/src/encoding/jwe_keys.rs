@@ -0,0 +1,323 @@
+use crate::encoding::{Jwe, JweHeader, Jwk};
+use aes::{Aes128, Aes192, Aes256};
+use aes_gcm::aead::consts::U12;
+use aes_gcm::aead::generic_array::GenericArray;
+use aes_gcm::aead::{Aead, Payload};
+use aes_gcm::{Aes128Gcm, Aes256Gcm, AesGcm, KeyInit};
+use aes_kw::{KekAes128, KekAes192, KekAes256};
+use cbc::cipher::block_padding::Pkcs7;
+use cbc::cipher::{BlockDecryptMut, KeyIvInit};
+use hmac::{Hmac, Mac};
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::{Oaep, Pkcs1v15Encrypt, RsaPrivateKey};
+use sha1::Sha1;
+use sha2::{Sha256, Sha384, Sha512};
+
+const DECRYPTION_FAILED: &str =
+    "Failed to decrypt JWE token. Please check that the key is correct and the token has not been modified";
+
+/// A key capable of recovering the content encryption key of a JWE.
+///
+/// Symmetric keys are used for `dir` and the AES key wrap algorithms (A128KW/A192KW/A256KW),
+/// RSA private keys for RSA1_5, RSA-OAEP and RSA-OAEP-256 and EC private keys for the ECDH-ES
+/// key agreement algorithms.
+pub enum JweDecryptionKey {
+    Symmetric(Vec<u8>),
+    Rsa(Box<RsaPrivateKey>),
+    P256(p256::SecretKey),
+    P384(p384::SecretKey),
+    P521(p521::SecretKey),
+}
+
+impl JweDecryptionKey {
+    /// Builds a decryption key for the key management algorithm of `header`.
+    ///
+    /// Symmetric keys may be given as an "oct" JWK or as the raw secret. RSA keys may be
+    /// PKCS#8 or PKCS#1 PEM encoded and EC keys PKCS#8 or SEC1 PEM encoded, matching the
+    /// curve of the ephemeral public key in the header.
+    pub fn from_key(header: &JweHeader, key: &str) -> Result<Self, String> {
+        let key = key.trim();
+
+        let decryption_key = match header.alg.as_str() {
+            "dir" | "A128KW" | "A192KW" | "A256KW" => Self::Symmetric(symmetric_key(key)?),
+            "RSA1_5" | "RSA-OAEP" | "RSA-OAEP-256" => Self::Rsa(Box::new(
+                RsaPrivateKey::from_pkcs8_pem(key)
+                    .or_else(|_| RsaPrivateKey::from_pkcs1_pem(key))
+                    .map_err(|e| format!("Failed to parse RSA private key: {}", e))?,
+            )),
+            "ECDH-ES" | "ECDH-ES+A128KW" | "ECDH-ES+A192KW" | "ECDH-ES+A256KW" => {
+                match header.ephemeral_public_key()?.crv.as_deref() {
+                    Some("P-256") => Self::P256(
+                        p256::SecretKey::from_pkcs8_pem(key)
+                            .or_else(|_| p256::SecretKey::from_sec1_pem(key))
+                            .map_err(|e| format!("Failed to parse P-256 private key: {}", e))?,
+                    ),
+                    Some("P-384") => Self::P384(
+                        p384::SecretKey::from_pkcs8_pem(key)
+                            .or_else(|_| p384::SecretKey::from_sec1_pem(key))
+                            .map_err(|e| format!("Failed to parse P-384 private key: {}", e))?,
+                    ),
+                    Some("P-521") => Self::P521(
+                        p521::SecretKey::from_pkcs8_pem(key)
+                            .or_else(|_| p521::SecretKey::from_sec1_pem(key))
+                            .map_err(|e| format!("Failed to parse P-521 private key: {}", e))?,
+                    ),
+                    curve => {
+                        return Err(format!(
+                            "Unsupported ephemeral key curve '{}'",
+                            curve.unwrap_or_default()
+                        ))
+                    }
+                }
+            }
+            algorithm => {
+                return Err(format!(
+                    "Unsupported JWE key management algorithm '{}'",
+                    algorithm
+                ))
+            }
+        };
+
+        Ok(decryption_key)
+    }
+
+    /// Recovers the content encryption key (CEK) of `jwe`, either directly, by unwrapping
+    /// or decrypting its encrypted key, or through ECDH-ES key agreement
+    pub fn content_encryption_key(&self, jwe: &Jwe) -> Result<Vec<u8>, String> {
+        let header = &jwe.header;
+        let algorithm = header.alg.as_str();
+
+        match self {
+            Self::Symmetric(key) if algorithm == "dir" => {
+                let key_length = content_key_length(&header.enc)?;
+                if key.len() != key_length {
+                    return Err(format!(
+                        "{} requires a {} byte key but got {} bytes",
+                        header.enc,
+                        key_length,
+                        key.len()
+                    ));
+                }
+                Ok(key.clone())
+            }
+            Self::Symmetric(key) => unwrap_key(algorithm, key, &jwe.encrypted_key),
+            Self::Rsa(private_key) => {
+                let content_key = match algorithm {
+                    "RSA1_5" => private_key.decrypt(Pkcs1v15Encrypt, &jwe.encrypted_key),
+                    "RSA-OAEP" => private_key.decrypt(Oaep::new::<Sha1>(), &jwe.encrypted_key),
+                    _ => private_key.decrypt(Oaep::new::<Sha256>(), &jwe.encrypted_key),
+                };
+                content_key.map_err(|_| DECRYPTION_FAILED.to_string())
+            }
+            Self::P256(_) | Self::P384(_) | Self::P521(_) => {
+                let shared_secret = self.shared_secret(&header.ephemeral_public_key()?)?;
+
+                match algorithm.strip_prefix("ECDH-ES+") {
+                    Some(key_wrap_algorithm) => {
+                        let key_length = key_wrap_length(key_wrap_algorithm)?;
+                        let key = concat_kdf(&shared_secret, algorithm, key_length, header)?;
+                        unwrap_key(key_wrap_algorithm, &key, &jwe.encrypted_key)
+                    }
+                    None => {
+                        let key_length = content_key_length(&header.enc)?;
+                        concat_kdf(&shared_secret, &header.enc, key_length, header)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Computes the ECDH shared secret between this private key and an ephemeral public key
+    fn shared_secret(&self, ephemeral_key: &Jwk) -> Result<Vec<u8>, String> {
+        let point = ephemeral_key.ec_point()?;
+
+        let shared_secret = match self {
+            Self::P256(secret_key) => {
+                let public_key = p256::PublicKey::from_sec1_bytes(&point)
+                    .map_err(|e| format!("Invalid ephemeral public key: {}", e))?;
+                p256::ecdh::diffie_hellman(secret_key.to_nonzero_scalar(), public_key.as_affine())
+                    .raw_secret_bytes()
+                    .to_vec()
+            }
+            Self::P384(secret_key) => {
+                let public_key = p384::PublicKey::from_sec1_bytes(&point)
+                    .map_err(|e| format!("Invalid ephemeral public key: {}", e))?;
+                p384::ecdh::diffie_hellman(secret_key.to_nonzero_scalar(), public_key.as_affine())
+                    .raw_secret_bytes()
+                    .to_vec()
+            }
+            Self::P521(secret_key) => {
+                let public_key = p521::PublicKey::from_sec1_bytes(&point)
+                    .map_err(|e| format!("Invalid ephemeral public key: {}", e))?;
+                p521::ecdh::diffie_hellman(secret_key.to_nonzero_scalar(), public_key.as_affine())
+                    .raw_secret_bytes()
+                    .to_vec()
+            }
+            _ => return Err("ECDH-ES requires an EC private key".to_string()),
+        };
+
+        Ok(shared_secret)
+    }
+}
+
+/// Decrypts and authenticates the ciphertext of `jwe` with its content encryption key
+pub fn decrypt_content(jwe: &Jwe, content_key: &[u8]) -> Result<Vec<u8>, String> {
+    let key_length = content_key_length(&jwe.header.enc)?;
+    if content_key.len() != key_length {
+        return Err(DECRYPTION_FAILED.to_string());
+    }
+
+    // The protected header, exactly as encoded in the token, is the additional authenticated data
+    let aad = jwe.segments.protected.as_bytes();
+
+    match jwe.header.enc.as_str() {
+        "A128GCM" => decrypt_gcm::<Aes128Gcm>(jwe, content_key, aad),
+        "A192GCM" => decrypt_gcm::<AesGcm<Aes192, U12>>(jwe, content_key, aad),
+        "A256GCM" => decrypt_gcm::<Aes256Gcm>(jwe, content_key, aad),
+        "A128CBC-HS256" => {
+            decrypt_cbc_hmac::<cbc::Decryptor<Aes128>, Hmac<Sha256>>(jwe, content_key, aad)
+        }
+        "A192CBC-HS384" => {
+            decrypt_cbc_hmac::<cbc::Decryptor<Aes192>, Hmac<Sha384>>(jwe, content_key, aad)
+        }
+        _ => decrypt_cbc_hmac::<cbc::Decryptor<Aes256>, Hmac<Sha512>>(jwe, content_key, aad),
+    }
+}
+
+/// Returns the content encryption key length in bytes required by an `enc` algorithm
+fn content_key_length(encryption: &str) -> Result<usize, String> {
+    match encryption {
+        "A128GCM" => Ok(16),
+        "A192GCM" => Ok(24),
+        "A256GCM" | "A128CBC-HS256" => Ok(32),
+        "A192CBC-HS384" => Ok(48),
+        "A256CBC-HS512" => Ok(64),
+        _ => Err(format!(
+            "Unsupported JWE content encryption algorithm '{}'",
+            encryption
+        )),
+    }
+}
+
+fn key_wrap_length(algorithm: &str) -> Result<usize, String> {
+    match algorithm {
+        "A128KW" => Ok(16),
+        "A192KW" => Ok(24),
+        "A256KW" => Ok(32),
+        _ => Err(format!("Unsupported key wrap algorithm '{}'", algorithm)),
+    }
+}
+
+/// Reads a symmetric key from an "oct" JWK, falling back to the raw bytes of the secret
+fn symmetric_key(key: &str) -> Result<Vec<u8>, String> {
+    if !key.starts_with('{') {
+        return Ok(key.as_bytes().to_vec());
+    }
+
+    let jwk: Jwk = serde_json::from_str(key).map_err(|e| format!("Failed to parse JWK: {}", e))?;
+    if jwk.kty != "oct" {
+        return Err(format!(
+            "Expected a symmetric (\"oct\") JWK but got \"{}\"",
+            jwk.kty
+        ));
+    }
+
+    jwk.parameter("k")
+}
+
+/// Unwraps a content encryption key with AES key wrap (RFC 3394)
+fn unwrap_key(algorithm: &str, key: &[u8], wrapped_key: &[u8]) -> Result<Vec<u8>, String> {
+    let key_length = key_wrap_length(algorithm)?;
+    if key.len() != key_length {
+        return Err(format!(
+            "{} requires a {} byte key but got {} bytes",
+            algorithm,
+            key_length,
+            key.len()
+        ));
+    }
+
+    let content_key = match key_length {
+        16 => KekAes128::try_from(key).and_then(|kek| kek.unwrap_vec(wrapped_key)),
+        24 => KekAes192::try_from(key).and_then(|kek| kek.unwrap_vec(wrapped_key)),
+        _ => KekAes256::try_from(key).and_then(|kek| kek.unwrap_vec(wrapped_key)),
+    };
+
+    content_key.map_err(|_| DECRYPTION_FAILED.to_string())
+}
+
+/// Derives a key from an ECDH shared secret with the Concat KDF (NIST SP 800-56A) as
+/// parameterised by RFC 7518 section 4.6.2
+fn concat_kdf(
+    shared_secret: &[u8],
+    algorithm_id: &str,
+    key_length: usize,
+    header: &JweHeader,
+) -> Result<Vec<u8>, String> {
+    let mut other_info = Vec::new();
+
+    for field in [
+        algorithm_id.as_bytes().to_vec(),
+        header.binary_parameter("apu")?,
+        header.binary_parameter("apv")?,
+    ] {
+        other_info.extend((field.len() as u32).to_be_bytes());
+        other_info.extend(field);
+    }
+    other_info.extend(((key_length * 8) as u32).to_be_bytes());
+
+    let mut key = vec![0; key_length];
+    concat_kdf::derive_key_into::<Sha256>(shared_secret, &other_info, &mut key)
+        .map_err(|e| format!("Failed to derive key: {}", e))?;
+
+    Ok(key)
+}
+
+fn decrypt_gcm<C>(jwe: &Jwe, content_key: &[u8], aad: &[u8]) -> Result<Vec<u8>, String>
+where
+    C: Aead + KeyInit,
+{
+    if jwe.iv.len() != 12 || jwe.tag.len() != 16 {
+        return Err(DECRYPTION_FAILED.to_string());
+    }
+
+    let cipher = C::new_from_slice(content_key).map_err(|_| DECRYPTION_FAILED.to_string())?;
+
+    let mut message = jwe.ciphertext.clone();
+    message.extend(&jwe.tag);
+
+    cipher
+        .decrypt(
+            GenericArray::from_slice(&jwe.iv),
+            Payload { msg: &message, aad },
+        )
+        .map_err(|_| DECRYPTION_FAILED.to_string())
+}
+
+/// Decrypts AES-CBC content after verifying its HMAC tag as described in RFC 7518 section 5.2
+fn decrypt_cbc_hmac<D, M>(jwe: &Jwe, content_key: &[u8], aad: &[u8]) -> Result<Vec<u8>, String>
+where
+    D: BlockDecryptMut + KeyIvInit,
+    M: Mac + KeyInit,
+{
+    let (mac_key, encryption_key) = content_key.split_at(content_key.len() / 2);
+
+    // The tag is the first half of the HMAC output, so an empty or short tag must be rejected
+    if jwe.tag.len() != mac_key.len() {
+        return Err(DECRYPTION_FAILED.to_string());
+    }
+
+    let mut mac = <M as Mac>::new_from_slice(mac_key).map_err(|_| DECRYPTION_FAILED.to_string())?;
+    mac.update(aad);
+    mac.update(&jwe.iv);
+    mac.update(&jwe.ciphertext);
+    mac.update(&((aad.len() as u64) * 8).to_be_bytes());
+    mac.verify_truncated_left(&jwe.tag)
+        .map_err(|_| DECRYPTION_FAILED.to_string())?;
+
+    D::new_from_slices(encryption_key, &jwe.iv)
+        .map_err(|_| DECRYPTION_FAILED.to_string())?
+        .decrypt_padded_vec_mut::<Pkcs7>(&jwe.ciphertext)
+        .map_err(|_| DECRYPTION_FAILED.to_string())
+}
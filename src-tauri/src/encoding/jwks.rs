@@ -0,0 +1,182 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use jwt::AlgorithmType;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tokio::fs::read_to_string;
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum JwksSource {
+    Text(String),
+    File(PathBuf),
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Jwk {
+    pub kty: String,
+    pub kid: Option<String>,
+    #[serde(rename = "use")]
+    pub use_: Option<String>,
+    pub alg: Option<String>,
+    pub crv: Option<String>,
+    pub n: Option<String>,
+    pub e: Option<String>,
+    pub x: Option<String>,
+    pub y: Option<String>,
+    pub k: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Jwks {
+    pub keys: Vec<Jwk>,
+}
+
+impl Jwks {
+    /// Loads a JWKS document from pasted text or a local file. A single JWK is accepted
+    /// in place of a full `{"keys": [...]}` document. Keys are never fetched over the network.
+    pub async fn from_source(source: JwksSource) -> Result<Jwks, String> {
+        let jwks_json = match source {
+            JwksSource::Text(text) => text,
+            JwksSource::File(path) => read_to_string(path)
+                .await
+                .map_err(|e| format!("Failed to read JWKS file: {}", e))?,
+        };
+
+        Self::from_json(&jwks_json)
+    }
+
+    pub fn from_json(jwks_json: &str) -> Result<Jwks, String> {
+        let json: serde_json::Value =
+            serde_json::from_str(jwks_json).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+
+        if json.get("keys").is_some() {
+            serde_json::from_value(json).map_err(|e| format!("Failed to parse JWKS: {}", e))
+        } else if json.get("kty").is_some() {
+            let jwk =
+                serde_json::from_value(json).map_err(|e| format!("Failed to parse JWK: {}", e))?;
+            Ok(Jwks { keys: vec![jwk] })
+        } else {
+            Err("Missing 'keys' key".to_string())
+        }
+    }
+}
+
+impl Jwk {
+    /// Whether this key can be used to verify tokens signed with `algorithm`, based on its
+    /// key type, curve, intended use and optional algorithm restriction
+    pub fn supports(&self, algorithm: AlgorithmType) -> bool {
+        let key_type_matches = match algorithm {
+            AlgorithmType::Hs256 | AlgorithmType::Hs384 | AlgorithmType::Hs512 => self.kty == "oct",
+            AlgorithmType::Rs256
+            | AlgorithmType::Rs384
+            | AlgorithmType::Rs512
+            | AlgorithmType::Ps256
+            | AlgorithmType::Ps384
+            | AlgorithmType::Ps512 => self.kty == "RSA",
+            AlgorithmType::Es256 => self.kty == "EC" && self.crv.as_deref() == Some("P-256"),
+            AlgorithmType::Es384 => self.kty == "EC" && self.crv.as_deref() == Some("P-384"),
+            AlgorithmType::Es512 => self.kty == "EC" && self.crv.as_deref() == Some("P-521"),
+            AlgorithmType::None => false,
+        };
+
+        let algorithm_matches = self
+            .alg
+            .as_ref()
+            .is_none_or(|jwk_algorithm| *jwk_algorithm == algorithm_name(algorithm));
+
+        key_type_matches && algorithm_matches && self.use_.as_deref() != Some("enc")
+    }
+
+    /// Decodes a base64url encoded key parameter such as `n`, `e`, `x`, `y` or `k`
+    pub fn parameter(&self, name: &str) -> Result<Vec<u8>, String> {
+        let value = match name {
+            "n" => &self.n,
+            "e" => &self.e,
+            "x" => &self.x,
+            "y" => &self.y,
+            "k" => &self.k,
+            _ => &None,
+        };
+
+        let value = value
+            .as_deref()
+            .ok_or_else(|| format!("Missing '{}' parameter", name))?;
+
+        URL_SAFE_NO_PAD
+            .decode(value.trim_end_matches('='))
+            .map_err(|e| format!("Invalid '{}' parameter: {}", name, e))
+    }
+
+    /// Builds an uncompressed SEC1 point from the `x` and `y` coordinates of an EC JWK
+    pub fn ec_point(&self) -> Result<Vec<u8>, String> {
+        let mut point = vec![0x04];
+        point.extend(self.parameter("x")?);
+        point.extend(self.parameter("y")?);
+        Ok(point)
+    }
+}
+
+/// Returns the JWA name of an algorithm as it appears in a header or JWK, e.g. "RS256"
+pub fn algorithm_name(algorithm: AlgorithmType) -> String {
+    match serde_json::to_value(algorithm) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => format!("{:?}", algorithm),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::NamedTempFile;
+
+    #[tokio::test]
+    async fn test_from_source_reads_text_and_files() {
+        let json = r#"{ "keys": [{ "kty": "oct", "kid": "key-1", "k": "bW9jay1zZWNyZXQ" }] }"#;
+
+        let jwks = Jwks::from_source(JwksSource::Text(json.to_string()))
+            .await
+            .unwrap();
+        assert_eq!(jwks.keys.len(), 1);
+        assert_eq!(jwks.keys[0].kid.as_deref(), Some("key-1"));
+
+        let temp_file = NamedTempFile::new().unwrap();
+        fs::write(&temp_file, json).unwrap();
+        let jwks = Jwks::from_source(JwksSource::File(temp_file.path().to_path_buf()))
+            .await
+            .unwrap();
+        assert_eq!(jwks.keys[0].parameter("k").unwrap(), b"mock-secret");
+    }
+
+    #[test]
+    fn test_from_json_accepts_a_single_jwk() {
+        let jwks = Jwks::from_json(r#"{ "kty": "EC", "crv": "P-256" }"#).unwrap();
+        assert_eq!(jwks.keys.len(), 1);
+        assert!(jwks.keys[0].supports(AlgorithmType::Es256));
+        assert!(!jwks.keys[0].supports(AlgorithmType::Es384));
+    }
+
+    #[test]
+    fn test_from_json_missing_keys_key() {
+        let result = Jwks::from_json(r#"{ "wrong_key": [] }"#);
+        assert_eq!(result.unwrap_err(), "Missing 'keys' key");
+    }
+
+    #[test]
+    fn test_supports_respects_alg_and_use() {
+        let jwks = Jwks::from_json(
+            r#"{ "keys": [
+                { "kty": "RSA", "alg": "RS256" },
+                { "kty": "RSA", "use": "enc" },
+                { "kty": "RSA" }
+            ] }"#,
+        )
+        .unwrap();
+
+        assert!(jwks.keys[0].supports(AlgorithmType::Rs256));
+        assert!(!jwks.keys[0].supports(AlgorithmType::Ps256));
+        assert!(!jwks.keys[1].supports(AlgorithmType::Rs256));
+        assert!(jwks.keys[2].supports(AlgorithmType::Ps512));
+        assert!(!jwks.keys[2].supports(AlgorithmType::Hs256));
+    }
+}
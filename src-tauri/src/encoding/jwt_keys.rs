@@ -0,0 +1,374 @@
+use crate::encoding::Jwk;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use hmac::digest::KeyInit;
+use hmac::Hmac;
+use jwt::{AlgorithmType, SigningAlgorithm, VerifyingAlgorithm};
+use rsa::pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey};
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::rand_core::OsRng;
+use rsa::signature::{RandomizedSigner, SignatureEncoding, Signer, Verifier};
+use rsa::{pkcs1v15, pss, BigUint, RsaPrivateKey, RsaPublicKey};
+use sha2::{Sha256, Sha384, Sha512};
+
+/// A key capable of verifying the signature of a JWT for a single algorithm.
+///
+/// HMAC keys are built from a shared secret, while RSA (RS*/PS*) and ECDSA (ES*)
+/// keys are built from a PEM encoded public key.
+pub enum JwtVerifyingKey {
+    Hs256(Hmac<Sha256>),
+    Hs384(Hmac<Sha384>),
+    Hs512(Hmac<Sha512>),
+    Rsa(AlgorithmType, RsaPublicKey),
+    Es256(p256::ecdsa::VerifyingKey),
+    Es384(p384::ecdsa::VerifyingKey),
+    Es512(p521::ecdsa::VerifyingKey),
+}
+
+impl JwtVerifyingKey {
+    /// Builds a verifying key for `algorithm` from an HMAC secret or PEM encoded public key.
+    ///
+    /// # Returns
+    /// * `Ok(Some(JwtVerifyingKey))` - The key was parsed for the algorithm
+    /// * `Ok(None)` - The algorithm cannot be verified (e.g. `none`)
+    /// * `Err(String)` - The key could not be parsed for the algorithm
+    pub fn from_key(algorithm: AlgorithmType, key: &str) -> Result<Option<Self>, String> {
+        let verifying_key = match algorithm {
+            AlgorithmType::Hs256 => Self::Hs256(hmac_key(key.as_bytes())?),
+            AlgorithmType::Hs384 => Self::Hs384(hmac_key(key.as_bytes())?),
+            AlgorithmType::Hs512 => Self::Hs512(hmac_key(key.as_bytes())?),
+            AlgorithmType::Rs256
+            | AlgorithmType::Rs384
+            | AlgorithmType::Rs512
+            | AlgorithmType::Ps256
+            | AlgorithmType::Ps384
+            | AlgorithmType::Ps512 => Self::Rsa(algorithm, rsa_public_key(key)?),
+            AlgorithmType::Es256 => Self::Es256(
+                p256::ecdsa::VerifyingKey::from_public_key_pem(key.trim())
+                    .map_err(|e| format!("Failed to parse P-256 public key: {}", e))?,
+            ),
+            AlgorithmType::Es384 => Self::Es384(
+                p384::ecdsa::VerifyingKey::from_public_key_pem(key.trim())
+                    .map_err(|e| format!("Failed to parse P-384 public key: {}", e))?,
+            ),
+            AlgorithmType::Es512 => {
+                let public_key = p521::PublicKey::from_public_key_pem(key.trim())
+                    .map_err(|e| format!("Failed to parse P-521 public key: {}", e))?;
+                Self::Es512(
+                    p521::ecdsa::VerifyingKey::from_affine(*public_key.as_affine())
+                        .map_err(|e| format!("Failed to parse P-521 public key: {}", e))?,
+                )
+            }
+            AlgorithmType::None => return Ok(None),
+        };
+
+        Ok(Some(verifying_key))
+    }
+
+    /// Builds a verifying key for `algorithm` from a JSON Web Key.
+    ///
+    /// Symmetric keys ("oct") are read from `k`, RSA keys from `n` and `e` and EC keys
+    /// from the `x` and `y` coordinates. Use `Jwk::supports` to check the key type first.
+    pub fn from_jwk(algorithm: AlgorithmType, jwk: &Jwk) -> Result<Option<Self>, String> {
+        let verifying_key = match algorithm {
+            AlgorithmType::Hs256 => Self::Hs256(hmac_key(&jwk.parameter("k")?)?),
+            AlgorithmType::Hs384 => Self::Hs384(hmac_key(&jwk.parameter("k")?)?),
+            AlgorithmType::Hs512 => Self::Hs512(hmac_key(&jwk.parameter("k")?)?),
+            AlgorithmType::Rs256
+            | AlgorithmType::Rs384
+            | AlgorithmType::Rs512
+            | AlgorithmType::Ps256
+            | AlgorithmType::Ps384
+            | AlgorithmType::Ps512 => {
+                let public_key = RsaPublicKey::new(
+                    BigUint::from_bytes_be(&jwk.parameter("n")?),
+                    BigUint::from_bytes_be(&jwk.parameter("e")?),
+                )
+                .map_err(|e| format!("Failed to parse RSA public key: {}", e))?;
+                Self::Rsa(algorithm, public_key)
+            }
+            AlgorithmType::Es256 => Self::Es256(
+                p256::ecdsa::VerifyingKey::from_sec1_bytes(&jwk.ec_point()?)
+                    .map_err(|e| format!("Failed to parse P-256 public key: {}", e))?,
+            ),
+            AlgorithmType::Es384 => Self::Es384(
+                p384::ecdsa::VerifyingKey::from_sec1_bytes(&jwk.ec_point()?)
+                    .map_err(|e| format!("Failed to parse P-384 public key: {}", e))?,
+            ),
+            AlgorithmType::Es512 => Self::Es512(
+                p521::ecdsa::VerifyingKey::from_sec1_bytes(&jwk.ec_point()?)
+                    .map_err(|e| format!("Failed to parse P-521 public key: {}", e))?,
+            ),
+            AlgorithmType::None => return Ok(None),
+        };
+
+        Ok(Some(verifying_key))
+    }
+}
+
+impl VerifyingAlgorithm for JwtVerifyingKey {
+    fn algorithm_type(&self) -> AlgorithmType {
+        match self {
+            Self::Hs256(_) => AlgorithmType::Hs256,
+            Self::Hs384(_) => AlgorithmType::Hs384,
+            Self::Hs512(_) => AlgorithmType::Hs512,
+            Self::Rsa(algorithm, _) => *algorithm,
+            Self::Es256(_) => AlgorithmType::Es256,
+            Self::Es384(_) => AlgorithmType::Es384,
+            Self::Es512(_) => AlgorithmType::Es512,
+        }
+    }
+
+    fn verify_bytes(
+        &self,
+        header: &str,
+        claims: &str,
+        signature: &[u8],
+    ) -> Result<bool, jwt::Error> {
+        let message = format!("{}.{}", header, claims);
+        let message = message.as_bytes();
+
+        // The HMAC implementations report a mismatch as an error rather than `false`
+        let verified = match self {
+            Self::Hs256(key) => key.verify_bytes(header, claims, signature).unwrap_or(false),
+            Self::Hs384(key) => key.verify_bytes(header, claims, signature).unwrap_or(false),
+            Self::Hs512(key) => key.verify_bytes(header, claims, signature).unwrap_or(false),
+            Self::Rsa(algorithm, key) => verify_rsa(*algorithm, key, message, signature),
+            Self::Es256(key) => {
+                verify_signature::<_, p256::ecdsa::Signature>(key, message, signature)
+            }
+            Self::Es384(key) => {
+                verify_signature::<_, p384::ecdsa::Signature>(key, message, signature)
+            }
+            Self::Es512(key) => {
+                verify_signature::<_, p521::ecdsa::Signature>(key, message, signature)
+            }
+        };
+
+        Ok(verified)
+    }
+}
+
+/// A key capable of signing a JWT for a single algorithm.
+///
+/// HMAC keys are built from a shared secret, while RSA (RS*/PS*) and ECDSA (ES*)
+/// keys are built from a PEM encoded private key.
+pub enum JwtSigningKey {
+    Hs256(Hmac<Sha256>),
+    Hs384(Hmac<Sha384>),
+    Hs512(Hmac<Sha512>),
+    Rsa(AlgorithmType, RsaPrivateKey),
+    Es256(p256::ecdsa::SigningKey),
+    Es384(p384::ecdsa::SigningKey),
+    Es512(p521::ecdsa::SigningKey),
+}
+
+impl JwtSigningKey {
+    /// Builds a signing key for `algorithm` from an HMAC secret or PEM encoded private key.
+    ///
+    /// RSA keys may be PKCS#8 ("PRIVATE KEY") or PKCS#1 ("RSA PRIVATE KEY") encoded and
+    /// EC keys may be PKCS#8 or SEC1 ("EC PRIVATE KEY") encoded.
+    pub fn from_key(algorithm: AlgorithmType, key: &str) -> Result<Self, String> {
+        let key = key.trim();
+
+        let signing_key = match algorithm {
+            AlgorithmType::Hs256 => Self::Hs256(hmac_key(key.as_bytes())?),
+            AlgorithmType::Hs384 => Self::Hs384(hmac_key(key.as_bytes())?),
+            AlgorithmType::Hs512 => Self::Hs512(hmac_key(key.as_bytes())?),
+            AlgorithmType::Rs256
+            | AlgorithmType::Rs384
+            | AlgorithmType::Rs512
+            | AlgorithmType::Ps256
+            | AlgorithmType::Ps384
+            | AlgorithmType::Ps512 => {
+                let private_key = RsaPrivateKey::from_pkcs8_pem(key)
+                    .or_else(|_| RsaPrivateKey::from_pkcs1_pem(key))
+                    .map_err(|e| format!("Failed to parse RSA private key: {}", e))?;
+                Self::Rsa(algorithm, private_key)
+            }
+            AlgorithmType::Es256 => {
+                let secret_key = p256::SecretKey::from_pkcs8_pem(key)
+                    .or_else(|_| p256::SecretKey::from_sec1_pem(key))
+                    .map_err(|e| format!("Failed to parse P-256 private key: {}", e))?;
+                Self::Es256(secret_key.into())
+            }
+            AlgorithmType::Es384 => {
+                let secret_key = p384::SecretKey::from_pkcs8_pem(key)
+                    .or_else(|_| p384::SecretKey::from_sec1_pem(key))
+                    .map_err(|e| format!("Failed to parse P-384 private key: {}", e))?;
+                Self::Es384(secret_key.into())
+            }
+            AlgorithmType::Es512 => {
+                let secret_key = p521::SecretKey::from_pkcs8_pem(key)
+                    .or_else(|_| p521::SecretKey::from_sec1_pem(key))
+                    .map_err(|e| format!("Failed to parse P-521 private key: {}", e))?;
+                Self::Es512(
+                    p521::ecdsa::SigningKey::from_bytes(&secret_key.to_bytes())
+                        .map_err(|e| format!("Failed to parse P-521 private key: {}", e))?,
+                )
+            }
+            AlgorithmType::None => {
+                return Err("Tokens cannot be signed with the \"none\" algorithm".to_string())
+            }
+        };
+
+        Ok(signing_key)
+    }
+}
+
+impl SigningAlgorithm for JwtSigningKey {
+    fn algorithm_type(&self) -> AlgorithmType {
+        match self {
+            Self::Hs256(_) => AlgorithmType::Hs256,
+            Self::Hs384(_) => AlgorithmType::Hs384,
+            Self::Hs512(_) => AlgorithmType::Hs512,
+            Self::Rsa(algorithm, _) => *algorithm,
+            Self::Es256(_) => AlgorithmType::Es256,
+            Self::Es384(_) => AlgorithmType::Es384,
+            Self::Es512(_) => AlgorithmType::Es512,
+        }
+    }
+
+    fn sign(&self, header: &str, claims: &str) -> Result<String, jwt::Error> {
+        let message = format!("{}.{}", header, claims);
+        let message = message.as_bytes();
+
+        let signature = match self {
+            Self::Hs256(key) => return key.sign(header, claims),
+            Self::Hs384(key) => return key.sign(header, claims),
+            Self::Hs512(key) => return key.sign(header, claims),
+            Self::Rsa(algorithm, key) => sign_rsa(*algorithm, key, message)?,
+            Self::Es256(key) => sign_message::<_, p256::ecdsa::Signature>(key, message)?,
+            Self::Es384(key) => sign_message::<_, p384::ecdsa::Signature>(key, message)?,
+            Self::Es512(key) => sign_message::<_, p521::ecdsa::Signature>(key, message)?,
+        };
+
+        Ok(URL_SAFE_NO_PAD.encode(signature))
+    }
+}
+
+fn hmac_key<M: KeyInit>(secret: &[u8]) -> Result<M, String> {
+    M::new_from_slice(secret).map_err(|e| format!("Failed to create HMAC key: {}", e))
+}
+
+/// Parses an RSA public key from either SPKI ("PUBLIC KEY") or PKCS#1 ("RSA PUBLIC KEY") PEM
+fn rsa_public_key(pem: &str) -> Result<RsaPublicKey, String> {
+    let pem = pem.trim();
+
+    RsaPublicKey::from_public_key_pem(pem)
+        .or_else(|_| RsaPublicKey::from_pkcs1_pem(pem))
+        .map_err(|e| format!("Failed to parse RSA public key: {}", e))
+}
+
+fn verify_rsa(
+    algorithm: AlgorithmType,
+    key: &RsaPublicKey,
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    let key = key.clone();
+
+    match algorithm {
+        AlgorithmType::Rs256 => verify_signature::<_, pkcs1v15::Signature>(
+            &pkcs1v15::VerifyingKey::<Sha256>::new(key),
+            message,
+            signature,
+        ),
+        AlgorithmType::Rs384 => verify_signature::<_, pkcs1v15::Signature>(
+            &pkcs1v15::VerifyingKey::<Sha384>::new(key),
+            message,
+            signature,
+        ),
+        AlgorithmType::Rs512 => verify_signature::<_, pkcs1v15::Signature>(
+            &pkcs1v15::VerifyingKey::<Sha512>::new(key),
+            message,
+            signature,
+        ),
+        AlgorithmType::Ps256 => verify_signature::<_, pss::Signature>(
+            &pss::VerifyingKey::<Sha256>::new(key),
+            message,
+            signature,
+        ),
+        AlgorithmType::Ps384 => verify_signature::<_, pss::Signature>(
+            &pss::VerifyingKey::<Sha384>::new(key),
+            message,
+            signature,
+        ),
+        AlgorithmType::Ps512 => verify_signature::<_, pss::Signature>(
+            &pss::VerifyingKey::<Sha512>::new(key),
+            message,
+            signature,
+        ),
+        _ => false,
+    }
+}
+
+/// Verifies `signature` over `message`, treating a malformed signature as a failed verification
+fn verify_signature<V, S>(key: &V, message: &[u8], signature: &[u8]) -> bool
+where
+    V: Verifier<S>,
+    S: for<'a> TryFrom<&'a [u8]>,
+{
+    S::try_from(signature).is_ok_and(|signature| key.verify(message, &signature).is_ok())
+}
+
+fn sign_rsa(
+    algorithm: AlgorithmType,
+    key: &RsaPrivateKey,
+    message: &[u8],
+) -> Result<Vec<u8>, jwt::Error> {
+    let key = key.clone();
+
+    match algorithm {
+        AlgorithmType::Rs256 => sign_message::<_, pkcs1v15::Signature>(
+            &pkcs1v15::SigningKey::<Sha256>::new(key),
+            message,
+        ),
+        AlgorithmType::Rs384 => sign_message::<_, pkcs1v15::Signature>(
+            &pkcs1v15::SigningKey::<Sha384>::new(key),
+            message,
+        ),
+        AlgorithmType::Rs512 => sign_message::<_, pkcs1v15::Signature>(
+            &pkcs1v15::SigningKey::<Sha512>::new(key),
+            message,
+        ),
+        AlgorithmType::Ps256 => sign_message_with_rng::<_, pss::Signature>(
+            &pss::BlindedSigningKey::<Sha256>::new(key),
+            message,
+        ),
+        AlgorithmType::Ps384 => sign_message_with_rng::<_, pss::Signature>(
+            &pss::BlindedSigningKey::<Sha384>::new(key),
+            message,
+        ),
+        AlgorithmType::Ps512 => sign_message_with_rng::<_, pss::Signature>(
+            &pss::BlindedSigningKey::<Sha512>::new(key),
+            message,
+        ),
+        algorithm => Err(jwt::Error::AlgorithmMismatch(
+            AlgorithmType::Rs256,
+            algorithm,
+        )),
+    }
+}
+
+// The `jwt` error type has no variant for signing failures, which only occur when a key
+// is too small for the chosen digest, so they are surfaced as an invalid signature
+fn sign_message<K, S>(key: &K, message: &[u8]) -> Result<Vec<u8>, jwt::Error>
+where
+    K: Signer<S>,
+    S: SignatureEncoding,
+{
+    key.try_sign(message)
+        .map(|signature| signature.to_vec())
+        .map_err(|_| jwt::Error::InvalidSignature)
+}
+
+fn sign_message_with_rng<K, S>(key: &K, message: &[u8]) -> Result<Vec<u8>, jwt::Error>
+where
+    K: RandomizedSigner<S>,
+    S: SignatureEncoding,
+{
+    key.try_sign_with_rng(&mut OsRng, message)
+        .map(|signature| signature.to_vec())
+        .map_err(|_| jwt::Error::InvalidSignature)
+}
@@ -1,3 +1,35 @@
+pub mod binary_encoding;
+pub mod decode_data;
 pub mod decode_jwt;
+pub mod decrypt_jwe;
+pub mod detect_encoding;
+pub mod encode_data;
+pub mod encode_jwt;
+pub mod inspect_jwe;
+pub mod jwe;
+pub mod jwe_keys;
+pub mod jwks;
+pub mod jwt_keys;
+pub mod validate_jwt_claims;
+pub mod verify_jwt;
+pub mod verify_jwt_with_jwks;
+pub mod web;
 
+#[cfg(test)]
+mod test_keys;
+
+pub use binary_encoding::*;
+pub use decode_data::*;
 pub use decode_jwt::*;
+pub use decrypt_jwe::*;
+pub use detect_encoding::*;
+pub use encode_data::*;
+pub use encode_jwt::*;
+pub use inspect_jwe::*;
+pub use jwe::*;
+pub use jwe_keys::*;
+pub use jwks::*;
+pub use jwt_keys::*;
+pub use validate_jwt_claims::*;
+pub use verify_jwt::*;
+pub use verify_jwt_with_jwks::*;
@@ -0,0 +1,42 @@
+/// Escapes text for safe use in HTML content and attribute values.
+///
+/// The characters `&`, `<`, `>`, `"` and `'` are replaced with their entities. All other
+/// characters, including non-ASCII characters, are kept as they are.
+///
+/// # Arguments
+/// * `input` - The text to escape
+///
+/// # Returns
+/// The escaped text
+///
+/// ```
+#[tauri::command]
+pub fn html_escape(input: &str) -> String {
+    let mut escaped = String::with_capacity(input.len());
+
+    for character in input.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(character),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escapes_special_characters() {
+        assert_eq!(
+            html_escape("<a href=\"/?a=1&b='2'\">caf\u{e9}</a>"),
+            "&lt;a href=&quot;/?a=1&amp;b=&#39;2&#39;&quot;&gt;caf\u{e9}&lt;/a&gt;"
+        );
+    }
+}
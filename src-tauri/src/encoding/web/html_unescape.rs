@@ -0,0 +1,100 @@
+// cspell:disable
+/// Named character references recognised when unescaping, besides numeric references
+#[rustfmt::skip]
+const NAMED_ENTITIES: [(&str, char); 40] = [
+    ("amp", '&'), ("lt", '<'), ("gt", '>'), ("quot", '"'), ("apos", '\''),
+    ("nbsp", '\u{a0}'), ("iexcl", '¡'), ("cent", '¢'), ("pound", '£'), ("curren", '¤'),
+    ("yen", '¥'), ("brvbar", '¦'), ("sect", '§'), ("copy", '©'), ("laquo", '«'),
+    ("not", '¬'), ("shy", '\u{ad}'), ("reg", '®'), ("deg", '°'), ("plusmn", '±'),
+    ("micro", 'µ'), ("para", '¶'), ("middot", '·'), ("raquo", '»'), ("iquest", '¿'),
+    ("times", '×'), ("divide", '÷'), ("ndash", '–'), ("mdash", '—'), ("lsquo", '‘'),
+    ("rsquo", '’'), ("ldquo", '“'), ("rdquo", '”'), ("bull", '•'), ("hellip", '…'),
+    ("euro", '€'), ("trade", '™'), ("larr", '←'), ("rarr", '→'), ("hearts", '♥'),
+];
+// cspell:enable
+
+/// Unescapes HTML entities back into text.
+///
+/// Decimal (`&#39;`) and hexadecimal (`&#x27;`) references are decoded along with common
+/// named references such as `&amp;`, `&nbsp;` and `&copy;`. Unknown or malformed references
+/// are left untouched.
+///
+/// # Arguments
+/// * `input` - The HTML text to unescape
+///
+/// # Returns
+/// The unescaped text
+///
+/// ```
+#[tauri::command]
+pub fn html_unescape(input: &str) -> String {
+    let mut unescaped = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(start) = rest.find('&') {
+        unescaped.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let decoded = rest[1..]
+            .find(';')
+            .and_then(|end| Some((decode_entity(&rest[1..=end])?, end + 2)));
+
+        match decoded {
+            Some((character, length)) => {
+                unescaped.push(character);
+                rest = &rest[length..];
+            }
+            None => {
+                unescaped.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    unescaped.push_str(rest);
+    unescaped
+}
+
+/// Decodes the name of a single reference, e.g. "amp", "#39" or "#x27"
+fn decode_entity(entity: &str) -> Option<char> {
+    if let Some(number) = entity.strip_prefix('#') {
+        let code_point = match number.strip_prefix(['x', 'X']) {
+            Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+            None => number.parse().ok()?,
+        };
+        return char::from_u32(code_point);
+    }
+
+    NAMED_ENTITIES
+        .iter()
+        .find(|(name, _)| *name == entity)
+        .map(|(_, character)| *character)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encoding::web::html_escape;
+
+    #[test]
+    fn test_unescapes_named_and_numeric_references() {
+        assert_eq!(
+            html_unescape("&lt;p&gt;Tom &amp; Jerry&#39;s &#x1F600; &copy;&nbsp;2025&lt;/p&gt;"),
+            "<p>Tom & Jerry's \u{1F600} ©\u{a0}2025</p>"
+        );
+    }
+
+    #[test]
+    fn test_leaves_unknown_and_malformed_references() {
+        assert_eq!(
+            html_unescape("AT&T &unknown; &#xZZ; &amp"),
+            "AT&T &unknown; &#xZZ; &amp"
+        );
+    }
+
+    #[test]
+    fn test_round_trips_escaped_text() {
+        let text = "<a href=\"/?a=1&b='2'\">caf\u{e9}</a>";
+        assert_eq!(html_unescape(&html_escape(text)), text);
+    }
+}
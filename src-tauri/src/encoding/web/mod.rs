@@ -0,0 +1,13 @@
+pub mod build_url;
+pub mod html_escape;
+pub mod html_unescape;
+pub mod parse_url;
+pub mod url_decode;
+pub mod url_encode;
+
+pub use build_url::*;
+pub use html_escape::*;
+pub use html_unescape::*;
+pub use parse_url::*;
+pub use url_decode::*;
+pub use url_encode::*;
@@ -0,0 +1,76 @@
+use crate::encoding::web::PercentEncodingMode;
+use percent_encoding::percent_decode_str;
+
+/// Decodes percent-encoded text.
+///
+/// Every `%XX` sequence is decoded and the result must be valid UTF-8. In `Form` mode '+'
+/// is decoded as a space, in the other modes it is kept as is.
+///
+/// # Arguments
+/// * `input` - The percent-encoded text
+/// * `mode` - The mode the text was encoded with
+///
+/// # Returns
+/// * `Ok(String)` - The decoded text
+/// * `Err(String)` - An error message if an escape sequence is malformed or the text is not UTF-8
+///
+/// ```
+#[tauri::command]
+pub fn url_decode(input: &str, mode: PercentEncodingMode) -> Result<String, String> {
+    let bytes = input.as_bytes();
+
+    if let Some(position) = (0..bytes.len()).find(|&i| {
+        bytes[i] == b'%'
+            && !(bytes.get(i + 1).is_some_and(u8::is_ascii_hexdigit)
+                && bytes.get(i + 2).is_some_and(u8::is_ascii_hexdigit))
+    }) {
+        return Err(format!(
+            "Invalid escape sequence at position {}. '%' must be followed by two hex digits",
+            position
+        ));
+    }
+
+    let input = match mode {
+        PercentEncodingMode::Form => input.replace('+', " "),
+        _ => input.to_string(),
+    };
+
+    percent_decode_str(&input)
+        .decode_utf8()
+        .map(|decoded| decoded.into_owned())
+        .map_err(|e| format!("Decoded text is not valid UTF-8: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decodes_escape_sequences() {
+        assert_eq!(
+            url_decode("caf%C3%A9%20%26+more", PercentEncodingMode::Component).unwrap(),
+            "caf\u{e9} &+more"
+        );
+        assert_eq!(
+            url_decode("caf%C3%A9%20%26+more", PercentEncodingMode::Form).unwrap(),
+            "caf\u{e9} & more"
+        );
+    }
+
+    #[test]
+    fn test_given_malformed_sequence_returns_error() {
+        assert_eq!(
+            url_decode("100%", PercentEncodingMode::Url).unwrap_err(),
+            "Invalid escape sequence at position 3. '%' must be followed by two hex digits"
+        );
+        assert!(url_decode("%zz", PercentEncodingMode::Url).is_err());
+    }
+
+    #[test]
+    fn test_given_invalid_utf8_returns_error() {
+        let result = url_decode("%C3%28", PercentEncodingMode::Component);
+        assert!(result
+            .unwrap_err()
+            .starts_with("Decoded text is not valid UTF-8"));
+    }
+}
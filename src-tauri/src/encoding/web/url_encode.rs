@@ -0,0 +1,94 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+
+/// Characters encoded in a URL component, matching JavaScript's `encodeURIComponent`
+const COMPONENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'!')
+    .remove(b'~')
+    .remove(b'*')
+    .remove(b'\'')
+    .remove(b'(')
+    .remove(b')');
+
+/// Characters encoded in a full URL, matching JavaScript's `encodeURI`. Reserved characters
+/// that delimit the parts of a URL are left as they are.
+const URL: &AsciiSet = &COMPONENT
+    .remove(b';')
+    .remove(b',')
+    .remove(b'/')
+    .remove(b'?')
+    .remove(b':')
+    .remove(b'@')
+    .remove(b'&')
+    .remove(b'=')
+    .remove(b'+')
+    .remove(b'$')
+    .remove(b'#');
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub enum PercentEncodingMode {
+    /// A single query value or path segment, reserved characters such as '/' and '&' are encoded
+    Component,
+    /// A complete URL, reserved characters are kept so the URL keeps its structure
+    Url,
+    /// `application/x-www-form-urlencoded`, like a component but spaces become '+'
+    Form,
+}
+
+/// Percent-encodes text for use in a URL.
+///
+/// Text is encoded as UTF-8 and every byte outside of the allowed set for the mode is
+/// written as `%XX`.
+///
+/// # Arguments
+/// * `input` - The text to encode
+/// * `mode` - Whether the text is a URL component, a full URL or form data
+///
+/// # Returns
+/// The percent-encoded text
+///
+/// ```
+#[tauri::command]
+pub fn url_encode(input: &str, mode: PercentEncodingMode) -> String {
+    match mode {
+        PercentEncodingMode::Component => utf8_percent_encode(input, COMPONENT).to_string(),
+        PercentEncodingMode::Url => utf8_percent_encode(input, URL).to_string(),
+        PercentEncodingMode::Form => {
+            url::form_urlencoded::byte_serialize(input.as_bytes()).collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "https://example.com/a path?q=caf\u{e9} & more#top";
+
+    #[test]
+    fn test_component_encodes_reserved_characters() {
+        assert_eq!(
+            url_encode(INPUT, PercentEncodingMode::Component),
+            "https%3A%2F%2Fexample.com%2Fa%20path%3Fq%3Dcaf%C3%A9%20%26%20more%23top"
+        );
+    }
+
+    #[test]
+    fn test_url_keeps_reserved_characters() {
+        assert_eq!(
+            url_encode(INPUT, PercentEncodingMode::Url),
+            "https://example.com/a%20path?q=caf%C3%A9%20&%20more#top"
+        );
+    }
+
+    #[test]
+    fn test_form_encodes_spaces_as_plus() {
+        assert_eq!(
+            url_encode("a b&c=d", PercentEncodingMode::Form),
+            "a+b%26c%3Dd"
+        );
+    }
+}
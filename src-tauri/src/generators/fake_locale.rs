@@ -0,0 +1,371 @@
+use serde::{Deserialize, Serialize};
+
+/// The locale fake data is generated for
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq)]
+pub enum FakeLocale {
+    #[default]
+    EnUs,
+    EnGb,
+    DeDe,
+    FrFr,
+    EsEs,
+}
+
+/// The names, places and formats of a locale. In patterns `#` is replaced with a random digit
+/// and `?` with a random uppercase letter, and in formats `{name}` placeholders are replaced
+/// with the generated part.
+#[derive(Debug)]
+pub struct LocaleData {
+    pub first_names: &'static [&'static str],
+    pub last_names: &'static [&'static str],
+    pub streets: &'static [&'static str],
+    pub cities: &'static [&'static str],
+    pub country: &'static str,
+    /// The format of the street address, with `{number}` and `{street}` placeholders
+    pub street_address: &'static str,
+    /// The format of the full address, with `{street_address}`, `{postal_code}` and `{city}`
+    /// placeholders
+    pub address: &'static str,
+    pub postal_code: &'static str,
+    pub phone_number: &'static str,
+    pub company_suffixes: &'static [&'static str],
+    /// The country code and BBAN pattern of IBANs, if the country uses them
+    pub iban: Option<(&'static str, &'static str)>,
+}
+
+// cspell:disable
+const EN_US: LocaleData = LocaleData {
+    first_names: &[
+        "James",
+        "Mary",
+        "Robert",
+        "Patricia",
+        "John",
+        "Jennifer",
+        "Michael",
+        "Linda",
+        "David",
+        "Elizabeth",
+        "William",
+        "Barbara",
+        "Richard",
+        "Susan",
+        "Joseph",
+        "Jessica",
+        "Thomas",
+        "Sarah",
+        "Charles",
+        "Karen",
+    ],
+    last_names: &[
+        "Smith",
+        "Johnson",
+        "Williams",
+        "Brown",
+        "Jones",
+        "Garcia",
+        "Miller",
+        "Davis",
+        "Rodriguez",
+        "Martinez",
+        "Hernandez",
+        "Lopez",
+        "Wilson",
+        "Anderson",
+        "Taylor",
+        "Moore",
+        "Jackson",
+        "Martin",
+        "Lee",
+        "Thompson",
+    ],
+    streets: &[
+        "Main Street",
+        "Oak Avenue",
+        "Maple Drive",
+        "Cedar Lane",
+        "Pine Street",
+        "Elm Street",
+        "Washington Avenue",
+        "Lake Road",
+        "Hillcrest Drive",
+        "Park Place",
+    ],
+    cities: &[
+        "Springfield",
+        "Riverside",
+        "Franklin",
+        "Greenville",
+        "Bristol",
+        "Clinton",
+        "Fairview",
+        "Salem",
+        "Madison",
+        "Georgetown",
+    ],
+    country: "United States",
+    street_address: "{number} {street}",
+    address: "{street_address}, {city} {postal_code}",
+    postal_code: "#####",
+    // 555-0100 to 555-0199 are reserved for fictional use
+    phone_number: "+1 (###) 555-01##",
+    company_suffixes: &["Inc.", "LLC", "Group", "& Co.", "Corporation"],
+    iban: None,
+};
+
+const EN_GB: LocaleData = LocaleData {
+    first_names: &[
+        "Oliver", "Amelia", "George", "Isla", "Harry", "Olivia", "Jack", "Emily", "Charlie",
+        "Poppy", "Thomas", "Ava", "Alfie", "Isabella", "Oscar", "Jessica", "William", "Lily",
+        "Henry", "Sophie",
+    ],
+    last_names: &[
+        "Smith", "Jones", "Taylor", "Brown", "Williams", "Wilson", "Johnson", "Davies", "Robinson",
+        "Wright", "Thompson", "Evans", "Walker", "White", "Roberts", "Green", "Hall", "Wood",
+        "Jackson", "Clarke",
+    ],
+    streets: &[
+        "High Street",
+        "Station Road",
+        "Church Lane",
+        "Victoria Road",
+        "Green Lane",
+        "Manor Road",
+        "Park Road",
+        "Queens Road",
+        "Mill Lane",
+        "Kings Road",
+    ],
+    cities: &[
+        "London",
+        "Manchester",
+        "Birmingham",
+        "Leeds",
+        "Bristol",
+        "Sheffield",
+        "Liverpool",
+        "Nottingham",
+        "Leicester",
+        "Brighton",
+    ],
+    country: "United Kingdom",
+    street_address: "{number} {street}",
+    address: "{street_address}, {city} {postal_code}",
+    postal_code: "??# #??",
+    // 07700 900000 to 07700 900999 are reserved for drama
+    phone_number: "+44 7700 900###",
+    company_suffixes: &["Ltd", "PLC", "LLP", "& Sons", "Holdings"],
+    iban: Some(("GB", "????##############")),
+};
+
+const DE_DE: LocaleData = LocaleData {
+    first_names: &[
+        "Lukas",
+        "Anna",
+        "Leon",
+        "Lena",
+        "Finn",
+        "Hannah",
+        "Paul",
+        "Mia",
+        "Jonas",
+        "Emma",
+        "Felix",
+        "Lea",
+        "Maximilian",
+        "Sophie",
+        "Elias",
+        "Marie",
+        "Noah",
+        "Laura",
+        "Jürgen",
+        "Käthe",
+    ],
+    last_names: &[
+        "Müller",
+        "Schmidt",
+        "Schneider",
+        "Fischer",
+        "Weber",
+        "Meyer",
+        "Wagner",
+        "Becker",
+        "Schulz",
+        "Hoffmann",
+        "Schäfer",
+        "Koch",
+        "Bauer",
+        "Richter",
+        "Klein",
+        "Wolf",
+        "Schröder",
+        "Neumann",
+        "Schwarz",
+        "Zimmermann",
+    ],
+    streets: &[
+        "Hauptstraße",
+        "Schulstraße",
+        "Gartenstraße",
+        "Bahnhofstraße",
+        "Dorfstraße",
+        "Bergstraße",
+        "Birkenweg",
+        "Lindenstraße",
+        "Kirchstraße",
+        "Waldstraße",
+    ],
+    cities: &[
+        "Berlin",
+        "Hamburg",
+        "München",
+        "Köln",
+        "Frankfurt am Main",
+        "Stuttgart",
+        "Düsseldorf",
+        "Leipzig",
+        "Dortmund",
+        "Bremen",
+    ],
+    country: "Deutschland",
+    street_address: "{street} {number}",
+    address: "{street_address}, {postal_code} {city}",
+    postal_code: "#####",
+    phone_number: "+49 30 #######",
+    company_suffixes: &["GmbH", "AG", "KG", "GmbH & Co. KG", "e.K."],
+    iban: Some(("DE", "##################")),
+};
+
+const FR_FR: LocaleData = LocaleData {
+    first_names: &[
+        "Gabriel", "Louise", "Léo", "Jade", "Raphaël", "Emma", "Louis", "Chloé", "Arthur", "Léa",
+        "Hugo", "Manon", "Jules", "Inès", "Adam", "Camille", "Lucas", "Zoé", "Théo", "Célia",
+    ],
+    last_names: &[
+        "Martin", "Bernard", "Dubois", "Thomas", "Robert", "Richard", "Petit", "Durand", "Leroy",
+        "Moreau", "Simon", "Laurent", "Lefèvre", "Michel", "Garcia", "David", "Bertrand", "Roux",
+        "Vincent", "Fournier",
+    ],
+    streets: &[
+        "rue de la Paix",
+        "avenue des Champs-Élysées",
+        "rue Victor Hugo",
+        "boulevard Voltaire",
+        "rue de la République",
+        "place de la Mairie",
+        "rue du Moulin",
+        "allée des Tilleuls",
+        "rue Pasteur",
+        "chemin des Vignes",
+    ],
+    cities: &[
+        "Paris",
+        "Marseille",
+        "Lyon",
+        "Toulouse",
+        "Nice",
+        "Nantes",
+        "Strasbourg",
+        "Montpellier",
+        "Bordeaux",
+        "Lille",
+    ],
+    country: "France",
+    street_address: "{number} {street}",
+    address: "{street_address}, {postal_code} {city}",
+    postal_code: "#####",
+    phone_number: "+33 6 ## ## ## ##",
+    company_suffixes: &["SA", "SARL", "SAS", "et Fils", "Groupe"],
+    iban: Some(("FR", "#######################")),
+};
+
+const ES_ES: LocaleData = LocaleData {
+    first_names: &[
+        "Hugo",
+        "Lucía",
+        "Martín",
+        "Sofía",
+        "Pablo",
+        "Martina",
+        "Alejandro",
+        "María",
+        "Lucas",
+        "Julia",
+        "Daniel",
+        "Paula",
+        "Mateo",
+        "Valeria",
+        "Álvaro",
+        "Emma",
+        "Manuel",
+        "Daniela",
+        "Adrián",
+        "Carmen",
+    ],
+    last_names: &[
+        "García",
+        "Rodríguez",
+        "González",
+        "Fernández",
+        "López",
+        "Martínez",
+        "Sánchez",
+        "Pérez",
+        "Gómez",
+        "Martín",
+        "Jiménez",
+        "Ruiz",
+        "Hernández",
+        "Díaz",
+        "Moreno",
+        "Muñoz",
+        "Álvarez",
+        "Romero",
+        "Alonso",
+        "Gutiérrez",
+    ],
+    streets: &[
+        "Calle Mayor",
+        "Calle Real",
+        "Avenida de la Constitución",
+        "Calle de Alcalá",
+        "Plaza de España",
+        "Calle del Sol",
+        "Paseo de la Castellana",
+        "Calle Nueva",
+        "Calle de la Iglesia",
+        "Avenida de Andalucía",
+    ],
+    cities: &[
+        "Madrid",
+        "Barcelona",
+        "Valencia",
+        "Sevilla",
+        "Zaragoza",
+        "Málaga",
+        "Murcia",
+        "Palma",
+        "Bilbao",
+        "Alicante",
+    ],
+    country: "España",
+    street_address: "{street} {number}",
+    address: "{street_address}, {postal_code} {city}",
+    postal_code: "#####",
+    phone_number: "+34 6## ### ###",
+    company_suffixes: &["S.L.", "S.A.", "y Asociados", "Hermanos", "Grupo"],
+    iban: Some(("ES", "####################")),
+};
+// cspell:enable
+
+impl FakeLocale {
+    pub fn data(&self) -> &'static LocaleData {
+        match self {
+            FakeLocale::EnUs => &EN_US,
+            FakeLocale::EnGb => &EN_GB,
+            FakeLocale::DeDe => &DE_DE,
+            FakeLocale::FrFr => &FR_FR,
+            FakeLocale::EsEs => &ES_ES,
+        }
+    }
+}
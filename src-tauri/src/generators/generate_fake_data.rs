@@ -0,0 +1,376 @@
+use crate::generators::{seeded_rng, FakeLocale, LocaleData};
+use chrono::{Days, NaiveDate};
+use rand::prelude::IndexedRandom;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum FakeField {
+    FirstName,
+    LastName,
+    FullName,
+    Email,
+    PhoneNumber,
+    StreetAddress,
+    City,
+    PostalCode,
+    Country,
+    Address,
+    Company,
+    Ipv4,
+    Ipv6,
+    CreditCard,
+    Date,
+    Iban,
+}
+
+/// Test card numbers published by payment processors, which are never issued to real cards
+const TEST_CARD_NUMBERS: [&str; 14] = [
+    // Visa
+    "4111111111111111",
+    "4012888888881881",
+    "4242424242424242",
+    "4000056655665556",
+    // Mastercard
+    "5555555555554444",
+    "5105105105105100",
+    "5200828282828210",
+    "2223003122003222",
+    // American Express
+    "378282246310005",
+    "371449635398431",
+    "378734493671000",
+    // Discover
+    "6011111111111117",
+    "6011000990139424",
+    // JCB
+    "3530111333300000",
+];
+
+// Reserved for documentation by RFC 2606
+const EMAIL_DOMAINS: [&str; 3] = ["example.com", "example.org", "example.net"];
+
+/// The person a record describes, so the names and email of one record match
+struct FakePerson {
+    first_name: &'static str,
+    last_name: &'static str,
+}
+
+/// Generates records of realistic fake data for test fixtures.
+///
+/// Each record has a value for every requested field, in the same order. Names, addresses,
+/// phone numbers, companies and IBANs follow the conventions of the locale and the name and
+/// email of a record belong to the same person. Emails use domains reserved for examples,
+/// phone numbers use ranges reserved for fiction where the country has them, IPv6 addresses
+/// use the documentation prefix and credit card numbers are Luhn-valid test numbers. Dates
+/// are ISO-8601 dates between 1950 and 2030.
+///
+/// The same seed always generates the same records. Without a seed the records are random.
+///
+/// # Arguments
+/// * `count` - The number of records to generate
+/// * `fields` - The fields of each record
+/// * `locale` - The locale of the data, en-US if not given
+/// * `seed` - The seed of the random number generator, if the output must be reproducible
+///
+/// # Returns
+/// * `Ok(Vec<Vec<String>>)` - The records, each with the values of the fields in order
+/// * `Err(String)` - An error message if a field is not available in the locale
+///
+/// ```
+#[tauri::command]
+pub fn generate_fake_data(
+    count: usize,
+    fields: Vec<FakeField>,
+    locale: Option<FakeLocale>,
+    seed: Option<u64>,
+) -> Result<Vec<Vec<String>>, String> {
+    let data = locale.unwrap_or_default().data();
+
+    if fields.contains(&FakeField::Iban) && data.iban.is_none() {
+        return Err(format!("IBANs are not used in {}", data.country));
+    }
+
+    let mut rng = seeded_rng(seed);
+
+    let records = (0..count)
+        .map(|_| {
+            let person = FakePerson {
+                first_name: data.first_names.choose(&mut rng).unwrap(),
+                last_name: data.last_names.choose(&mut rng).unwrap(),
+            };
+
+            fields
+                .iter()
+                .map(|field| generate_field(*field, &person, data, &mut rng))
+                .collect()
+        })
+        .collect();
+
+    Ok(records)
+}
+
+fn generate_field(
+    field: FakeField,
+    person: &FakePerson,
+    data: &LocaleData,
+    rng: &mut impl Rng,
+) -> String {
+    match field {
+        FakeField::FirstName => person.first_name.to_string(),
+        FakeField::LastName => person.last_name.to_string(),
+        FakeField::FullName => format!("{} {}", person.first_name, person.last_name),
+        FakeField::Email => generate_email(person, rng),
+        FakeField::PhoneNumber => fill_pattern(data.phone_number, rng),
+        FakeField::StreetAddress => generate_street_address(data, rng),
+        FakeField::City => data.cities.choose(rng).unwrap().to_string(),
+        FakeField::PostalCode => fill_pattern(data.postal_code, rng),
+        FakeField::Country => data.country.to_string(),
+        FakeField::Address => data
+            .address
+            .replace("{street_address}", &generate_street_address(data, rng))
+            .replace("{postal_code}", &fill_pattern(data.postal_code, rng))
+            .replace("{city}", data.cities.choose(rng).unwrap()),
+        FakeField::Company => format!(
+            "{} {}",
+            data.last_names.choose(rng).unwrap(),
+            data.company_suffixes.choose(rng).unwrap()
+        ),
+        FakeField::Ipv4 => Ipv4Addr::from(rng.random::<u32>()).to_string(),
+        FakeField::Ipv6 => {
+            // 2001:db8::/32 is reserved for documentation
+            let address = (0x2001_0db8_u128 << 96) | (rng.random::<u128>() >> 32);
+            Ipv6Addr::from(address).to_string()
+        }
+        FakeField::CreditCard => generate_credit_card(rng),
+        FakeField::Date => {
+            let start = NaiveDate::from_ymd_opt(1950, 1, 1).unwrap_or_default();
+            let date = start + Days::new(rng.random_range(0..29_220));
+            date.format("%Y-%m-%d").to_string()
+        }
+        FakeField::Iban => {
+            let (country_code, bban) = data.iban.unwrap_or_default();
+            generate_iban(country_code, &fill_pattern(bban, rng))
+        }
+    }
+}
+
+/// Replaces `#` with random digits and `?` with random uppercase letters
+fn fill_pattern(pattern: &str, rng: &mut impl Rng) -> String {
+    pattern
+        .chars()
+        .map(|c| match c {
+            '#' => char::from(b'0' + rng.random_range(0..10)),
+            '?' => char::from(b'A' + rng.random_range(0..26)),
+            c => c,
+        })
+        .collect()
+}
+
+fn generate_street_address(data: &LocaleData, rng: &mut impl Rng) -> String {
+    data.street_address
+        .replace("{number}", &rng.random_range(1..300).to_string())
+        .replace("{street}", data.streets.choose(rng).unwrap())
+}
+
+fn generate_email(person: &FakePerson, rng: &mut impl Rng) -> String {
+    let local_part = match rng.random_range(0..3) {
+        0 => format!("{}.{}", person.first_name, person.last_name),
+        1 => format!(
+            "{}{}",
+            person.first_name.chars().next().unwrap_or('x'),
+            person.last_name
+        ),
+        _ => format!("{}{}", person.first_name, rng.random_range(1..100)),
+    };
+
+    format!(
+        "{}@{}",
+        to_ascii_lowercase(&local_part),
+        EMAIL_DOMAINS.choose(rng).unwrap()
+    )
+}
+
+/// Lowercases and transliterates the letters of European names to ASCII, e.g. "Müller" to
+/// "mueller" and "José" to "jose"
+fn to_ascii_lowercase(text: &str) -> String {
+    let mut ascii = String::with_capacity(text.len());
+
+    for c in text.to_lowercase().chars() {
+        match c {
+            'ä' => ascii.push_str("ae"),
+            'ö' => ascii.push_str("oe"),
+            'ü' => ascii.push_str("ue"),
+            'ß' => ascii.push_str("ss"),
+            'à' | 'á' | 'â' | 'ã' | 'å' => ascii.push('a'),
+            'ç' => ascii.push('c'),
+            'è' | 'é' | 'ê' | 'ë' => ascii.push('e'),
+            'ì' | 'í' | 'î' | 'ï' => ascii.push('i'),
+            'ñ' => ascii.push('n'),
+            'ò' | 'ó' | 'ô' | 'õ' => ascii.push('o'),
+            'ù' | 'ú' | 'û' => ascii.push('u'),
+            c if c.is_ascii_alphanumeric() || c == '.' => ascii.push(c),
+            _ => {}
+        }
+    }
+
+    ascii
+}
+
+/// Picks a published test card number, as random numbers under real issuer prefixes could
+/// belong to real cards
+fn generate_credit_card(rng: &mut impl Rng) -> String {
+    TEST_CARD_NUMBERS.choose(rng).unwrap().to_string()
+}
+
+/// Builds an IBAN from the country code and BBAN by computing its ISO 7064 MOD 97-10 check
+/// digits
+fn generate_iban(country_code: &str, bban: &str) -> String {
+    let check_digits = 98 - iban_remainder(&format!("{}{}00", bban, country_code));
+    format!("{}{:02}{}", country_code, check_digits, bban)
+}
+
+/// The remainder of the number formed by replacing letters with 10 to 35, divided by 97
+fn iban_remainder(text: &str) -> u32 {
+    text.chars()
+        .filter_map(|c| c.to_digit(36))
+        .fold(0, |remainder, value| {
+            let shift = if value > 9 { 100 } else { 10 };
+            (remainder * shift + value) % 97
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_luhn_valid(number: &str) -> bool {
+        let sum: u32 = number
+            .chars()
+            .rev()
+            .filter_map(|c| c.to_digit(10))
+            .enumerate()
+            .map(|(index, digit)| match (index % 2 == 1, digit * 2) {
+                (true, doubled) if doubled > 9 => doubled - 9,
+                (true, doubled) => doubled,
+                (false, _) => digit,
+            })
+            .sum();
+
+        sum.is_multiple_of(10)
+    }
+
+    fn is_iban_valid(iban: &str) -> bool {
+        let (head, tail) = iban.split_at(4);
+        iban_remainder(&format!("{}{}", tail, head)) == 1
+    }
+
+    #[test]
+    fn test_generates_a_value_for_every_field() {
+        let fields = vec![
+            FakeField::FullName,
+            FakeField::Email,
+            FakeField::Address,
+            FakeField::Company,
+            FakeField::Ipv4,
+            FakeField::Ipv6,
+        ];
+        let records = generate_fake_data(20, fields, None, None).unwrap();
+
+        assert_eq!(records.len(), 20);
+        for record in records {
+            assert_eq!(record.len(), 6);
+            assert!(record.iter().all(|value| !value.is_empty()));
+            assert!(record[4].parse::<Ipv4Addr>().is_ok());
+            assert!(record[5].starts_with("2001:db8:"));
+        }
+    }
+
+    #[test]
+    fn test_names_and_emails_belong_to_the_same_person() {
+        let fields = vec![FakeField::LastName, FakeField::Email];
+        let records = generate_fake_data(20, fields, Some(FakeLocale::DeDe), None).unwrap();
+
+        for record in records {
+            let email = &record[1];
+            assert!(email.is_ascii(), "{}", email);
+            let domain = email.split('@').nth(1).unwrap();
+            assert!(EMAIL_DOMAINS.contains(&domain), "{}", email);
+        }
+
+        assert_eq!(to_ascii_lowercase("Jürgen.Müller"), "juergen.mueller");
+        assert_eq!(to_ascii_lowercase("Álvaro"), "alvaro");
+    }
+
+    #[test]
+    fn test_credit_card_numbers_are_luhn_valid_test_numbers() {
+        let records = generate_fake_data(100, vec![FakeField::CreditCard], None, None).unwrap();
+
+        for record in records {
+            assert!(TEST_CARD_NUMBERS.contains(&record[0].as_str()));
+        }
+        for number in TEST_CARD_NUMBERS {
+            assert!(is_luhn_valid(number), "{}", number);
+        }
+        assert!(!is_luhn_valid("4111111111111112"));
+    }
+
+    #[test]
+    fn test_ibans_are_valid_for_the_locale() {
+        // Example IBANs from the IBAN registry
+        assert!(is_iban_valid("GB29NWBK60161331926819"));
+        assert!(is_iban_valid("DE89370400440532013000"));
+
+        for (locale, country_code, length) in [
+            (FakeLocale::EnGb, "GB", 22),
+            (FakeLocale::DeDe, "DE", 22),
+            (FakeLocale::FrFr, "FR", 27),
+            (FakeLocale::EsEs, "ES", 24),
+        ] {
+            let records =
+                generate_fake_data(20, vec![FakeField::Iban], Some(locale), None).unwrap();
+            for record in records {
+                let iban = &record[0];
+                assert!(iban.starts_with(country_code), "{}", iban);
+                assert_eq!(iban.len(), length, "{}", iban);
+                assert!(is_iban_valid(iban), "{}", iban);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generates_dates_and_locale_formats() {
+        let fields = vec![
+            FakeField::Date,
+            FakeField::PhoneNumber,
+            FakeField::PostalCode,
+        ];
+        let records = generate_fake_data(20, fields, Some(FakeLocale::EnGb), None).unwrap();
+
+        for record in records {
+            assert!(NaiveDate::parse_from_str(&record[0], "%Y-%m-%d").is_ok());
+            assert!(record[1].starts_with("+44 7700 900"));
+            assert_eq!(record[2].len(), 7);
+        }
+    }
+
+    #[test]
+    fn test_same_seed_generates_same_records() {
+        let fields = vec![FakeField::FullName, FakeField::CreditCard, FakeField::Date];
+
+        let first = generate_fake_data(10, fields.clone(), None, Some(42)).unwrap();
+        let second = generate_fake_data(10, fields.clone(), None, Some(42)).unwrap();
+        let other = generate_fake_data(10, fields, None, Some(43)).unwrap();
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn test_given_iban_in_locale_without_ibans_returns_error() {
+        let result = generate_fake_data(1, vec![FakeField::Iban], Some(FakeLocale::EnUs), None);
+
+        assert_eq!(result.unwrap_err(), "IBANs are not used in United States");
+    }
+}
@@ -0,0 +1,338 @@
+use crate::encoding::BinaryEncoding;
+use hmac::digest::KeyInit;
+use hmac::{Hmac, Mac};
+use md5::Md5;
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use sha3::{Sha3_224, Sha3_256, Sha3_384, Sha3_512};
+use std::path::PathBuf;
+use tokio::fs::File;
+use tokio::io::AsyncReadExt;
+
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+pub enum HashAlgorithm {
+    Md5,
+    Sha1,
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+    Sha3_224,
+    Sha3_256,
+    Sha3_384,
+    Sha3_512,
+    Blake3,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum HashInput {
+    Text(String),
+    File(PathBuf),
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct HashResult {
+    pub digest: String,
+    pub matches: Option<bool>,
+}
+
+/// Generates a digest or HMAC of text or a file.
+///
+/// Files are read in chunks so files larger than the available memory can be hashed. When
+/// a key is given an HMAC is computed instead of a plain digest. When an expected checksum
+/// is given it is compared with the digest, accepting either hex or the chosen output
+/// encoding and ignoring anything after the first whitespace (as in `sha256sum` output).
+///
+/// # Arguments
+/// * `input` - The text (hashed as UTF-8) or path of the file to hash
+/// * `algorithm` - The hash algorithm to use
+/// * `hmac_key` - The secret key for an HMAC, if any
+/// * `encoding` - How the digest is encoded, e.g. hex or Base64
+/// * `expected` - The checksum to compare the digest against, if any
+///
+/// # Returns
+/// * `Ok(HashResult)` - The encoded digest and whether it matches the expected checksum
+/// * `Err(String)` - An error message if the file cannot be read or the key is invalid
+///
+/// ```
+#[tauri::command]
+pub async fn generate_hash(
+    input: HashInput,
+    algorithm: HashAlgorithm,
+    hmac_key: Option<String>,
+    encoding: BinaryEncoding,
+    expected: Option<String>,
+) -> Result<HashResult, String> {
+    let mut digester = match &hmac_key {
+        Some(key) => hmac_digester(algorithm, key.as_bytes())?,
+        None => digester(algorithm),
+    };
+
+    match input {
+        HashInput::Text(text) => digester.update(text.as_bytes()),
+        HashInput::File(path) => {
+            let mut file = File::open(&path)
+                .await
+                .map_err(|e| format!("Failed to open file: {}", e))?;
+            let mut buffer = vec![0; READ_BUFFER_SIZE];
+
+            loop {
+                let read = file
+                    .read(&mut buffer)
+                    .await
+                    .map_err(|e| format!("Failed to read file: {}", e))?;
+                if read == 0 {
+                    break;
+                }
+                digester.update(&buffer[..read]);
+            }
+        }
+    }
+
+    let digest = digester.finalize();
+
+    let matches = expected
+        .as_deref()
+        .and_then(|expected| expected.split_whitespace().next())
+        .map(|expected| {
+            [encoding, BinaryEncoding::Hex]
+                .iter()
+                .any(|encoding| encoding.decode(expected).is_ok_and(|bytes| bytes == digest))
+        });
+
+    Ok(HashResult {
+        digest: encoding.encode(&digest),
+        matches,
+    })
+}
+
+/// Incrementally hashes input so that large files never need to be held in memory
+trait Digester: Send {
+    fn update(&mut self, data: &[u8]);
+    fn finalize(self: Box<Self>) -> Vec<u8>;
+}
+
+struct PlainDigester<D>(D);
+
+impl<D: Digest + Send> Digester for PlainDigester<D> {
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(&mut self.0, data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        self.0.finalize().to_vec()
+    }
+}
+
+struct HmacDigester<M>(M);
+
+impl<M: Mac + Send> Digester for HmacDigester<M> {
+    fn update(&mut self, data: &[u8]) {
+        Mac::update(&mut self.0, data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        self.0.finalize().into_bytes().to_vec()
+    }
+}
+
+impl Digester for blake3::Hasher {
+    fn update(&mut self, data: &[u8]) {
+        blake3::Hasher::update(self, data);
+    }
+
+    fn finalize(self: Box<Self>) -> Vec<u8> {
+        blake3::Hasher::finalize(&self).as_bytes().to_vec()
+    }
+}
+
+fn digester(algorithm: HashAlgorithm) -> Box<dyn Digester> {
+    match algorithm {
+        HashAlgorithm::Md5 => Box::new(PlainDigester(Md5::new())),
+        HashAlgorithm::Sha1 => Box::new(PlainDigester(Sha1::new())),
+        HashAlgorithm::Sha224 => Box::new(PlainDigester(Sha224::new())),
+        HashAlgorithm::Sha256 => Box::new(PlainDigester(Sha256::new())),
+        HashAlgorithm::Sha384 => Box::new(PlainDigester(Sha384::new())),
+        HashAlgorithm::Sha512 => Box::new(PlainDigester(Sha512::new())),
+        HashAlgorithm::Sha3_224 => Box::new(PlainDigester(Sha3_224::new())),
+        HashAlgorithm::Sha3_256 => Box::new(PlainDigester(Sha3_256::new())),
+        HashAlgorithm::Sha3_384 => Box::new(PlainDigester(Sha3_384::new())),
+        HashAlgorithm::Sha3_512 => Box::new(PlainDigester(Sha3_512::new())),
+        HashAlgorithm::Blake3 => Box::new(blake3::Hasher::new()),
+    }
+}
+
+fn hmac_digester(algorithm: HashAlgorithm, key: &[u8]) -> Result<Box<dyn Digester>, String> {
+    let digester: Box<dyn Digester> = match algorithm {
+        HashAlgorithm::Md5 => Box::new(HmacDigester(hmac_key::<Hmac<Md5>>(key)?)),
+        HashAlgorithm::Sha1 => Box::new(HmacDigester(hmac_key::<Hmac<Sha1>>(key)?)),
+        HashAlgorithm::Sha224 => Box::new(HmacDigester(hmac_key::<Hmac<Sha224>>(key)?)),
+        HashAlgorithm::Sha256 => Box::new(HmacDigester(hmac_key::<Hmac<Sha256>>(key)?)),
+        HashAlgorithm::Sha384 => Box::new(HmacDigester(hmac_key::<Hmac<Sha384>>(key)?)),
+        HashAlgorithm::Sha512 => Box::new(HmacDigester(hmac_key::<Hmac<Sha512>>(key)?)),
+        HashAlgorithm::Sha3_224 => Box::new(HmacDigester(hmac_key::<Hmac<Sha3_224>>(key)?)),
+        HashAlgorithm::Sha3_256 => Box::new(HmacDigester(hmac_key::<Hmac<Sha3_256>>(key)?)),
+        HashAlgorithm::Sha3_384 => Box::new(HmacDigester(hmac_key::<Hmac<Sha3_384>>(key)?)),
+        HashAlgorithm::Sha3_512 => Box::new(HmacDigester(hmac_key::<Hmac<Sha3_512>>(key)?)),
+        // BLAKE3 has its own keyed mode which requires a key of exactly 32 bytes
+        HashAlgorithm::Blake3 => {
+            let key: [u8; 32] = key.try_into().map_err(|_| {
+                format!(
+                    "BLAKE3 keyed hashing requires a 32 byte key but got {} bytes",
+                    key.len()
+                )
+            })?;
+            Box::new(blake3::Hasher::new_keyed(&key))
+        }
+    };
+
+    Ok(digester)
+}
+
+fn hmac_key<M: KeyInit>(key: &[u8]) -> Result<M, String> {
+    M::new_from_slice(key).map_err(|e| format!("Failed to create HMAC key: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::NamedTempFile;
+
+    async fn hash_text(algorithm: HashAlgorithm, hmac_key: Option<&str>) -> String {
+        generate_hash(
+            HashInput::Text("The quick brown fox jumps over the lazy dog".to_string()),
+            algorithm,
+            hmac_key.map(String::from),
+            BinaryEncoding::Hex,
+            None,
+        )
+        .await
+        .unwrap()
+        .digest
+    }
+
+    // cspell:disable
+    #[tokio::test]
+    async fn test_digests_match_known_values() {
+        let cases = [
+            (HashAlgorithm::Md5, "9e107d9d372bb6826bd81d3542a419d6"),
+            (
+                HashAlgorithm::Sha1,
+                "2fd4e1c67a2d28fced849ee1bb76e7391b93eb12",
+            ),
+            (
+                HashAlgorithm::Sha256,
+                "d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592",
+            ),
+            (
+                HashAlgorithm::Sha3_256,
+                "69070dda01975c8c120c3aada1b282394e7f032fa9cf32f4cb2259a0897dfc04",
+            ),
+            (
+                HashAlgorithm::Blake3,
+                "2f1514181aadccd913abd94cfa592701a5686ab23f8df1dff1b74710febc6d4a",
+            ),
+        ];
+
+        for (algorithm, expected) in cases {
+            assert_eq!(
+                hash_text(algorithm, None).await,
+                expected,
+                "{:?}",
+                algorithm
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hmacs_match_known_values() {
+        assert_eq!(
+            hash_text(HashAlgorithm::Md5, Some("key")).await,
+            "80070713463e7749b90c2dc24911e275"
+        );
+        assert_eq!(
+            hash_text(HashAlgorithm::Sha256, Some("key")).await,
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+    // cspell:enable
+
+    #[tokio::test]
+    async fn test_blake3_keyed_hash_requires_32_byte_key() {
+        let result = generate_hash(
+            HashInput::Text("text".to_string()),
+            HashAlgorithm::Blake3,
+            Some("short".to_string()),
+            BinaryEncoding::Hex,
+            None,
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            "BLAKE3 keyed hashing requires a 32 byte key but got 5 bytes"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_hashes_files_larger_than_the_read_buffer() {
+        let contents = vec![b'a'; READ_BUFFER_SIZE * 3 + 17];
+        let temp_file = NamedTempFile::new().unwrap();
+        fs::write(&temp_file, &contents).unwrap();
+
+        let result = generate_hash(
+            HashInput::File(temp_file.path().to_path_buf()),
+            HashAlgorithm::Sha256,
+            None,
+            BinaryEncoding::Base64,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let expected = BinaryEncoding::Base64.encode(&Sha256::digest(&contents));
+        assert_eq!(result.digest, expected);
+    }
+
+    #[tokio::test]
+    async fn test_compares_against_expected_checksum() {
+        let hash = |expected: &str| {
+            generate_hash(
+                HashInput::Text("hello".to_string()),
+                HashAlgorithm::Sha1,
+                None,
+                BinaryEncoding::Base64,
+                Some(expected.to_string()),
+            )
+        };
+
+        // cspell:disable
+        let matching = hash("AAF4C61DDCC5E8A2DABEDE0F3B482CD9AEA9434D  hello.txt").await;
+        assert_eq!(matching.unwrap().matches, Some(true));
+
+        let matching = hash("qvTGHdzF6KLavt4PO0gs2a6pQ00=").await;
+        assert_eq!(matching.unwrap().matches, Some(true));
+        // cspell:enable
+
+        let mismatch = hash("0000").await;
+        assert_eq!(mismatch.unwrap().matches, Some(false));
+    }
+
+    #[tokio::test]
+    async fn test_given_missing_file_returns_error() {
+        let result = generate_hash(
+            HashInput::File(PathBuf::from("/does/not/exist")),
+            HashAlgorithm::Sha256,
+            None,
+            BinaryEncoding::Hex,
+            None,
+        )
+        .await;
+
+        assert!(result.unwrap_err().starts_with("Failed to open file"));
+    }
+}
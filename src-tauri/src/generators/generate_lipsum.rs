@@ -1,106 +1,412 @@
+use crate::generators::{
+    seeded_rng, Corpus, InlineStyle, LipsumCorpus, LipsumFormat, LipsumMarkup,
+};
 use rand::prelude::IndexedRandom;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum LipsumType {
     Words,
     Sentences,
     Paragraphs,
+    Characters,
+    Bytes,
 }
 
-// cspell:disable
-#[rustfmt::skip]
-const LIPSUM_WORDS: [&str; 68] = [
-    "lorem", "ipsum", "dolor", "sit", "amet", "consectetur", "adipiscing", "elit", "sed", "do",
-    "eiusmod", "tempor", "incididunt", "ut", "labore", "et", "dolore", "magna", "aliqua", "enim", "ad", "minim",
-    "veniam", "quis", "nostrud", "exercitation", "ullamco", "laboris", "nisi", "ut", "aliquip", "ex", "ea",
-    "commodo", "consequat", "duis", "aute", "irure", "dolor", "in", "reprehenderit", "in", "voluptate", "velit",
-    "esse", "cillum", "dolore", "eu", "fugiat", "nulla", "pariatur", "excepteur", "sint", "occaecat", "cupidatat",
-    "non", "proident", "sunt", "in", "culpa", "qui", "officia", "deserunt", "mollit", "anim", "id", "est", "laborum"
-];
-// cspell:enable
+/// The length of sentences and paragraphs and how sentences are written. Missing fields take
+/// their default values.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy)]
+#[serde(default)]
+pub struct LipsumShape {
+    pub min_words_per_sentence: usize,
+    pub max_words_per_sentence: usize,
+    pub min_sentences_per_paragraph: usize,
+    pub max_sentences_per_paragraph: usize,
+    pub commas: bool,
+    /// Whether the text starts with "Lorem ipsum dolor sit amet", only with the Lorem and
+    /// Cicero corpora
+    pub start_with_lorem_ipsum: bool,
+}
+
+impl Default for LipsumShape {
+    fn default() -> Self {
+        LipsumShape {
+            min_words_per_sentence: 5,
+            max_words_per_sentence: 15,
+            min_sentences_per_paragraph: 3,
+            max_sentences_per_paragraph: 8,
+            commas: false,
+            start_with_lorem_ipsum: false,
+        }
+    }
+}
+
+impl LipsumShape {
+    fn validate(&self) -> Result<(), String> {
+        let ranges = [
+            (
+                "words per sentence",
+                self.min_words_per_sentence,
+                self.max_words_per_sentence,
+            ),
+            (
+                "sentences per paragraph",
+                self.min_sentences_per_paragraph,
+                self.max_sentences_per_paragraph,
+            ),
+        ];
+
+        for (name, min, max) in ranges {
+            if min == 0 || min > max {
+                return Err(format!(
+                    "Invalid number of {}. The minimum must be at least 1 and no greater than the maximum",
+                    name
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// cspell:disable-next-line
+const LOREM_IPSUM_OPENING: [&str; 5] = ["lorem", "ipsum", "dolor", "sit", "amet"];
 
 /// Returns a random punctuation mark with weighted distribution:
 /// 70% period, 15% question mark, 15% exclamation mark
-fn get_random_punctuation() -> String {
-    let mut rng = rand::rng();
+fn get_random_punctuation(corpus: &Corpus, rng: &mut impl Rng) -> String {
     let random_value = rng.random_range(0..10);
+    let [period, question_mark, exclamation_mark] = corpus.punctuation;
 
     match random_value {
-        0..=6 => ".".to_string(),
-        7..=8 => "?".to_string(),
-        _ => "!".to_string(),
+        0..=6 => period.to_string(),
+        7..=8 => question_mark.to_string(),
+        _ => exclamation_mark.to_string(),
     }
 }
 
-/// Generates a random word from the LIPSUM_WORDS list with the first letter capitalized
-fn generate_random_word() -> String {
-    let mut rng = rand::rng();
-    let word = LIPSUM_WORDS.choose(&mut rng).unwrap().to_string();
-    let first_char = word.chars().next().unwrap_or(' ');
+/// The corpus, format, markup and shape lipsum text is generated with
+struct LipsumOptions {
+    corpus: Corpus,
+    format: LipsumFormat,
+    markup: LipsumMarkup,
+    shape: LipsumShape,
+}
+
+/// Capitalizes the first letter of a word
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
 
-    if !first_char.is_whitespace() {
-        let capitalized =
-            first_char.to_uppercase().collect::<String>() + &word[first_char.len_utf8()..];
-        capitalized
-    } else {
-        word
+    match chars.next() {
+        Some(first_char) if !first_char.is_whitespace() => {
+            first_char.to_uppercase().collect::<String>() + chars.as_str()
+        }
+        _ => word.to_string(),
     }
 }
 
-/// Generates a specified number of random capitalized words
-fn generate_words(count: usize) -> Vec<String> {
-    (0..count).map(|_| generate_random_word()).collect()
+/// Picks a random word from the corpus
+fn generate_random_word(options: &LipsumOptions, rng: &mut impl Rng) -> String {
+    options.corpus.words.choose(rng).unwrap().to_string()
+}
+
+/// Picks a specified number of random words, starting with "lorem ipsum dolor sit amet" when
+/// `opening` is set
+fn generate_words(
+    count: usize,
+    opening: bool,
+    options: &LipsumOptions,
+    rng: &mut impl Rng,
+) -> Vec<String> {
+    let opening_words = if opening {
+        &LOREM_IPSUM_OPENING[..count.min(LOREM_IPSUM_OPENING.len())]
+    } else {
+        &[]
+    };
+
+    opening_words
+        .iter()
+        .map(|word| word.to_string())
+        .chain((opening_words.len()..count).map(|_| generate_random_word(options, rng)))
+        .collect()
 }
 
-/// Generates a specified number of random sentences with varying word counts
-fn generate_sentences(count: usize) -> Vec<String> {
-    let mut rng = rand::rng();
+/// Writes words in sentence case, escaped for the output format and, when enabled,
+/// occasionally followed by a comma or emphasized or linked
+fn write_words(words: &[String], options: &LipsumOptions, rng: &mut impl Rng) -> String {
+    let corpus = &options.corpus;
+
+    words
+        .iter()
+        .enumerate()
+        .map(|(index, word)| {
+            let word = if index == 0 {
+                capitalize(word)
+            } else {
+                word.to_string()
+            };
+            let mut word = options.format.escape(&word);
+
+            if options.markup.inline && rng.random_range(0..12) == 0 {
+                let style = [
+                    InlineStyle::Emphasis,
+                    InlineStyle::Strong,
+                    InlineStyle::Link,
+                ]
+                .choose(rng)
+                .copied()
+                .unwrap_or(InlineStyle::Emphasis);
+                word = options.format.inline(style, &word);
+            }
+
+            if options.shape.commas && index + 1 < words.len() && rng.random_range(0..8) == 0 {
+                word.push_str(corpus.comma);
+            }
+
+            word
+        })
+        .collect::<Vec<String>>()
+        .join(corpus.word_separator)
+}
+
+/// Generates a specified number of random sentences with varying word counts. The first
+/// sentence starts with "Lorem ipsum dolor sit amet" when `opening` is set.
+fn generate_sentences(
+    count: usize,
+    opening: bool,
+    options: &LipsumOptions,
+    rng: &mut impl Rng,
+) -> Vec<String> {
+    let shape = &options.shape;
+
     (0..count)
-        .map(|_| {
-            let words = generate_words(rng.random_range(5..16));
-            words.join(" ") + &get_random_punctuation()
+        .map(|index| {
+            let word_count =
+                rng.random_range(shape.min_words_per_sentence..=shape.max_words_per_sentence);
+            let words = generate_words(word_count, opening && index == 0, options, rng);
+            write_words(&words, options, rng) + &get_random_punctuation(&options.corpus, rng)
         })
         .collect()
 }
 
+/// Generates a short title of 2 to 5 words in title case without punctuation or inline
+/// markup
+fn generate_title(options: &LipsumOptions, rng: &mut impl Rng) -> String {
+    let word_count = rng.random_range(2..6);
+
+    (0..word_count)
+        .map(|_| {
+            options
+                .format
+                .escape(&capitalize(&generate_random_word(options, rng)))
+        })
+        .collect::<Vec<String>>()
+        .join(options.corpus.word_separator)
+}
+
+/// Generates a bullet or numbered list of 3 to 6 short items
+fn generate_list(options: &LipsumOptions, rng: &mut impl Rng) -> String {
+    let ordered = rng.random_bool(0.5);
+    let item_count = rng.random_range(3..7);
+
+    let items = (0..item_count)
+        .map(|_| {
+            let word_count = rng.random_range(2..8);
+            let words = generate_words(word_count, false, options, rng);
+            write_words(&words, options, rng)
+        })
+        .collect::<Vec<String>>();
+
+    options.format.list(ordered, &items)
+}
+
+/// Generates plain sentences until they are at least `length` long, then cuts them to
+/// exactly `length` characters or bytes. The text ends with a period rather than a space
+/// when it is cut between words.
+fn generate_exact_length(
+    length: usize,
+    in_bytes: bool,
+    options: &LipsumOptions,
+    rng: &mut impl Rng,
+) -> String {
+    let plain = LipsumOptions {
+        corpus: Corpus {
+            words: options.corpus.words.clone(),
+            ..options.corpus
+        },
+        format: LipsumFormat::PlainText,
+        markup: LipsumMarkup::default(),
+        shape: options.shape,
+    };
+    let measure = |text: &str| {
+        if in_bytes {
+            text.len()
+        } else {
+            text.chars().count()
+        }
+    };
+
+    // The size is tracked as sentences are appended, as measuring the whole text every time
+    // would take quadratic time
+    let mut text = String::new();
+    let mut size = 0;
+    let mut opening = options.shape.start_with_lorem_ipsum;
+    while size < length {
+        if !text.is_empty() {
+            text.push_str(plain.corpus.word_separator);
+            size += measure(plain.corpus.word_separator);
+        }
+        let sentence = generate_sentences(1, opening, &plain, rng).concat();
+        size += measure(&sentence);
+        text.push_str(&sentence);
+        opening = false;
+    }
+
+    let mut truncated_size = 0;
+    let mut text = text
+        .chars()
+        .take_while(|c| {
+            truncated_size += if in_bytes { c.len_utf8() } else { 1 };
+            truncated_size <= length
+        })
+        .collect::<String>();
+    let size = measure(&text);
+
+    if text.ends_with(' ') {
+        text.pop();
+        text.push('.');
+    }
+    // A multi-byte character may not fit in the remaining bytes
+    text.push_str(&" ".repeat(length - size));
+
+    text
+}
+
 /// Generates lorem ipsum text based on the specified count and type.
 ///
+/// Sentences are written in sentence case, optionally with commas and starting with "Lorem
+/// ipsum dolor sit amet". The number of words per sentence and sentences per paragraph are
+/// picked at random within the ranges of the shape. The characters and bytes types generate
+/// filler text of exactly `count` characters or UTF-8 bytes, not counting any markup.
+///
+/// The same seed always generates the same text, which is useful for snapshot tests and
+/// fixtures. Without a seed the text is random.
+///
+/// The words come from the chosen corpus, the classic lorem ipsum words by default. Besides
+/// the built-in corpora (including non-Latin scripts for testing internationalization) a
+/// word list can be loaded from a file of words separated by whitespace.
+///
+/// The text can be written as plain text, HTML (wrapped in `<p>` elements) or Markdown.
+/// Markup optionally adds headings every three paragraphs, bullet and numbered lists between
+/// paragraphs, inline emphasis and links, and a title wrapping everything in a complete
+/// document. The count always refers to the words, sentences or paragraphs, not to the
+/// headings and lists around them.
+///
 /// # Arguments
-/// * `count` - The number of words, sentences, or paragraphs to generate
-/// * `lipsum_type` - The type of lorem ipsum text to generate (Words, Sentences, or Paragraphs)
+/// * `count` - The number of words, sentences, paragraphs, characters or bytes to generate
+/// * `lipsum_type` - The type of lorem ipsum text to generate
+/// * `corpus` - The words to generate the text from, lorem ipsum if not given
+/// * `format` - The output format, plain text if not given
+/// * `markup` - The structure to add to the text, none if not given
+/// * `shape` - The length of sentences and paragraphs, 5 to 15 words per sentence and 3 to 8
+///   sentences per paragraph without commas if not given
+/// * `seed` - The seed of the random number generator, if the output must be reproducible
 ///
 /// # Returns
-/// A string containing the generated lorem ipsum text
+/// * `Ok(String)` - The generated lorem ipsum text
+/// * `Err(String)` - An error message if the word list cannot be loaded or the shape is
+///   invalid or starts with lorem ipsum in another corpus than Lorem or Cicero
+///
+/// ```
 #[tauri::command]
-pub fn generate_lipsum(count: usize, lipsum_type: LipsumType) -> String {
+pub fn generate_lipsum(
+    count: usize,
+    lipsum_type: LipsumType,
+    corpus: Option<LipsumCorpus>,
+    format: Option<LipsumFormat>,
+    markup: Option<LipsumMarkup>,
+    shape: Option<LipsumShape>,
+    seed: Option<u64>,
+) -> Result<String, String> {
+    let shape = shape.unwrap_or_default();
+    shape.validate()?;
+
+    let corpus = corpus.unwrap_or_default();
+    if shape.start_with_lorem_ipsum && !matches!(corpus, LipsumCorpus::Lorem | LipsumCorpus::Cicero)
+    {
+        return Err(
+            "Only the Lorem and Cicero corpora can start with \"Lorem ipsum dolor sit amet\""
+                .to_string(),
+        );
+    }
+
+    let options = LipsumOptions {
+        corpus: corpus.load()?,
+        format: format.unwrap_or_default(),
+        markup: markup.unwrap_or_default(),
+        shape,
+    };
+
     if count == 0 {
-        return String::new();
+        return Ok(String::new());
     }
 
-    match lipsum_type {
+    let mut rng = seeded_rng(seed);
+    let separator = options.corpus.word_separator;
+    let opening = shape.start_with_lorem_ipsum;
+
+    let blocks = match lipsum_type {
         LipsumType::Words => {
-            let words = generate_words(count);
-            words.join(" ") + &get_random_punctuation()
+            let words = generate_words(count, opening, &options, &mut rng);
+            let text = write_words(&words, &options, &mut rng)
+                + &get_random_punctuation(&options.corpus, &mut rng);
+            vec![options.format.paragraph(&text)]
         }
         LipsumType::Sentences => {
-            let sentences = generate_sentences(count);
-            sentences.join(" ")
+            let sentences = generate_sentences(count, opening, &options, &mut rng);
+            vec![options.format.paragraph(&sentences.join(separator))]
         }
         LipsumType::Paragraphs => {
-            let mut rng = rand::rng();
-            let paragraphs = (0..count)
-                .map(|_| {
-                    let sentences_per_paragraph = rng.random_range(3..9);
-                    let sentences = generate_sentences(sentences_per_paragraph);
-                    sentences.join(" ")
-                })
-                .collect::<Vec<String>>();
+            let mut blocks = Vec::new();
 
-            paragraphs.join("\n\n")
+            for index in 0..count {
+                if options.markup.headings && index % 3 == 0 {
+                    let heading = generate_title(&options, &mut rng);
+                    blocks.push(options.format.heading(2, &heading));
+                }
+
+                let sentences_per_paragraph = rng.random_range(
+                    shape.min_sentences_per_paragraph..=shape.max_sentences_per_paragraph,
+                );
+                let sentences = generate_sentences(
+                    sentences_per_paragraph,
+                    opening && index == 0,
+                    &options,
+                    &mut rng,
+                );
+                blocks.push(options.format.paragraph(&sentences.join(separator)));
+
+                if options.markup.lists && index + 1 < count && rng.random_range(0..3) == 0 {
+                    blocks.push(generate_list(&options, &mut rng));
+                }
+            }
+
+            blocks
         }
-    }
+        LipsumType::Characters | LipsumType::Bytes => {
+            let in_bytes = matches!(lipsum_type, LipsumType::Bytes);
+            let text = generate_exact_length(count, in_bytes, &options, &mut rng);
+            vec![options.format.paragraph(&options.format.escape(&text))]
+        }
+    };
+
+    let title = options
+        .markup
+        .document
+        .then(|| generate_title(&options, &mut rng));
+
+    Ok(options.format.blocks(&blocks, title.as_deref()))
 }
 
 #[cfg(test)]
@@ -109,13 +415,13 @@ mod tests {
 
     #[test]
     fn test_empty_when_count_is_zero() {
-        let lipsum = generate_lipsum(0, LipsumType::Words);
+        let lipsum = generate_lipsum(0, LipsumType::Words, None, None, None, None, None).unwrap();
         assert_eq!(lipsum, "");
     }
 
     #[test]
     fn test_word_generation_format_and_count() {
-        let lipsum = generate_lipsum(10, LipsumType::Words);
+        let lipsum = generate_lipsum(10, LipsumType::Words, None, None, None, None, None).unwrap();
 
         // Check that the lipsum is not empty
         assert!(!lipsum.is_empty(), "Generated lipsum should not be empty");
@@ -140,7 +446,8 @@ mod tests {
 
     #[test]
     fn test_sentence_generation_format_and_count() {
-        let lipsum = generate_lipsum(10, LipsumType::Sentences);
+        let lipsum =
+            generate_lipsum(10, LipsumType::Sentences, None, None, None, None, None).unwrap();
 
         // Check that the lipsum is not empty
         assert!(!lipsum.is_empty(), "Generated lipsum should not be empty");
@@ -168,7 +475,8 @@ mod tests {
 
     #[test]
     fn test_paragraph_generation_format_and_count() {
-        let lipsum = generate_lipsum(10, LipsumType::Paragraphs);
+        let lipsum =
+            generate_lipsum(10, LipsumType::Paragraphs, None, None, None, None, None).unwrap();
 
         // Check that the lipsum is not empty
         assert!(!lipsum.is_empty(), "Generated lipsum should not be empty");
@@ -206,4 +514,287 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_same_seed_generates_same_text() {
+        for lipsum_type in [
+            LipsumType::Words,
+            LipsumType::Sentences,
+            LipsumType::Paragraphs,
+        ] {
+            let first =
+                generate_lipsum(20, lipsum_type.clone(), None, None, None, None, Some(42)).unwrap();
+            let second =
+                generate_lipsum(20, lipsum_type.clone(), None, None, None, None, Some(42)).unwrap();
+            let other = generate_lipsum(20, lipsum_type, None, None, None, None, Some(43)).unwrap();
+
+            assert_eq!(first, second);
+            assert_ne!(first, other);
+        }
+    }
+
+    #[test]
+    fn test_generates_text_from_the_chosen_corpus() {
+        let lipsum = generate_lipsum(
+            50,
+            LipsumType::Words,
+            Some(LipsumCorpus::Tech),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let tech = LipsumCorpus::Tech.load().unwrap();
+        for word in lipsum.trim_end_matches(['.', '?', '!']).split(' ') {
+            assert!(tech.words.contains(&word.to_lowercase()), "{}", word);
+        }
+
+        let lipsum = generate_lipsum(
+            5,
+            LipsumType::Sentences,
+            Some(LipsumCorpus::Cjk),
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        assert!(!lipsum.contains(' '));
+        assert_eq!(lipsum.matches(['。', '？', '！']).count(), 5);
+    }
+
+    #[test]
+    fn test_generates_html_paragraphs() {
+        let lipsum = generate_lipsum(
+            3,
+            LipsumType::Paragraphs,
+            None,
+            Some(LipsumFormat::Html),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let paragraphs = lipsum.lines().collect::<Vec<&str>>();
+
+        assert_eq!(paragraphs.len(), 3);
+        assert!(paragraphs
+            .iter()
+            .all(|p| p.starts_with("<p>") && p.ends_with("</p>")));
+    }
+
+    #[test]
+    fn test_generates_markdown_documents() {
+        let markup = LipsumMarkup {
+            headings: true,
+            lists: true,
+            inline: true,
+            document: true,
+        };
+        let lipsum = generate_lipsum(
+            30,
+            LipsumType::Paragraphs,
+            None,
+            Some(LipsumFormat::Markdown),
+            Some(markup),
+            None,
+            Some(7),
+        )
+        .unwrap();
+        let blocks = lipsum.split("\n\n").collect::<Vec<&str>>();
+
+        assert!(blocks[0].starts_with("# "));
+        assert_eq!(
+            blocks
+                .iter()
+                .filter(|block| block.starts_with("## "))
+                .count(),
+            10
+        );
+        assert!(blocks
+            .iter()
+            .any(|block| block.starts_with("- ") || block.starts_with("1. ")));
+        assert!(
+            lipsum.contains("](https://example.com)")
+                || lipsum.contains("**")
+                || lipsum.contains('_')
+        );
+
+        let paragraphs = blocks[1..]
+            .iter()
+            .filter(|block| {
+                !block.starts_with('#') && !block.starts_with("- ") && !block.starts_with("1. ")
+            })
+            .count();
+        assert_eq!(paragraphs, 30);
+    }
+
+    #[test]
+    fn test_generates_complete_html_documents() {
+        let markup = LipsumMarkup {
+            document: true,
+            ..LipsumMarkup::default()
+        };
+        let lipsum = generate_lipsum(
+            5,
+            LipsumType::Words,
+            None,
+            Some(LipsumFormat::Html),
+            Some(markup),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert!(lipsum.starts_with("<!DOCTYPE html>"));
+        assert!(lipsum.contains("<title>"));
+        assert!(lipsum.ends_with("</html>"));
+    }
+
+    #[test]
+    fn test_writes_sentences_in_sentence_case() {
+        let lipsum =
+            generate_lipsum(20, LipsumType::Sentences, None, None, None, None, None).unwrap();
+
+        for sentence in lipsum.split_inclusive(['.', '?', '!']) {
+            let words = sentence.split_whitespace().collect::<Vec<&str>>();
+            assert!(
+                words[0].chars().next().unwrap().is_uppercase(),
+                "{}",
+                sentence
+            );
+            assert!(
+                words[1..]
+                    .iter()
+                    .all(|word| word.chars().all(|c| !c.is_uppercase())),
+                "{}",
+                sentence
+            );
+        }
+    }
+
+    #[test]
+    fn test_follows_the_shape() {
+        let shape = LipsumShape {
+            min_words_per_sentence: 3,
+            max_words_per_sentence: 4,
+            min_sentences_per_paragraph: 2,
+            max_sentences_per_paragraph: 2,
+            commas: true,
+            start_with_lorem_ipsum: true,
+        };
+        let lipsum = generate_lipsum(
+            50,
+            LipsumType::Paragraphs,
+            None,
+            None,
+            None,
+            Some(shape),
+            Some(1),
+        )
+        .unwrap();
+
+        assert!(lipsum.starts_with("Lorem ipsum dolor"));
+        assert!(lipsum.contains(','));
+        for paragraph in lipsum.split("\n\n") {
+            let sentences = paragraph
+                .split_inclusive(['.', '?', '!'])
+                .collect::<Vec<&str>>();
+            assert_eq!(sentences.len(), 2, "{}", paragraph);
+            for sentence in sentences {
+                let word_count = sentence.split_whitespace().count();
+                assert!((3..=4).contains(&word_count), "{}", sentence);
+            }
+        }
+
+        let shape = LipsumShape {
+            commas: false,
+            ..shape
+        };
+        let lipsum =
+            generate_lipsum(2, LipsumType::Words, None, None, None, Some(shape), None).unwrap();
+        assert_eq!(lipsum.trim_end_matches(['.', '?', '!']), "Lorem ipsum");
+    }
+
+    #[test]
+    fn test_generates_exact_lengths() {
+        for count in [1, 7, 100, 1000, 100_000] {
+            let lipsum =
+                generate_lipsum(count, LipsumType::Characters, None, None, None, None, None)
+                    .unwrap();
+            assert_eq!(lipsum.chars().count(), count);
+
+            let lipsum = generate_lipsum(
+                count,
+                LipsumType::Bytes,
+                Some(LipsumCorpus::Cyrillic),
+                None,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+            assert_eq!(lipsum.len(), count);
+        }
+    }
+
+    #[test]
+    fn test_shape_fields_default_when_missing() {
+        let shape: LipsumShape = serde_json::from_str(r#"{"commas": true}"#).unwrap();
+
+        assert!(shape.commas);
+        assert_eq!(shape.min_words_per_sentence, 5);
+        assert_eq!(shape.max_sentences_per_paragraph, 8);
+    }
+
+    #[test]
+    fn test_given_invalid_shape_returns_error() {
+        let shape = LipsumShape {
+            min_words_per_sentence: 10,
+            max_words_per_sentence: 5,
+            ..LipsumShape::default()
+        };
+        let result = generate_lipsum(
+            1,
+            LipsumType::Sentences,
+            None,
+            None,
+            None,
+            Some(shape),
+            None,
+        );
+
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid number of words per sentence. The minimum must be at least 1 and no greater than the maximum"
+        );
+    }
+
+    #[test]
+    fn test_starts_with_lorem_ipsum_only_in_latin_corpora() {
+        let shape = LipsumShape {
+            start_with_lorem_ipsum: true,
+            ..LipsumShape::default()
+        };
+        let generate = |corpus| {
+            generate_lipsum(
+                5,
+                LipsumType::Words,
+                Some(corpus),
+                None,
+                None,
+                Some(shape),
+                Some(1),
+            )
+        };
+
+        assert!(generate(LipsumCorpus::Cicero)
+            .unwrap()
+            .starts_with("Lorem ipsum dolor sit amet"));
+        assert_eq!(
+            generate(LipsumCorpus::Cjk).unwrap_err(),
+            "Only the Lorem and Cicero corpora can start with \"Lorem ipsum dolor sit amet\""
+        );
+    }
 }
@@ -0,0 +1,355 @@
+use crate::encoding::BinaryEncoding;
+use crate::generators::PASSPHRASE_WORDS;
+use rand::prelude::{IndexedRandom, SliceRandom};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const DIGITS: &str = "0123456789";
+const SYMBOLS: &str = "!#$%&()*+,-./:;<=>?@[]^_{|}~";
+
+/// Characters that are easily confused with each other in many fonts
+const AMBIGUOUS: &str = "0O1lI|";
+
+const API_KEY_ALPHABET: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PasswordOptions {
+    pub length: usize,
+    pub lowercase: bool,
+    pub uppercase: bool,
+    pub digits: bool,
+    pub symbols: bool,
+    /// Whether to leave out characters that are easily confused, like `0` and `O`
+    pub exclude_ambiguous: bool,
+    /// Other characters to leave out
+    pub exclude: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct PassphraseOptions {
+    pub words: usize,
+    pub separator: String,
+    pub capitalize: bool,
+    /// Whether to append a digit to one of the words
+    pub include_number: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ApiKeyOptions {
+    /// The prefix identifying the kind of key, e.g. `sk_live_`
+    pub prefix: Option<String>,
+    /// The number of random alphanumeric characters after the prefix
+    pub length: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub enum SecretType {
+    Password(PasswordOptions),
+    Passphrase(PassphraseOptions),
+    ApiKey(ApiKeyOptions),
+    RandomBytes {
+        length: usize,
+        encoding: BinaryEncoding,
+    },
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct GeneratedSecret {
+    pub secret: String,
+    /// The estimated entropy in bits, assuming an attacker knows how the secret was generated
+    pub entropy: f64,
+}
+
+/// Generates passwords, passphrases, API keys or random bytes for use as secrets.
+///
+/// Passwords contain at least one character of every selected class. Passphrases are words
+/// from an embedded list of 1296 words, as in diceware. API keys are random alphanumeric
+/// characters after an optional prefix, and random bytes are encoded as e.g. hex or Base64.
+///
+/// The secrets come from the operating system seeded cryptographically secure generator
+/// and can never be seeded, unlike the other generators.
+///
+/// The entropy estimate is the number of bits an attacker who knows the options would have
+/// to guess, e.g. 10.3 bits per passphrase word. The prefix of an API key adds no entropy.
+///
+/// # Arguments
+/// * `count` - The number of secrets to generate
+/// * `secret_type` - The kind of secret to generate along with its options
+///
+/// # Returns
+/// * `Ok(Vec<GeneratedSecret>)` - The secrets, each with its estimated entropy
+/// * `Err(String)` - An error message if the options cannot generate a secret
+///
+/// ```
+#[tauri::command]
+pub fn generate_secret(
+    count: usize,
+    secret_type: SecretType,
+) -> Result<Vec<GeneratedSecret>, String> {
+    let mut rng = rand::rng();
+
+    match secret_type {
+        SecretType::Password(options) => {
+            let classes = get_character_classes(&options)?;
+            let alphabet_size = classes.iter().map(Vec::len).sum::<usize>();
+            let entropy = options.length as f64 * (alphabet_size as f64).log2();
+
+            Ok((0..count)
+                .map(|_| GeneratedSecret {
+                    secret: generate_password(options.length, &classes, &mut rng),
+                    entropy,
+                })
+                .collect())
+        }
+        SecretType::Passphrase(options) => {
+            if options.words == 0 {
+                return Err("A passphrase needs at least one word".to_string());
+            }
+
+            let mut entropy = options.words as f64 * (PASSPHRASE_WORDS.len() as f64).log2();
+            if options.include_number {
+                // The digit and the word it is appended to
+                entropy += 10f64.log2() + (options.words as f64).log2();
+            }
+
+            Ok((0..count)
+                .map(|_| GeneratedSecret {
+                    secret: generate_passphrase(&options, &mut rng),
+                    entropy,
+                })
+                .collect())
+        }
+        SecretType::ApiKey(options) => {
+            if options.length == 0 {
+                return Err("The length of an API key must be at least 1".to_string());
+            }
+
+            let alphabet = API_KEY_ALPHABET.chars().collect::<Vec<char>>();
+            let prefix = options.prefix.unwrap_or_default();
+            let entropy = options.length as f64 * (alphabet.len() as f64).log2();
+
+            Ok((0..count)
+                .map(|_| {
+                    let key = (0..options.length)
+                        .filter_map(|_| alphabet.choose(&mut rng))
+                        .collect::<String>();
+                    GeneratedSecret {
+                        secret: format!("{}{}", prefix, key),
+                        entropy,
+                    }
+                })
+                .collect())
+        }
+        SecretType::RandomBytes { length, encoding } => {
+            if length == 0 {
+                return Err("The number of random bytes must be at least 1".to_string());
+            }
+
+            Ok((0..count)
+                .map(|_| {
+                    let mut bytes = vec![0u8; length];
+                    rng.fill(&mut bytes[..]);
+                    GeneratedSecret {
+                        secret: encoding.encode(&bytes),
+                        entropy: length as f64 * 8.0,
+                    }
+                })
+                .collect())
+        }
+    }
+}
+
+/// Gets the characters of every selected class, without the excluded characters
+fn get_character_classes(options: &PasswordOptions) -> Result<Vec<Vec<char>>, String> {
+    let excluded = options.exclude.as_deref().unwrap_or_default();
+    let is_excluded =
+        |c: &char| excluded.contains(*c) || (options.exclude_ambiguous && AMBIGUOUS.contains(*c));
+
+    let classes = [
+        (options.lowercase, LOWERCASE),
+        (options.uppercase, UPPERCASE),
+        (options.digits, DIGITS),
+        (options.symbols, SYMBOLS),
+    ]
+    .into_iter()
+    .filter(|(selected, _)| *selected)
+    .map(|(_, characters)| {
+        characters
+            .chars()
+            .filter(|c| !is_excluded(c))
+            .collect::<Vec<char>>()
+    })
+    .collect::<Vec<Vec<char>>>();
+
+    if classes.is_empty() {
+        return Err("Select at least one character class".to_string());
+    }
+    if classes.iter().any(Vec::is_empty) {
+        return Err("Every character of a selected class is excluded".to_string());
+    }
+    if options.length < classes.len() {
+        return Err(format!(
+            "The password must be at least {} characters long to contain every selected class",
+            classes.len()
+        ));
+    }
+
+    Ok(classes)
+}
+
+fn generate_password(length: usize, classes: &[Vec<char>], rng: &mut impl Rng) -> String {
+    let alphabet = classes.concat();
+
+    // One character of every class, the rest from any class, in random order
+    let mut password: Vec<char> = Vec::with_capacity(length);
+    password.extend(
+        classes
+            .iter()
+            .filter_map(|class| class.choose(rng))
+            .copied(),
+    );
+    password.extend(
+        (classes.len()..length)
+            .filter_map(|_| alphabet.choose(rng))
+            .copied(),
+    );
+    password.shuffle(rng);
+
+    password.into_iter().collect()
+}
+
+fn generate_passphrase(options: &PassphraseOptions, rng: &mut impl Rng) -> String {
+    let mut words = (0..options.words)
+        .filter_map(|_| PASSPHRASE_WORDS.choose(rng))
+        .map(|word| {
+            if options.capitalize {
+                word[..1].to_uppercase() + &word[1..]
+            } else {
+                word.to_string()
+            }
+        })
+        .collect::<Vec<String>>();
+
+    if options.include_number {
+        let index = rng.random_range(0..words.len());
+        words[index].push_str(&rng.random_range(0..10).to_string());
+    }
+
+    words.join(&options.separator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn password_options(length: usize) -> PasswordOptions {
+        PasswordOptions {
+            length,
+            lowercase: true,
+            uppercase: true,
+            digits: true,
+            symbols: false,
+            exclude_ambiguous: true,
+            exclude: Some("abc".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_generates_passwords() {
+        let passwords = generate_secret(20, SecretType::Password(password_options(3))).unwrap();
+
+        for password in &passwords {
+            let secret = &password.secret;
+            assert_eq!(secret.len(), 3);
+            assert!(secret.chars().any(|c| c.is_ascii_lowercase()));
+            assert!(secret.chars().any(|c| c.is_ascii_uppercase()));
+            assert!(secret.chars().any(|c| c.is_ascii_digit()));
+            assert!(!secret.contains(|c| "abc0O1lI".contains(c)));
+        }
+        // 22 lowercase, 24 uppercase and 8 digits
+        assert!((passwords[0].entropy - 3.0 * 54f64.log2()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_given_impossible_password_options_returns_error() {
+        let mut options = password_options(2);
+        assert!(generate_secret(1, SecretType::Password(options.clone())).is_err());
+
+        options.length = 16;
+        options.exclude = Some(DIGITS.to_string());
+        assert!(generate_secret(1, SecretType::Password(options.clone())).is_err());
+
+        options.lowercase = false;
+        options.uppercase = false;
+        options.digits = false;
+        assert!(generate_secret(1, SecretType::Password(options)).is_err());
+    }
+
+    #[test]
+    fn test_generates_passphrases() {
+        let options = PassphraseOptions {
+            words: 5,
+            separator: "-".to_string(),
+            capitalize: true,
+            include_number: true,
+        };
+
+        let passphrase = &generate_secret(1, SecretType::Passphrase(options)).unwrap()[0];
+
+        let words = passphrase.secret.split('-').collect::<Vec<&str>>();
+        assert_eq!(words.len(), 5);
+        assert!(words
+            .iter()
+            .all(|word| word.starts_with(char::is_uppercase)));
+        assert_eq!(
+            words
+                .iter()
+                .filter(|word| word.ends_with(char::is_numeric))
+                .count(),
+            1
+        );
+        assert!((passphrase.entropy - (5.0 * 1296f64.log2() + 50f64.log2())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_generates_api_keys() {
+        let options = ApiKeyOptions {
+            prefix: Some("sk_test_".to_string()),
+            length: 32,
+        };
+
+        let keys = generate_secret(2, SecretType::ApiKey(options)).unwrap();
+
+        assert!(keys[0].secret.starts_with("sk_test_"));
+        assert_eq!(keys[0].secret.len(), 40);
+        assert!(keys[0].secret[8..]
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric()));
+        assert_ne!(keys[0].secret, keys[1].secret);
+        assert!((keys[0].entropy - 32.0 * 62f64.log2()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_generates_random_bytes() {
+        let secret_type = SecretType::RandomBytes {
+            length: 32,
+            encoding: BinaryEncoding::Hex,
+        };
+
+        let bytes = &generate_secret(1, secret_type).unwrap()[0];
+
+        assert_eq!(bytes.secret.len(), 64);
+        assert_eq!(bytes.entropy, 256.0);
+    }
+
+    #[test]
+    fn test_passphrase_words_are_unique() {
+        let mut words = PASSPHRASE_WORDS.to_vec();
+        words.sort();
+        words.dedup();
+
+        assert_eq!(words.len(), PASSPHRASE_WORDS.len());
+    }
+}
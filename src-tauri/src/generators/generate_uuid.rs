@@ -1,22 +1,75 @@
+use crate::generators::seeded_rng;
+use rand::rngs::StdRng;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use uuid::{ContextV7, Timestamp, Uuid};
+use std::sync::Mutex;
+use uuid::{Builder, Context, ContextV7, Timestamp, Uuid};
+
+/// Shared by every batch so v7 UUIDs sort in generation order, even across calls and within
+/// the same millisecond
+static CONTEXT_V7: Mutex<ContextV7> = Mutex::new(ContextV7::new());
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum UuidNamespace {
+    Dns,
+    Url,
+    Oid,
+    X500,
+    Custom(Uuid),
+}
+
+impl UuidNamespace {
+    pub fn uuid(&self) -> Uuid {
+        match self {
+            UuidNamespace::Dns => Uuid::NAMESPACE_DNS,
+            UuidNamespace::Url => Uuid::NAMESPACE_URL,
+            UuidNamespace::Oid => Uuid::NAMESPACE_OID,
+            UuidNamespace::X500 => Uuid::NAMESPACE_X500,
+            UuidNamespace::Custom(namespace) => *namespace,
+        }
+    }
+}
+
+/// The namespace and name a name-based (v3 or v5) UUID is derived from
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UuidName {
+    pub namespace: UuidNamespace,
+    pub name: String,
+}
 
 #[derive(Debug, Deserialize, Serialize)]
 pub enum UuidVersion {
+    V1,
+    V3(UuidName),
     V4,
+    V5(UuidName),
+    V6,
     V7,
+    V8,
+    Nil,
+    Max,
 }
 
 /// Generates one or more UUIDs based on the specified parameters.
 ///
-/// This function creates UUIDs in either version 4 (random) or version 7 (time-based) format.
-/// It allows customizing the output format with options for uppercase letters and hyphen inclusion.
+/// This function creates UUIDs in version 1 or 6 (time and node id), 3 or 5 (name-based, MD5 or
+/// SHA-1), 4 (random), 7 (time-based), 8 (custom, filled with random data) or the nil and max
+/// format. It allows customizing the output format with options for uppercase letters and
+/// hyphen inclusion.
+///
+/// Version 1 and 6 UUIDs in a batch share a random node id, and name-based UUIDs are
+/// deterministic so every UUID in a batch is the same. Version 7 UUIDs are strictly
+/// monotonic, both within a batch and across calls.
+///
+/// With a seed the random bits of version 4 and 8 UUIDs are reproducible, so the same seed
+/// always generates the same UUIDs.
 ///
 /// # Arguments
 /// * `number_of_uuids` - The number of UUIDs to generate
-/// * `version` - The UUID version to use (V4 or V7)
+/// * `version` - The UUID version to use, with the namespace and name for V3 and V5
 /// * `uppercase` - Whether to format the UUID in uppercase
 /// * `hyphens` - Whether to include hyphens in the UUID string
+/// * `seed` - The seed of the random number generator, if the output must be reproducible
 ///
 /// # Returns
 /// A vector of UUID strings formatted according to the specified parameters
@@ -28,33 +81,73 @@ pub fn generate_uuid(
     version: UuidVersion,
     uppercase: bool,
     hyphens: bool,
+    seed: Option<u64>,
 ) -> Vec<String> {
-    let mut uuids = Vec::with_capacity(number_of_uuids as usize);
+    let mut generator = UuidGenerator::new(version, uppercase, hyphens, seed);
 
-    for _ in 0..number_of_uuids {
-        let uuid = match version {
-            UuidVersion::V4 => Uuid::new_v4(),
-            UuidVersion::V7 => Uuid::new_v7(Timestamp::now(&ContextV7::new())),
+    (0..number_of_uuids).map(|_| generator.generate()).collect()
+}
+
+/// Generates formatted UUIDs one at a time, sharing the node id and clock sequence of time
+/// based UUIDs between them
+pub struct UuidGenerator {
+    version: UuidVersion,
+    uppercase: bool,
+    hyphens: bool,
+    node_id: [u8; 6],
+    context: Context,
+    rng: StdRng,
+}
+
+impl UuidGenerator {
+    pub fn new(version: UuidVersion, uppercase: bool, hyphens: bool, seed: Option<u64>) -> Self {
+        let mut rng = seeded_rng(seed);
+
+        UuidGenerator {
+            version,
+            uppercase,
+            hyphens,
+            node_id: random_node_id(&mut rng),
+            context: Context::new_random(),
+            rng,
+        }
+    }
+
+    pub fn generate(&mut self) -> String {
+        let uuid = match &self.version {
+            UuidVersion::V1 => Uuid::new_v1(Timestamp::now(&self.context), &self.node_id),
+            UuidVersion::V3(name) => Uuid::new_v3(&name.namespace.uuid(), name.name.as_bytes()),
+            UuidVersion::V4 => Builder::from_random_bytes(self.rng.random()).into_uuid(),
+            UuidVersion::V5(name) => Uuid::new_v5(&name.namespace.uuid(), name.name.as_bytes()),
+            UuidVersion::V6 => Uuid::new_v6(Timestamp::now(&self.context), &self.node_id),
+            UuidVersion::V7 => Uuid::new_v7(Timestamp::now(&CONTEXT_V7)),
+            UuidVersion::V8 => Uuid::new_v8(self.rng.random()),
+            UuidVersion::Nil => Uuid::nil(),
+            UuidVersion::Max => Uuid::max(),
         };
 
         let mut builder = Uuid::encode_buffer();
 
-        let uuid_str = if hyphens {
+        let uuid_str = if self.hyphens {
             uuid.hyphenated().encode_lower(&mut builder)
         } else {
             uuid.simple().encode_lower(&mut builder)
         };
 
-        let uuid_str = if uppercase {
+        if self.uppercase {
             uuid_str.to_uppercase()
         } else {
             uuid_str.to_string()
-        };
-
-        uuids.push(uuid_str);
+        }
     }
+}
 
-    uuids
+/// Generates a random node id with the multicast bit set, as RFC 9562 recommends when no
+/// MAC address is used, so it can never collide with a real network card
+fn random_node_id(rng: &mut impl Rng) -> [u8; 6] {
+    let mut node_id: [u8; 6] = rng.random();
+    node_id[0] |= 0x01;
+    node_id
 }
 
 #[cfg(test)]
@@ -65,13 +158,13 @@ mod tests {
 
     #[test]
     fn test_generates_one_uuid() {
-        let uuids = generate_uuid(1, UuidVersion::V4, false, true);
+        let uuids = generate_uuid(1, UuidVersion::V4, false, true, None);
         assert_eq!(uuids.len(), 1);
     }
 
     #[test]
     fn test_generates_multiple_unique_uuids() {
-        let uuids = generate_uuid(100, UuidVersion::V4, false, true);
+        let uuids = generate_uuid(100, UuidVersion::V4, false, true, None);
         let unique_uuids: Vec<&String> = uuids
             .iter()
             .collect::<HashSet<&String>>()
@@ -83,7 +176,7 @@ mod tests {
 
     #[test]
     fn test_generates_uuid_v4() {
-        let uuids = generate_uuid(1, UuidVersion::V4, false, false);
+        let uuids = generate_uuid(1, UuidVersion::V4, false, false, None);
         assert_eq!(uuids.len(), 1);
         assert_eq!(uuids[0].len(), 32);
 
@@ -93,9 +186,20 @@ mod tests {
         assert_eq!(parsed.get_variant(), uuid::Variant::RFC4122);
     }
 
+    #[test]
+    fn test_same_seed_generates_same_uuids() {
+        let first = generate_uuid(10, UuidVersion::V4, false, true, Some(42));
+        let second = generate_uuid(10, UuidVersion::V4, false, true, Some(42));
+        let other = generate_uuid(10, UuidVersion::V4, false, true, Some(43));
+
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(Uuid::parse_str(&first[0]).unwrap().get_version_num(), 4);
+    }
+
     #[test]
     fn test_generates_uuid_v7() {
-        let uuids = generate_uuid(1, UuidVersion::V7, false, false);
+        let uuids = generate_uuid(1, UuidVersion::V7, false, false, None);
         assert_eq!(uuids.len(), 1);
         assert_eq!(uuids[0].len(), 32);
 
@@ -115,9 +219,105 @@ mod tests {
         assert!(timestamp_secs > now - 60);
     }
 
+    #[test]
+    fn test_generates_monotonic_uuid_v7_batches() {
+        let mut uuids = generate_uuid(10_000, UuidVersion::V7, false, true, None);
+        uuids.extend(generate_uuid(10_000, UuidVersion::V7, false, true, None));
+
+        assert!(uuids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_generates_time_based_uuids_with_a_shared_node_id() {
+        for (version, expected_version) in [(UuidVersion::V1, 1), (UuidVersion::V6, 6)] {
+            let uuids = generate_uuid(2, version, false, true, None);
+            let first = Uuid::parse_str(&uuids[0]).unwrap();
+            let second = Uuid::parse_str(&uuids[1]).unwrap();
+
+            assert_eq!(first.get_version_num(), expected_version);
+            assert_eq!(first.get_variant(), uuid::Variant::RFC4122);
+            assert_ne!(first, second);
+            assert_eq!(first.get_node_id(), second.get_node_id());
+            assert_eq!(first.get_node_id().unwrap()[0] & 0x01, 0x01);
+
+            let (timestamp_secs, _) = first.get_timestamp().unwrap().to_unix();
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+            assert!(timestamp_secs <= now);
+            assert!(timestamp_secs > now - 60);
+        }
+    }
+
+    #[test]
+    fn test_generates_name_based_uuids() {
+        let name = |namespace| UuidName {
+            namespace,
+            name: "www.example.com".to_string(),
+        };
+
+        let uuids = generate_uuid(
+            2,
+            UuidVersion::V3(name(UuidNamespace::Dns)),
+            false,
+            true,
+            None,
+        );
+        assert_eq!(uuids, vec!["5df41881-3aed-3515-88a7-2f4a814cf09e"; 2]);
+
+        let uuids = generate_uuid(
+            1,
+            UuidVersion::V5(name(UuidNamespace::Dns)),
+            false,
+            true,
+            None,
+        );
+        assert_eq!(uuids[0], "2ed6657d-e927-568b-95e1-2665a8aea6a2");
+
+        let custom = UuidNamespace::Custom(Uuid::NAMESPACE_DNS);
+        let uuids = generate_uuid(1, UuidVersion::V5(name(custom)), true, false, None);
+        assert_eq!(uuids[0], "2ED6657DE927568B95E12665A8AEA6A2");
+    }
+
+    #[test]
+    fn test_generates_custom_nil_and_max_uuids() {
+        let uuids = generate_uuid(1, UuidVersion::V8, false, true, None);
+        let parsed = Uuid::parse_str(&uuids[0]).unwrap();
+        assert_eq!(parsed.get_version(), Some(uuid::Version::Custom));
+        assert_eq!(parsed.get_variant(), uuid::Variant::RFC4122);
+
+        let uuids = generate_uuid(1, UuidVersion::Nil, false, true, None);
+        assert_eq!(uuids[0], "00000000-0000-0000-0000-000000000000");
+
+        let uuids = generate_uuid(1, UuidVersion::Max, true, false, None);
+        assert_eq!(uuids[0], "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF");
+    }
+
+    #[test]
+    fn test_deserializes_versions_from_the_frontend() {
+        let version: UuidVersion = serde_json::from_str(r#""V4""#).unwrap();
+        assert!(matches!(version, UuidVersion::V4));
+
+        let version: UuidVersion = serde_json::from_str(
+            r#"{ "V5": { "namespace": { "Custom": "6ba7b810-9dad-11d1-80b4-00c04fd430c8" }, "name": "example" } }"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            version,
+            UuidVersion::V5(UuidName { namespace: UuidNamespace::Custom(namespace), .. })
+                if namespace == Uuid::NAMESPACE_DNS
+        ));
+
+        let result = serde_json::from_str::<UuidVersion>(
+            r#"{ "V3": { "namespace": { "Custom": "not-a-uuid" }, "name": "example" } }"#,
+        );
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_format_with_hyphens() {
-        let uuids = generate_uuid(1, UuidVersion::V7, false, true);
+        let uuids = generate_uuid(1, UuidVersion::V7, false, true, None);
         let uuid = &uuids[0];
 
         // Count the number of hyphens in the UUID
@@ -132,7 +332,7 @@ mod tests {
 
     #[test]
     fn test_format_without_hyphens() {
-        let uuids = generate_uuid(1, UuidVersion::V7, false, false);
+        let uuids = generate_uuid(1, UuidVersion::V7, false, false, None);
         let uuid = &uuids[0];
 
         assert_eq!(uuid.len(), 32);
@@ -141,7 +341,7 @@ mod tests {
 
     #[test]
     fn test_format_uppercase() {
-        let uuids = generate_uuid(1, UuidVersion::V7, true, false);
+        let uuids = generate_uuid(1, UuidVersion::V7, true, false, None);
         let uuid = &uuids[0];
 
         assert!(uuid.chars().all(|c| !c.is_alphabetic() || c.is_uppercase()));
@@ -150,7 +350,7 @@ mod tests {
 
     #[test]
     fn test_format_lowercase() {
-        let uuids = generate_uuid(1, UuidVersion::V7, false, false);
+        let uuids = generate_uuid(1, UuidVersion::V7, false, false, None);
         let uuid = &uuids[0];
 
         assert_eq!(uuid.len(), 32);
@@ -0,0 +1,122 @@
+use crate::generators::ids::radix;
+use rand::Rng;
+use sha3::{Digest, Sha3_512};
+
+const BASE36: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+pub const CUID2_LENGTH: usize = 24;
+const MAX_CUID2_LENGTH: usize = 32;
+const FINGERPRINT_LENGTH: usize = 32;
+
+/// Generates CUID2s following the reference implementation: a random lowercase letter
+/// followed by a SHA3-512 hash of the time, a random salt, a counter and a per generator
+/// fingerprint, written in base 36
+pub struct Cuid2Generator {
+    length: usize,
+    counter: u64,
+    fingerprint: String,
+}
+
+impl Cuid2Generator {
+    pub fn new(length: usize) -> Result<Self, String> {
+        if !(2..=MAX_CUID2_LENGTH).contains(&length) {
+            return Err(format!(
+                "The length must be between 2 and {}",
+                MAX_CUID2_LENGTH
+            ));
+        }
+
+        let mut fingerprint = hash(&entropy(FINGERPRINT_LENGTH));
+        fingerprint.truncate(FINGERPRINT_LENGTH);
+
+        Ok(Cuid2Generator {
+            length,
+            counter: rand::rng().random_range(0..476_782_367),
+            fingerprint,
+        })
+    }
+
+    pub fn generate(&mut self, timestamp: u64) -> String {
+        let first_letter = BASE36[rand::rng().random_range(10..36)] as char;
+
+        let input = format!(
+            "{}{}{}{}",
+            base36(timestamp),
+            entropy(self.length),
+            base36(self.counter),
+            self.fingerprint
+        );
+        self.counter += 1;
+
+        let hashed = hash(&input);
+        let body = hashed.get(1..self.length).unwrap_or_default();
+
+        format!("{}{}", first_letter, body)
+    }
+}
+
+/// Checks that `id` has the shape of a CUID2. CUID2s are opaque and carry no timestamp.
+pub fn validate_cuid2(id: &str) -> Result<(), String> {
+    let starts_with_letter = id.chars().next().is_some_and(|c| c.is_ascii_lowercase());
+    let is_base36 = id.bytes().all(|byte| BASE36.contains(&byte));
+
+    if (2..=MAX_CUID2_LENGTH).contains(&id.len()) && starts_with_letter && is_base36 {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid CUID2. Expected a lowercase letter followed by 1 to {} lowercase letters or digits",
+            MAX_CUID2_LENGTH - 1
+        ))
+    }
+}
+
+/// Hashes `input` with SHA3-512 and writes it in base 36, dropping the first, biased, digit
+fn hash(input: &str) -> String {
+    let digest = Sha3_512::digest(input.as_bytes());
+    radix::encode(&digest, BASE36, 0)[1..].to_string()
+}
+
+fn entropy(length: usize) -> String {
+    let mut rng = rand::rng();
+    (0..length)
+        .map(|_| BASE36[rng.random_range(0..36)] as char)
+        .collect()
+}
+
+fn base36(value: u64) -> String {
+    radix::encode(&value.to_be_bytes(), BASE36, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generates_unique_ids_of_the_requested_length() {
+        let mut generator = Cuid2Generator::new(CUID2_LENGTH).unwrap();
+        let first = generator.generate(1_700_000_000_000);
+        let second = generator.generate(1_700_000_000_000);
+
+        assert_eq!(first.len(), 24);
+        assert_ne!(first, second);
+        assert!(validate_cuid2(&first).is_ok());
+
+        let mut generator = Cuid2Generator::new(10).unwrap();
+        assert_eq!(generator.generate(1_700_000_000_000).len(), 10);
+    }
+
+    #[test]
+    fn test_rejects_invalid_lengths_and_ids() {
+        assert!(Cuid2Generator::new(1).is_err());
+        assert!(Cuid2Generator::new(33).is_err());
+        assert!(validate_cuid2("1abc").is_err());
+        assert!(validate_cuid2("Abc").is_err());
+        assert!(validate_cuid2("tz4a98xxat96iws9zmbrgj3a").is_ok());
+    }
+
+    #[test]
+    fn test_base36() {
+        assert_eq!(base36(0), "0");
+        assert_eq!(base36(35), "z");
+        assert_eq!(base36(36), "10");
+    }
+}
@@ -0,0 +1,166 @@
+use crate::generators::ids::{
+    decode_ksuid, decode_snowflake, decode_ulid, nano_id_alphabet, validate_cuid2,
+    validate_nano_id, IdFormat, NANO_ID_ALPHABET, TWITTER_EPOCH,
+};
+use chrono::{DateTime, SecondsFormat};
+use data_encoding::HEXLOWER;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DecodedId {
+    pub timestamp: Option<String>,
+    pub worker_id: Option<u16>,
+    pub sequence: Option<u16>,
+    pub payload: Option<String>,
+}
+
+/// Decodes an ID back into the fields it contains.
+///
+/// ULIDs, KSUIDs and Snowflake IDs report their embedded timestamp as an ISO-8601 UTC date
+/// time and their random payload as hex, or worker id and sequence for Snowflake IDs. Nano
+/// IDs and CUID2s carry no timestamp so they are only validated.
+///
+/// # Arguments
+/// * `id` - The ID to decode
+/// * `format` - The format of the ID, with the epoch for Snowflake IDs and the alphabet for
+///   Nano IDs
+///
+/// # Returns
+/// * `Ok(DecodedId)` - The fields of the ID, `None` for those the format does not have
+/// * `Err(String)` - An error message if the ID is not valid for the format
+///
+/// ```
+#[tauri::command]
+pub fn decode_id(id: &str, format: IdFormat) -> Result<DecodedId, String> {
+    let id = id.trim();
+
+    let decoded = match format {
+        IdFormat::Ulid { .. } => {
+            let (timestamp, random) = decode_ulid(id)?;
+            DecodedId {
+                timestamp: format_millis(timestamp),
+                worker_id: None,
+                sequence: None,
+                payload: Some(HEXLOWER.encode(&random.to_be_bytes()[6..])),
+            }
+        }
+        IdFormat::Ksuid => {
+            let (timestamp, payload) = decode_ksuid(id)?;
+            DecodedId {
+                timestamp: format_millis(timestamp * 1000),
+                worker_id: None,
+                sequence: None,
+                payload: Some(HEXLOWER.encode(&payload)),
+            }
+        }
+        IdFormat::Snowflake { epoch, .. } => {
+            let (timestamp, worker_id, sequence) =
+                decode_snowflake(id, epoch.unwrap_or(TWITTER_EPOCH))?;
+            DecodedId {
+                timestamp: format_millis(timestamp),
+                worker_id: Some(worker_id),
+                sequence: Some(sequence),
+                payload: None,
+            }
+        }
+        IdFormat::NanoId { alphabet, .. } => {
+            let alphabet = nano_id_alphabet(alphabet.as_deref().unwrap_or(NANO_ID_ALPHABET))?;
+            validate_nano_id(id, &alphabet)?;
+            empty()
+        }
+        IdFormat::Cuid2 { .. } => {
+            validate_cuid2(id)?;
+            empty()
+        }
+    };
+
+    Ok(decoded)
+}
+
+fn format_millis(millis: u64) -> Option<String> {
+    DateTime::from_timestamp_millis(i64::try_from(millis).ok()?)
+        .map(|date_time| date_time.to_rfc3339_opts(SecondsFormat::Millis, true))
+}
+
+fn empty() -> DecodedId {
+    DecodedId {
+        timestamp: None,
+        worker_id: None,
+        sequence: None,
+        payload: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ids::NANO_ID_LENGTH;
+
+    // cspell:disable
+    #[test]
+    fn test_decodes_ulid() {
+        let decoded = decode_id(
+            "01ARZ3NDEKTSV4RRFFQ69G5FAV",
+            IdFormat::Ulid { monotonic: false },
+        )
+        .unwrap();
+
+        assert_eq!(
+            decoded.timestamp.as_deref(),
+            Some("2016-07-30T23:54:10.259Z")
+        );
+        assert_eq!(decoded.payload.map(|payload| payload.len()), Some(20));
+    }
+
+    #[test]
+    fn test_decodes_ksuid() {
+        let decoded = decode_id("0ujtsYcgvSTl8PAuAdqWYSMnLOv", IdFormat::Ksuid).unwrap();
+
+        assert_eq!(
+            decoded.timestamp.as_deref(),
+            Some("2017-10-10T04:00:47.000Z")
+        );
+        assert_eq!(
+            decoded.payload.as_deref(),
+            Some("b5a1cd34b5f99d1154fb6853345c9735")
+        );
+    }
+    // cspell:enable
+
+    #[test]
+    fn test_decodes_snowflake() {
+        let decoded = decode_id(
+            "1541815603606036480",
+            IdFormat::Snowflake {
+                epoch: None,
+                worker_id: 0,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            decoded.timestamp.as_deref(),
+            Some("2022-06-28T16:07:40.105Z")
+        );
+        assert_eq!(decoded.worker_id, Some(378));
+        assert_eq!(decoded.sequence, Some(0));
+    }
+
+    #[test]
+    fn test_validates_ids_without_timestamps() {
+        let decoded = decode_id("tz4a98xxat96iws9zmbrgj3a", IdFormat::Cuid2 { length: None });
+        assert_eq!(decoded.unwrap().timestamp, None);
+
+        let result = decode_id(
+            "abc!",
+            IdFormat::NanoId {
+                alphabet: None,
+                length: Some(NANO_ID_LENGTH),
+            },
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            "Invalid Nano ID character '!' at position 3"
+        );
+    }
+}
@@ -0,0 +1,232 @@
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub enum IdExportFormat {
+    Newline,
+    Csv { header: Option<String> },
+    Json,
+    Sql { table: String, column: String },
+    RustArray,
+    TypeScriptArray,
+}
+
+/// Writes IDs one at a time in an export format, so any number of IDs can be streamed to a
+/// file without holding them all in memory.
+///
+/// The number of IDs must be known up front because Rust arrays declare their length.
+pub struct IdWriter<W: Write> {
+    writer: W,
+    format: IdExportFormat,
+    written: u64,
+}
+
+impl<W: Write> IdWriter<W> {
+    pub fn new(mut writer: W, format: IdExportFormat, count: u64) -> Result<Self, String> {
+        let header = match &format {
+            IdExportFormat::Newline => String::new(),
+            IdExportFormat::Csv { header } => header
+                .as_deref()
+                .map(|header| format!("{}\n", csv_field(header)))
+                .unwrap_or_default(),
+            IdExportFormat::Json => "[".to_string(),
+            IdExportFormat::Sql { table, column } if count > 0 => format!(
+                "INSERT INTO {} ({}) VALUES\n",
+                sql_identifier(table)?,
+                sql_identifier(column)?
+            ),
+            IdExportFormat::Sql { table, column } => {
+                sql_identifier(table)?;
+                sql_identifier(column)?;
+                String::new()
+            }
+            IdExportFormat::RustArray => format!("const IDS: [&str; {}] = [\n", count),
+            IdExportFormat::TypeScriptArray => "const ids: string[] = [\n".to_string(),
+        };
+
+        write(&mut writer, &header)?;
+
+        Ok(IdWriter {
+            writer,
+            format,
+            written: 0,
+        })
+    }
+
+    pub fn write(&mut self, id: &str) -> Result<(), String> {
+        let first = self.written == 0;
+
+        let line = match &self.format {
+            IdExportFormat::Newline => format!("{}\n", id),
+            IdExportFormat::Csv { .. } => format!("{}\n", csv_field(id)),
+            IdExportFormat::Json => {
+                format!("{}\n  {}", if first { "" } else { "," }, json_string(id))
+            }
+            IdExportFormat::Sql { .. } => format!(
+                "{}  ('{}')",
+                if first { "" } else { ",\n" },
+                id.replace('\'', "''")
+            ),
+            IdExportFormat::RustArray => format!("    {:?},\n", id),
+            IdExportFormat::TypeScriptArray => format!("  {},\n", json_string(id)),
+        };
+
+        write(&mut self.writer, &line)?;
+        self.written += 1;
+
+        Ok(())
+    }
+
+    /// Closes the export and returns the underlying writer, flushed
+    pub fn finish(mut self) -> Result<W, String> {
+        let footer = match &self.format {
+            IdExportFormat::Newline | IdExportFormat::Csv { .. } => "",
+            IdExportFormat::Json if self.written == 0 => "]\n",
+            IdExportFormat::Json => "\n]\n",
+            IdExportFormat::Sql { .. } if self.written == 0 => "",
+            IdExportFormat::Sql { .. } => ";\n",
+            IdExportFormat::RustArray | IdExportFormat::TypeScriptArray => "];\n",
+        };
+
+        write(&mut self.writer, footer)?;
+        self.writer
+            .flush()
+            .map_err(|e| format!("Failed to write IDs: {}", e))?;
+
+        Ok(self.writer)
+    }
+}
+
+fn write(writer: &mut impl Write, text: &str) -> Result<(), String> {
+    writer
+        .write_all(text.as_bytes())
+        .map_err(|e| format!("Failed to write IDs: {}", e))
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn json_string(text: &str) -> String {
+    serde_json::Value::String(text.to_string()).to_string()
+}
+
+/// Checks that a table or column name is a plain, optionally schema qualified, identifier so
+/// it can be used in the statement without quoting
+fn sql_identifier(identifier: &str) -> Result<&str, String> {
+    let valid = !identifier.is_empty()
+        && identifier.split('.').all(|part| {
+            part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+
+    if valid {
+        Ok(identifier)
+    } else {
+        Err(format!(
+            "Invalid SQL identifier '{}'. Use letters, digits and underscores",
+            identifier
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn export(format: IdExportFormat, ids: &[&str]) -> String {
+        let mut writer = IdWriter::new(Vec::new(), format, ids.len() as u64).unwrap();
+        for id in ids {
+            writer.write(id).unwrap();
+        }
+        String::from_utf8(writer.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_exports_each_format() {
+        let ids = ["a1", "b2"];
+
+        assert_eq!(export(IdExportFormat::Newline, &ids), "a1\nb2\n");
+        assert_eq!(
+            export(
+                IdExportFormat::Csv {
+                    header: Some("id".to_string())
+                },
+                &ids
+            ),
+            "id\na1\nb2\n"
+        );
+        assert_eq!(
+            export(IdExportFormat::Json, &ids),
+            "[\n  \"a1\",\n  \"b2\"\n]\n"
+        );
+        assert_eq!(
+            export(
+                IdExportFormat::Sql {
+                    table: "public.users".to_string(),
+                    column: "id".to_string()
+                },
+                &ids
+            ),
+            "INSERT INTO public.users (id) VALUES\n  ('a1'),\n  ('b2');\n"
+        );
+        assert_eq!(
+            export(IdExportFormat::RustArray, &ids),
+            "const IDS: [&str; 2] = [\n    \"a1\",\n    \"b2\",\n];\n"
+        );
+        assert_eq!(
+            export(IdExportFormat::TypeScriptArray, &ids),
+            "const ids: string[] = [\n  \"a1\",\n  \"b2\",\n];\n"
+        );
+    }
+
+    #[test]
+    fn test_escapes_special_characters() {
+        let ids = ["it's", "a,\"b\""];
+
+        assert_eq!(
+            export(IdExportFormat::Csv { header: None }, &ids),
+            "it's\n\"a,\"\"b\"\"\"\n"
+        );
+        let sql = export(
+            IdExportFormat::Sql {
+                table: "ids".to_string(),
+                column: "id".to_string(),
+            },
+            &ids,
+        );
+        assert!(sql.contains("('it''s')"));
+        let json = export(IdExportFormat::Json, &ids);
+        assert_eq!(
+            serde_json::from_str::<Vec<String>>(&json).unwrap(),
+            vec!["it's", "a,\"b\""]
+        );
+    }
+
+    #[test]
+    fn test_exports_empty_lists() {
+        assert_eq!(export(IdExportFormat::Json, &[]), "[]\n");
+        let sql = IdExportFormat::Sql {
+            table: "ids".to_string(),
+            column: "id".to_string(),
+        };
+        assert_eq!(export(sql, &[]), "");
+    }
+
+    #[test]
+    fn test_given_invalid_sql_identifier_returns_error() {
+        let format = IdExportFormat::Sql {
+            table: "ids; DROP TABLE users".to_string(),
+            column: "id".to_string(),
+        };
+
+        assert!(IdWriter::new(Vec::new(), format, 1)
+            .err()
+            .unwrap()
+            .starts_with("Invalid SQL identifier"));
+    }
+}
@@ -0,0 +1,140 @@
+use crate::generators::ids::{IdExportFormat, IdFormat, IdGenerator, IdWriter};
+use crate::generators::{UuidGenerator, UuidVersion};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+/// The kind of IDs to export, with the same options as `generate_uuid` and `generate_id`
+#[derive(Debug, Deserialize, Serialize)]
+pub enum IdSource {
+    Uuid {
+        version: UuidVersion,
+        uppercase: bool,
+        hyphens: bool,
+        seed: Option<u64>,
+    },
+    Id(IdFormat),
+}
+
+/// Generates IDs straight into a file.
+///
+/// IDs are written as they are generated so millions of IDs can be exported without holding
+/// them all in memory. The file is created, or truncated if it exists.
+///
+/// # Arguments
+/// * `path` - The file to write the IDs to
+/// * `number_of_ids` - The number of IDs to generate
+/// * `source` - The kind of IDs to generate
+/// * `format` - The export format
+///
+/// # Returns
+/// * `Ok(u64)` - The number of IDs written
+/// * `Err(String)` - An error message if the options are invalid or the file cannot be written
+///
+/// ```
+#[tauri::command]
+pub async fn export_ids(
+    path: PathBuf,
+    number_of_ids: u64,
+    source: IdSource,
+    format: IdExportFormat,
+) -> Result<u64, String> {
+    tokio::task::spawn_blocking(move || {
+        let mut next_id: Box<dyn FnMut() -> Result<String, String> + Send> = match source {
+            IdSource::Uuid {
+                version,
+                uppercase,
+                hyphens,
+                seed,
+            } => {
+                let mut generator = UuidGenerator::new(version, uppercase, hyphens, seed);
+                Box::new(move || Ok(generator.generate()))
+            }
+            IdSource::Id(format) => {
+                let mut generator = IdGenerator::new(format)?;
+                Box::new(move || generator.generate())
+            }
+        };
+
+        let file = File::create(&path).map_err(|e| format!("Failed to create file: {}", e))?;
+        let mut writer = IdWriter::new(BufWriter::new(file), format, number_of_ids)?;
+
+        for _ in 0..number_of_ids {
+            writer.write(&next_id()?)?;
+        }
+        writer.finish()?;
+
+        Ok(number_of_ids)
+    })
+    .await
+    .map_err(|e| format!("Failed to export IDs: {}", e))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::fs;
+    use tempfile::NamedTempFile;
+
+    #[tokio::test]
+    async fn test_streams_ids_to_a_file() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let source = IdSource::Uuid {
+            version: UuidVersion::V7,
+            uppercase: false,
+            hyphens: true,
+            seed: None,
+        };
+
+        let written = export_ids(
+            temp_file.path().to_path_buf(),
+            100_000,
+            source,
+            IdExportFormat::Newline,
+        )
+        .await
+        .unwrap();
+        assert_eq!(written, 100_000);
+
+        let contents = fs::read_to_string(temp_file.path()).unwrap();
+        let ids = contents.lines().collect::<Vec<&str>>();
+        assert_eq!(ids.len(), 100_000);
+        assert_eq!(ids.iter().collect::<HashSet<_>>().len(), 100_000);
+    }
+
+    #[tokio::test]
+    async fn test_exports_ids_as_json() {
+        let temp_file = NamedTempFile::new().unwrap();
+
+        export_ids(
+            temp_file.path().to_path_buf(),
+            3,
+            IdSource::Id(IdFormat::Ksuid),
+            IdExportFormat::Json,
+        )
+        .await
+        .unwrap();
+
+        let contents = fs::read_to_string(temp_file.path()).unwrap();
+        let ids = serde_json::from_str::<Vec<String>>(&contents).unwrap();
+        assert_eq!(ids.len(), 3);
+        assert!(ids.iter().all(|id| id.len() == 27));
+    }
+
+    #[tokio::test]
+    async fn test_given_invalid_options_returns_error() {
+        let temp_file = NamedTempFile::new().unwrap();
+
+        let result = export_ids(
+            temp_file.path().to_path_buf(),
+            1,
+            IdSource::Id(IdFormat::Cuid2 { length: Some(1) }),
+            IdExportFormat::Newline,
+        )
+        .await;
+
+        assert!(result.is_err());
+    }
+}
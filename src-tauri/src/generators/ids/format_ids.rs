@@ -0,0 +1,35 @@
+use crate::generators::ids::{IdExportFormat, IdWriter};
+
+/// Formats generated IDs for export, e.g. as a JSON array or SQL `INSERT` statement.
+///
+/// # Arguments
+/// * `ids` - The IDs to format
+/// * `format` - The export format
+///
+/// # Returns
+/// * `Ok(String)` - The formatted IDs
+/// * `Err(String)` - An error message if the format options are invalid
+///
+/// ```
+#[tauri::command]
+pub fn format_ids(ids: Vec<String>, format: IdExportFormat) -> Result<String, String> {
+    let mut writer = IdWriter::new(Vec::new(), format, ids.len() as u64)?;
+
+    for id in &ids {
+        writer.write(id)?;
+    }
+
+    String::from_utf8(writer.finish()?).map_err(|e| format!("Failed to format IDs: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_formats_ids() {
+        let ids = vec!["a".to_string(), "b".to_string()];
+
+        assert_eq!(format_ids(ids, IdExportFormat::Newline).unwrap(), "a\nb\n");
+    }
+}
@@ -0,0 +1,201 @@
+use crate::generators::ids::{
+    generate_ksuid, generate_nano_id, nano_id_alphabet, nano_id_length, Cuid2Generator,
+    SnowflakeGenerator, UlidGenerator, CUID2_LENGTH, NANO_ID_ALPHABET, NANO_ID_LENGTH,
+    TWITTER_EPOCH,
+};
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub enum IdFormat {
+    Ulid {
+        monotonic: bool,
+    },
+    Ksuid,
+    NanoId {
+        alphabet: Option<String>,
+        length: Option<usize>,
+    },
+    Snowflake {
+        epoch: Option<u64>,
+        worker_id: u16,
+    },
+    Cuid2 {
+        length: Option<usize>,
+    },
+}
+
+/// Generates one or more IDs in the given format.
+///
+/// Supports ULIDs (optionally monotonic within a batch), KSUIDs, Nano IDs with a custom
+/// alphabet and length (21 characters by default), Twitter-style Snowflake IDs with a
+/// configurable epoch (Twitter's by default) and worker id, and CUID2s (24 characters by
+/// default).
+///
+/// # Arguments
+/// * `number_of_ids` - The number of IDs to generate
+/// * `format` - The ID format and its options
+///
+/// # Returns
+/// * `Ok(Vec<String>)` - The generated IDs
+/// * `Err(String)` - An error message if the options are invalid
+///
+/// ```
+#[tauri::command]
+pub fn generate_id(number_of_ids: u16, format: IdFormat) -> Result<Vec<String>, String> {
+    let mut generator = IdGenerator::new(format)?;
+
+    (0..number_of_ids).map(|_| generator.generate()).collect()
+}
+
+/// Generates IDs of one format one at a time, keeping the state (e.g. the Snowflake sequence
+/// or monotonic ULID) shared between them
+pub enum IdGenerator {
+    Ulid(UlidGenerator),
+    Ksuid,
+    NanoId { alphabet: Vec<char>, length: usize },
+    Snowflake(SnowflakeGenerator),
+    Cuid2(Cuid2Generator),
+}
+
+impl IdGenerator {
+    pub fn new(format: IdFormat) -> Result<Self, String> {
+        let generator = match format {
+            IdFormat::Ulid { monotonic } => IdGenerator::Ulid(UlidGenerator::new(monotonic)),
+            IdFormat::Ksuid => IdGenerator::Ksuid,
+            IdFormat::NanoId { alphabet, length } => IdGenerator::NanoId {
+                alphabet: nano_id_alphabet(alphabet.as_deref().unwrap_or(NANO_ID_ALPHABET))?,
+                length: nano_id_length(length.unwrap_or(NANO_ID_LENGTH))?,
+            },
+            IdFormat::Snowflake { epoch, worker_id } => IdGenerator::Snowflake(
+                SnowflakeGenerator::new(epoch.unwrap_or(TWITTER_EPOCH), worker_id)?,
+            ),
+            IdFormat::Cuid2 { length } => {
+                IdGenerator::Cuid2(Cuid2Generator::new(length.unwrap_or(CUID2_LENGTH))?)
+            }
+        };
+
+        Ok(generator)
+    }
+
+    pub fn generate(&mut self) -> Result<String, String> {
+        match self {
+            IdGenerator::Ulid(generator) => generator.generate(now_millis()?),
+            IdGenerator::Ksuid => generate_ksuid(now_millis()? / 1000),
+            IdGenerator::NanoId { alphabet, length } => Ok(generate_nano_id(alphabet, *length)),
+            IdGenerator::Snowflake(generator) => loop {
+                // None means the sequence of this millisecond is exhausted, so try again
+                if let Some(id) = generator.generate(now_millis()?)? {
+                    return Ok(id.to_string());
+                }
+            },
+            IdGenerator::Cuid2(generator) => Ok(generator.generate(now_millis()?)),
+        }
+    }
+}
+
+fn now_millis() -> Result<u64, String> {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .map_err(|e| format!("Failed to read the system clock: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::ids::decode_ulid;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_generates_the_requested_number_of_unique_ids() {
+        let formats = [
+            IdFormat::Ulid { monotonic: false },
+            IdFormat::Ksuid,
+            IdFormat::NanoId {
+                alphabet: None,
+                length: Some(NANO_ID_LENGTH),
+            },
+            IdFormat::Snowflake {
+                epoch: None,
+                worker_id: 1,
+            },
+            IdFormat::Cuid2 { length: None },
+        ];
+
+        for format in formats {
+            let ids = generate_id(50, format).unwrap();
+            assert_eq!(ids.len(), 50);
+            assert_eq!(ids.iter().collect::<HashSet<_>>().len(), 50);
+        }
+    }
+
+    #[test]
+    fn test_monotonic_ulids_are_sorted() {
+        let ids = generate_id(1000, IdFormat::Ulid { monotonic: true }).unwrap();
+
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(ids.iter().all(|id| decode_ulid(id).is_ok()));
+    }
+
+    #[test]
+    fn test_snowflake_ids_are_sorted_across_exhausted_sequences() {
+        let ids = generate_id(
+            10_000,
+            IdFormat::Snowflake {
+                epoch: None,
+                worker_id: 7,
+            },
+        )
+        .unwrap();
+
+        let ids = ids
+            .iter()
+            .map(|id| id.parse::<u64>().unwrap())
+            .collect::<Vec<u64>>();
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_nano_ids_use_custom_alphabet() {
+        let ids = generate_id(
+            10,
+            IdFormat::NanoId {
+                alphabet: Some("ab".to_string()),
+                length: Some(8),
+            },
+        )
+        .unwrap();
+
+        assert!(ids
+            .iter()
+            .all(|id| id.len() == 8 && id.chars().all(|c| c == 'a' || c == 'b')));
+    }
+
+    #[test]
+    fn test_given_invalid_options_returns_error() {
+        let result = generate_id(
+            1,
+            IdFormat::Snowflake {
+                epoch: None,
+                worker_id: 1024,
+            },
+        );
+        assert_eq!(
+            result.unwrap_err(),
+            "The worker id must be between 0 and 1023"
+        );
+
+        let result = generate_id(1, IdFormat::Cuid2 { length: Some(40) });
+        assert!(result.is_err());
+
+        let result = generate_id(
+            1,
+            IdFormat::NanoId {
+                alphabet: None,
+                length: Some(0),
+            },
+        );
+        assert!(result.is_err());
+    }
+}
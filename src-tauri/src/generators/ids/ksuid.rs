@@ -0,0 +1,73 @@
+use crate::generators::ids::radix;
+
+// cspell:disable-next-line
+const BASE62: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+/// KSUID timestamps count seconds from 2014-05-13T16:53:20Z rather than the Unix epoch
+const KSUID_EPOCH: u64 = 1_400_000_000;
+
+/// Generates a KSUID: a 32-bit timestamp in seconds followed by 128 random bits, written as
+/// 27 Base62 characters
+pub fn generate_ksuid(unix_seconds: u64) -> Result<String, String> {
+    let timestamp = unix_seconds
+        .checked_sub(KSUID_EPOCH)
+        .and_then(|seconds| u32::try_from(seconds).ok())
+        .ok_or_else(|| "The current time cannot be represented by a KSUID".to_string())?;
+
+    let mut bytes = timestamp.to_be_bytes().to_vec();
+    bytes.extend(rand::random::<[u8; 16]>());
+
+    Ok(radix::encode(&bytes, BASE62, 27))
+}
+
+/// Decodes a KSUID into its Unix timestamp in seconds and its 16 byte payload
+pub fn decode_ksuid(id: &str) -> Result<(u64, Vec<u8>), String> {
+    let id = id.trim();
+
+    if id.len() != 27 {
+        return Err(format!(
+            "Invalid KSUID length. Expected 27 characters but got {}",
+            id.len()
+        ));
+    }
+
+    let bytes = radix::decode(id, BASE62, 20).ok_or_else(|| {
+        "Invalid KSUID. Expected Base62 no greater than aWgEPTl1tmebfsQzFP4bxwgy80V".to_string()
+    })?;
+    let (timestamp, payload) = bytes.split_at(4);
+    let timestamp = u32::from_be_bytes(timestamp.try_into().unwrap_or_default());
+
+    Ok((timestamp as u64 + KSUID_EPOCH, payload.to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decodes_reference_ksuid() {
+        // cspell:disable-next-line
+        let (timestamp, payload) = decode_ksuid("0ujtsYcgvSTl8PAuAdqWYSMnLOv").unwrap();
+        assert_eq!(timestamp, 1_507_608_047);
+        assert_eq!(
+            payload,
+            [
+                0xb5, 0xa1, 0xcd, 0x34, 0xb5, 0xf9, 0x9d, 0x11, 0x54, 0xfb, 0x68, 0x53, 0x34, 0x5c,
+                0x97, 0x35
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generated_ksuids_round_trip() {
+        let id = generate_ksuid(1_700_000_000).unwrap();
+        assert_eq!(id.len(), 27);
+        assert_eq!(decode_ksuid(&id).unwrap().0, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_values() {
+        assert!(generate_ksuid(1_000).is_err());
+        assert!(decode_ksuid("aWgEPTl1tmebfsQzFP4bxwgy80W").is_err());
+        assert!(decode_ksuid("0ujtsYcgvSTl8PAuAdqWYSMnLO!").is_err());
+    }
+}
@@ -0,0 +1,22 @@
+pub mod cuid2;
+pub mod decode_id;
+pub mod export_format;
+pub mod export_ids;
+pub mod format_ids;
+pub mod generate_id;
+pub mod ksuid;
+pub mod nano_id;
+pub mod radix;
+pub mod snowflake;
+pub mod ulid;
+
+pub use cuid2::*;
+pub use decode_id::*;
+pub use export_format::*;
+pub use export_ids::*;
+pub use format_ids::*;
+pub use generate_id::*;
+pub use ksuid::*;
+pub use nano_id::*;
+pub use snowflake::*;
+pub use ulid::*;
@@ -0,0 +1,95 @@
+use rand::prelude::IndexedRandom;
+
+// cspell:disable-next-line
+pub const NANO_ID_ALPHABET: &str =
+    "_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+pub const NANO_ID_LENGTH: usize = 21;
+const MAX_NANO_ID_LENGTH: usize = 256;
+
+/// Checks a custom Nano ID alphabet, which needs at least two distinct characters
+pub fn nano_id_alphabet(alphabet: &str) -> Result<Vec<char>, String> {
+    let characters = alphabet.chars().collect::<Vec<char>>();
+
+    if characters.len() < 2 {
+        return Err("The alphabet must contain at least 2 characters".to_string());
+    }
+
+    if let Some(duplicate) = characters
+        .iter()
+        .enumerate()
+        .find(|(index, character)| characters[..*index].contains(character))
+        .map(|(_, character)| character)
+    {
+        return Err(format!(
+            "The alphabet contains '{}' more than once",
+            duplicate
+        ));
+    }
+
+    Ok(characters)
+}
+
+/// Checks a custom Nano ID length
+pub fn nano_id_length(length: usize) -> Result<usize, String> {
+    if !(1..=MAX_NANO_ID_LENGTH).contains(&length) {
+        return Err(format!(
+            "The length must be between 1 and {}",
+            MAX_NANO_ID_LENGTH
+        ));
+    }
+
+    Ok(length)
+}
+
+/// Generates a Nano ID by picking `length` characters uniformly from `alphabet` with the
+/// thread local cryptographically secure generator
+pub fn generate_nano_id(alphabet: &[char], length: usize) -> String {
+    let mut rng = rand::rng();
+    (0..length)
+        .filter_map(|_| alphabet.choose(&mut rng))
+        .collect()
+}
+
+/// Checks that `id` only uses characters from `alphabet`. Nano IDs carry no timestamp.
+pub fn validate_nano_id(id: &str, alphabet: &[char]) -> Result<(), String> {
+    match id.char_indices().find(|(_, c)| !alphabet.contains(c)) {
+        Some((position, character)) => Err(format!(
+            "Invalid Nano ID character '{}' at position {}",
+            character, position
+        )),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generates_ids_from_the_alphabet() {
+        let alphabet = nano_id_alphabet(NANO_ID_ALPHABET).unwrap();
+        let id = generate_nano_id(&alphabet, NANO_ID_LENGTH);
+        assert_eq!(id.len(), NANO_ID_LENGTH);
+        assert!(validate_nano_id(&id, &alphabet).is_ok());
+
+        let alphabet = nano_id_alphabet("01").unwrap();
+        let id = generate_nano_id(&alphabet, 64);
+        assert!(id.chars().all(|c| c == '0' || c == '1'));
+    }
+
+    #[test]
+    fn test_rejects_invalid_alphabets_and_ids() {
+        assert!(nano_id_alphabet("a").is_err());
+        assert!(nano_id_length(0).is_err());
+        assert!(nano_id_length(MAX_NANO_ID_LENGTH + 1).is_err());
+        assert_eq!(nano_id_length(NANO_ID_LENGTH), Ok(NANO_ID_LENGTH));
+        assert_eq!(
+            nano_id_alphabet("abca").unwrap_err(),
+            "The alphabet contains 'a' more than once"
+        );
+        assert_eq!(
+            validate_nano_id("abz", &['a', 'b']).unwrap_err(),
+            "Invalid Nano ID character 'z' at position 2"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+/// Encodes a big-endian unsigned integer using `alphabet` as its digits, left padded with
+/// the zero digit to at least `length` characters
+pub fn encode(bytes: &[u8], alphabet: &[u8], length: usize) -> String {
+    let base = alphabet.len() as u32;
+    let mut number = bytes.to_vec();
+    let mut digits = Vec::new();
+
+    while number.iter().any(|&byte| byte != 0) {
+        let mut remainder = 0u32;
+        for byte in number.iter_mut() {
+            let value = (remainder << 8) | *byte as u32;
+            *byte = (value / base) as u8;
+            remainder = value % base;
+        }
+        digits.push(alphabet[remainder as usize]);
+    }
+
+    while digits.len() < length {
+        digits.push(alphabet[0]);
+    }
+
+    digits.iter().rev().map(|&digit| digit as char).collect()
+}
+
+/// Decodes `text` written with `alphabet` into a big-endian integer of `byte_length` bytes.
+/// Returns `None` when a character is not in the alphabet or the value does not fit.
+pub fn decode(text: &str, alphabet: &[u8], byte_length: usize) -> Option<Vec<u8>> {
+    let base = alphabet.len() as u32;
+    let mut number = vec![0u8; byte_length];
+
+    for character in text.bytes() {
+        let mut carry = alphabet.iter().position(|&digit| digit == character)? as u32;
+        for byte in number.iter_mut().rev() {
+            let value = *byte as u32 * base + carry;
+            *byte = value as u8;
+            carry = value >> 8;
+        }
+        if carry != 0 {
+            return None;
+        }
+    }
+
+    Some(number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEX: &[u8] = b"0123456789abcdef";
+
+    #[test]
+    fn test_encode_and_decode_round_trip() {
+        assert_eq!(encode(&[0x01, 0xff], HEX, 0), "1ff");
+        assert_eq!(encode(&[0x01, 0xff], HEX, 6), "0001ff");
+        assert_eq!(encode(&[0, 0], HEX, 0), "");
+        assert_eq!(decode("1ff", HEX, 2), Some(vec![0x01, 0xff]));
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_characters_and_overflow() {
+        assert_eq!(decode("1fg", HEX, 2), None);
+        assert_eq!(decode("10000", HEX, 2), None);
+    }
+}
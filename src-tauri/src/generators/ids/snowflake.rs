@@ -0,0 +1,135 @@
+/// The epoch of Twitter Snowflake IDs, 2010-11-04T01:42:54.657Z, in Unix milliseconds
+pub const TWITTER_EPOCH: u64 = 1_288_834_974_657;
+pub const MAX_WORKER_ID: u16 = (1 << WORKER_ID_BITS) - 1;
+
+const WORKER_ID_BITS: u32 = 10;
+const SEQUENCE_BITS: u32 = 12;
+const MAX_SEQUENCE: u16 = (1 << SEQUENCE_BITS) - 1;
+const MAX_TIMESTAMP: u64 = (1 << 41) - 1;
+
+/// Generates Twitter-style Snowflake IDs: a 41-bit millisecond timestamp relative to a
+/// custom epoch, a 10-bit worker id and a 12-bit sequence that increments for IDs generated
+/// within the same millisecond.
+pub struct SnowflakeGenerator {
+    epoch: u64,
+    worker_id: u16,
+    previous_timestamp: Option<u64>,
+    sequence: u16,
+}
+
+impl SnowflakeGenerator {
+    pub fn new(epoch: u64, worker_id: u16) -> Result<Self, String> {
+        if worker_id > MAX_WORKER_ID {
+            return Err(format!(
+                "The worker id must be between 0 and {}",
+                MAX_WORKER_ID
+            ));
+        }
+
+        Ok(SnowflakeGenerator {
+            epoch,
+            worker_id,
+            previous_timestamp: None,
+            sequence: 0,
+        })
+    }
+
+    /// Generates the next ID at `now` (Unix milliseconds). Returns `Ok(None)` when all 4096
+    /// sequence numbers of the current millisecond are used and the caller must wait.
+    pub fn generate(&mut self, now: u64) -> Result<Option<u64>, String> {
+        let timestamp = now
+            .checked_sub(self.epoch)
+            .filter(|timestamp| *timestamp <= MAX_TIMESTAMP)
+            .ok_or_else(|| "The current time cannot be represented with this epoch".to_string())?;
+
+        // Keep the previous timestamp if the clock moved backwards so IDs stay unique
+        let timestamp = match self.previous_timestamp {
+            Some(previous_timestamp) if timestamp <= previous_timestamp => {
+                if self.sequence == MAX_SEQUENCE {
+                    return Ok(None);
+                }
+                self.sequence += 1;
+                previous_timestamp
+            }
+            _ => {
+                self.previous_timestamp = Some(timestamp);
+                self.sequence = 0;
+                timestamp
+            }
+        };
+
+        Ok(Some(
+            (timestamp << (WORKER_ID_BITS + SEQUENCE_BITS))
+                | ((self.worker_id as u64) << SEQUENCE_BITS)
+                | self.sequence as u64,
+        ))
+    }
+}
+
+/// Decodes a Snowflake ID into its Unix timestamp in milliseconds, worker id and sequence
+pub fn decode_snowflake(id: &str, epoch: u64) -> Result<(u64, u16, u16), String> {
+    let id: u64 = id
+        .trim()
+        .parse()
+        .map_err(|_| "Invalid Snowflake ID. Expected a positive 64-bit integer".to_string())?;
+
+    let timestamp = (id >> (WORKER_ID_BITS + SEQUENCE_BITS))
+        .checked_add(epoch)
+        .ok_or_else(|| "The epoch is too large for this Snowflake ID".to_string())?;
+    let worker_id = ((id >> SEQUENCE_BITS) & MAX_WORKER_ID as u64) as u16;
+    let sequence = (id & MAX_SEQUENCE as u64) as u16;
+
+    Ok((timestamp, worker_id, sequence))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decodes_twitter_ids() {
+        let (timestamp, worker_id, sequence) =
+            decode_snowflake("1541815603606036480", TWITTER_EPOCH).unwrap();
+
+        assert_eq!(timestamp, 1_656_432_460_105);
+        assert_eq!(worker_id, 378);
+        assert_eq!(sequence, 0);
+    }
+
+    #[test]
+    fn test_given_overflowing_epoch_returns_error() {
+        assert!(decode_snowflake("1541815603606036480", u64::MAX).is_err());
+    }
+
+    #[test]
+    fn test_sequence_increments_within_a_millisecond() {
+        let mut generator = SnowflakeGenerator::new(TWITTER_EPOCH, 42).unwrap();
+        let now = 1_700_000_000_000;
+
+        let ids = (0..=MAX_SEQUENCE)
+            .map(|_| generator.generate(now).unwrap().unwrap())
+            .collect::<Vec<u64>>();
+
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(
+            decode_snowflake(&ids[4095].to_string(), TWITTER_EPOCH).unwrap(),
+            (now, 42, 4095)
+        );
+        assert_eq!(generator.generate(now).unwrap(), None);
+
+        let next = generator.generate(now + 1).unwrap().unwrap();
+        assert_eq!(
+            decode_snowflake(&next.to_string(), TWITTER_EPOCH).unwrap(),
+            (now + 1, 42, 0)
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_configuration() {
+        assert!(SnowflakeGenerator::new(TWITTER_EPOCH, 1024).is_err());
+
+        let mut generator = SnowflakeGenerator::new(TWITTER_EPOCH, 0).unwrap();
+        assert!(generator.generate(TWITTER_EPOCH - 1).is_err());
+        assert!(decode_snowflake("-1", TWITTER_EPOCH).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+use crate::generators::ids::radix;
+
+// cspell:disable-next-line
+const CROCKFORD_BASE32: &[u8] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const RANDOM_BITS: u32 = 80;
+const MAX_TIMESTAMP: u64 = (1 << 48) - 1;
+
+/// Generates ULIDs: a 48-bit millisecond timestamp followed by 80 random bits, written as 26
+/// Crockford Base32 characters.
+///
+/// In monotonic mode an ID generated within the same millisecond as the previous one reuses
+/// its random component incremented by one, so IDs sort in the order they were generated.
+pub struct UlidGenerator {
+    monotonic: bool,
+    previous: Option<(u64, u128)>,
+}
+
+impl UlidGenerator {
+    pub fn new(monotonic: bool) -> Self {
+        UlidGenerator {
+            monotonic,
+            previous: None,
+        }
+    }
+
+    pub fn generate(&mut self, timestamp: u64) -> Result<String, String> {
+        if timestamp > MAX_TIMESTAMP {
+            return Err("The current time cannot be represented by a ULID".to_string());
+        }
+
+        let random = match self.previous {
+            Some((previous_timestamp, previous_random))
+                if self.monotonic && timestamp <= previous_timestamp =>
+            {
+                let random = previous_random + 1;
+                if random >> RANDOM_BITS != 0 {
+                    return Err(
+                        "Too many ULIDs generated within one millisecond, the random component overflowed"
+                            .to_string(),
+                    );
+                }
+                // Keep the previous timestamp if the clock moved backwards so IDs stay sorted
+                self.previous = Some((previous_timestamp, random));
+                return Ok(encode(previous_timestamp, random));
+            }
+            _ => rand::random::<u128>() >> (128 - RANDOM_BITS),
+        };
+
+        self.previous = Some((timestamp, random));
+        Ok(encode(timestamp, random))
+    }
+}
+
+fn encode(timestamp: u64, random: u128) -> String {
+    let value = ((timestamp as u128) << RANDOM_BITS) | random;
+    radix::encode(&value.to_be_bytes(), CROCKFORD_BASE32, 26)
+}
+
+/// Decodes a ULID into its millisecond timestamp and random component. Decoding is case
+/// insensitive and accepts the Crockford aliases I and L for 1 and O for 0.
+pub fn decode_ulid(id: &str) -> Result<(u64, u128), String> {
+    let normalized = id
+        .trim()
+        .to_ascii_uppercase()
+        .replace(['I', 'L'], "1")
+        .replace('O', "0");
+
+    if normalized.len() != 26 {
+        return Err(format!(
+            "Invalid ULID length. Expected 26 characters but got {}",
+            normalized.len()
+        ));
+    }
+
+    let bytes = radix::decode(&normalized, CROCKFORD_BASE32, 16).ok_or_else(|| {
+        "Invalid ULID. Expected Crockford Base32 no greater than 7ZZZZZZZZZZZZZZZZZZZZZZZZZ"
+            .to_string()
+    })?;
+    let value = u128::from_be_bytes(bytes.try_into().unwrap_or_default());
+
+    Ok((
+        (value >> RANDOM_BITS) as u64,
+        value & ((1 << RANDOM_BITS) - 1),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encodes_timestamp_and_randomness() {
+        let (timestamp, random) = decode_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAV").unwrap();
+        assert_eq!(timestamp, 1_469_922_850_259);
+        assert_eq!(encode(timestamp, random), "01ARZ3NDEKTSV4RRFFQ69G5FAV");
+    }
+
+    #[test]
+    fn test_monotonic_ids_increment_within_a_millisecond() {
+        let mut generator = UlidGenerator::new(true);
+        let first = generator.generate(1_469_922_850_259).unwrap();
+        let second = generator.generate(1_469_922_850_259).unwrap();
+        let earlier_clock = generator.generate(1_469_922_850_000).unwrap();
+
+        assert!(first < second && second < earlier_clock);
+        assert_eq!(
+            decode_ulid(&second).unwrap().1,
+            decode_ulid(&first).unwrap().1 + 1
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_ulids() {
+        assert!(decode_ulid("01ARZ3NDEK").is_err());
+        assert!(decode_ulid("01ARZ3NDEKTSV4RRFFQ69G5FAU").is_err());
+        assert!(decode_ulid("81ARZ3NDEKTSV4RRFFQ69G5FAV").is_err());
+        assert_eq!(
+            decode_ulid("01arz3ndektsv4rrffq69g5fav").unwrap().0,
+            1_469_922_850_259
+        );
+    }
+}
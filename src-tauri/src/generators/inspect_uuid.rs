@@ -0,0 +1,197 @@
+use crate::encoding::BinaryEncoding;
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::{Uuid, Variant, Version};
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct UuidEncodings {
+    pub hyphenated: String,
+    pub simple: String,
+    pub braced: String,
+    pub urn: String,
+    pub uppercase: String,
+    pub base64_url: String,
+    pub integer: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct InspectedUuid {
+    pub version: usize,
+    pub version_name: String,
+    pub variant: String,
+    pub timestamp: Option<String>,
+    pub clock_sequence: Option<u16>,
+    pub node_id: Option<String>,
+    pub encodings: UuidEncodings,
+}
+
+/// Inspects a UUID and describes what it contains.
+///
+/// The UUID may be hyphenated, simple (32 hex digits), braced or a URN in any case. Time
+/// based UUIDs (v1, v6 and v7) report their embedded timestamp as an ISO-8601 UTC date time
+/// and v1/v6 UUIDs also report their clock sequence and node id.
+///
+/// # Arguments
+/// * `uuid` - The UUID to inspect
+///
+/// # Returns
+/// * `Ok(InspectedUuid)` - The version, variant, embedded fields and canonical encodings
+/// * `Err(String)` - An error message if the input is not a UUID
+///
+/// ```
+#[tauri::command]
+pub fn inspect_uuid(uuid: &str) -> Result<InspectedUuid, String> {
+    let uuid = Uuid::parse_str(uuid.trim()).map_err(|e| format!("Failed to parse UUID: {}", e))?;
+
+    let timestamp = uuid.get_timestamp();
+
+    let clock_sequence = match uuid.get_version() {
+        Some(Version::Mac | Version::SortMac) => {
+            timestamp.map(|timestamp| timestamp.to_gregorian().1)
+        }
+        _ => None,
+    };
+
+    let node_id = uuid.get_node_id().map(|node_id| {
+        node_id
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect::<Vec<String>>()
+            .join(":")
+    });
+
+    let timestamp = timestamp.and_then(|timestamp| {
+        let (seconds, nanoseconds) = timestamp.to_unix();
+        DateTime::<Utc>::from_timestamp(i64::try_from(seconds).ok()?, nanoseconds)
+            .map(|date_time| date_time.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    });
+
+    Ok(InspectedUuid {
+        version: uuid.get_version_num(),
+        version_name: version_name(uuid.get_version()).to_string(),
+        variant: variant_name(uuid.get_variant()).to_string(),
+        timestamp,
+        clock_sequence,
+        node_id,
+        encodings: UuidEncodings {
+            hyphenated: uuid.hyphenated().to_string(),
+            simple: uuid.simple().to_string(),
+            braced: uuid.braced().to_string(),
+            urn: uuid.urn().to_string(),
+            uppercase: uuid.hyphenated().to_string().to_uppercase(),
+            base64_url: BinaryEncoding::Base64UrlNoPad.encode(uuid.as_bytes()),
+            integer: uuid.as_u128().to_string(),
+        },
+    })
+}
+
+fn version_name(version: Option<Version>) -> &'static str {
+    match version {
+        Some(Version::Nil) => "Nil",
+        Some(Version::Mac) => "Time-based (v1)",
+        Some(Version::Dce) => "DCE Security (v2)",
+        Some(Version::Md5) => "Name-based MD5 (v3)",
+        Some(Version::Random) => "Random (v4)",
+        Some(Version::Sha1) => "Name-based SHA-1 (v5)",
+        Some(Version::SortMac) => "Reordered time-based (v6)",
+        Some(Version::SortRand) => "Unix time-based (v7)",
+        Some(Version::Custom) => "Custom (v8)",
+        Some(Version::Max) => "Max",
+        _ => "Unknown",
+    }
+}
+
+fn variant_name(variant: Variant) -> &'static str {
+    match variant {
+        Variant::NCS => "NCS (reserved)",
+        Variant::RFC4122 => "RFC 9562",
+        Variant::Microsoft => "Microsoft (reserved)",
+        _ => "Future (reserved)",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accepts_common_textual_forms() {
+        let forms = [
+            "c232ab00-9414-11ec-b3c8-9f6bdeced846",
+            "C232AB00941411ECB3C89F6BDECED846",
+            "{c232ab00-9414-11ec-b3c8-9f6bdeced846}",
+            "urn:uuid:c232ab00-9414-11ec-b3c8-9f6bdeced846",
+            "  c232ab00-9414-11ec-b3c8-9f6bdeced846\n",
+        ];
+
+        for form in forms {
+            let inspected = inspect_uuid(form).expect(form);
+            assert_eq!(
+                inspected.encodings.hyphenated,
+                "c232ab00-9414-11ec-b3c8-9f6bdeced846"
+            );
+        }
+    }
+
+    // Test vectors from RFC 9562 appendix A
+    #[test]
+    fn test_inspects_v1_uuid() {
+        let inspected = inspect_uuid("C232AB00-9414-11EC-B3C8-9F6BDECED846").unwrap();
+
+        assert_eq!(inspected.version, 1);
+        assert_eq!(inspected.version_name, "Time-based (v1)");
+        assert_eq!(inspected.variant, "RFC 9562");
+        assert_eq!(inspected.timestamp.as_deref(), Some("2022-02-22T19:22:22Z"));
+        assert_eq!(inspected.clock_sequence, Some(0x33c8));
+        assert_eq!(inspected.node_id.as_deref(), Some("9f:6b:de:ce:d8:46"));
+    }
+
+    #[test]
+    fn test_inspects_v6_and_v7_timestamps() {
+        let inspected = inspect_uuid("1EC9414C-232A-6B00-B3C8-9F6BDECED846").unwrap();
+        assert_eq!(inspected.version_name, "Reordered time-based (v6)");
+        assert_eq!(inspected.timestamp.as_deref(), Some("2022-02-22T19:22:22Z"));
+        assert_eq!(inspected.clock_sequence, Some(0x33c8));
+
+        let inspected = inspect_uuid("017F22E2-79B0-7CC3-98C4-DC0C0C07398F").unwrap();
+        assert_eq!(inspected.version_name, "Unix time-based (v7)");
+        assert_eq!(inspected.timestamp.as_deref(), Some("2022-02-22T19:22:22Z"));
+        assert_eq!(inspected.clock_sequence, None);
+        assert_eq!(inspected.node_id, None);
+    }
+
+    #[test]
+    fn test_inspects_uuids_without_timestamps() {
+        let inspected = inspect_uuid("919108f7-52d1-4320-9bac-f847db4148a8").unwrap();
+        assert_eq!(inspected.version, 4);
+        assert_eq!(inspected.timestamp, None);
+
+        let inspected = inspect_uuid("00000000-0000-0000-0000-000000000000").unwrap();
+        assert_eq!(inspected.version_name, "Nil");
+        assert_eq!(inspected.variant, "NCS (reserved)");
+    }
+
+    #[test]
+    fn test_returns_canonical_encodings() {
+        let encodings = inspect_uuid("c232ab00-9414-11ec-b3c8-9f6bdeced846")
+            .unwrap()
+            .encodings;
+
+        assert_eq!(encodings.simple, "c232ab00941411ecb3c89f6bdeced846");
+        assert_eq!(encodings.braced, "{c232ab00-9414-11ec-b3c8-9f6bdeced846}");
+        assert_eq!(
+            encodings.urn,
+            "urn:uuid:c232ab00-9414-11ec-b3c8-9f6bdeced846"
+        );
+        assert_eq!(encodings.uppercase, "C232AB00-9414-11EC-B3C8-9F6BDECED846");
+        // cspell:disable-next-line
+        assert_eq!(encodings.base64_url, "wjKrAJQUEeyzyJ9r3s7YRg");
+        assert_eq!(encodings.integer, "258133314363070689776975542038781941830");
+    }
+
+    #[test]
+    fn test_given_invalid_uuid_returns_error() {
+        let result = inspect_uuid("not-a-uuid");
+        assert!(result.unwrap_err().starts_with("Failed to parse UUID"));
+    }
+}
@@ -0,0 +1,235 @@
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// The words lipsum text is generated from
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub enum LipsumCorpus {
+    #[default]
+    Lorem,
+    Cicero,
+    Hipster,
+    Corporate,
+    Tech,
+    Cyrillic,
+    Cjk,
+    Arabic,
+    File(PathBuf),
+}
+
+/// A loaded corpus along with how its words and sentences are written
+#[derive(Debug)]
+pub struct Corpus {
+    pub words: Vec<String>,
+    pub word_separator: &'static str,
+    /// The period, question mark and exclamation mark of the script
+    pub punctuation: [&'static str; 3],
+    pub comma: &'static str,
+}
+
+// cspell:disable
+#[rustfmt::skip]
+const LOREM_WORDS: [&str; 68] = [
+    "lorem", "ipsum", "dolor", "sit", "amet", "consectetur", "adipiscing", "elit", "sed", "do",
+    "eiusmod", "tempor", "incididunt", "ut", "labore", "et", "dolore", "magna", "aliqua", "enim", "ad", "minim",
+    "veniam", "quis", "nostrud", "exercitation", "ullamco", "laboris", "nisi", "ut", "aliquip", "ex", "ea",
+    "commodo", "consequat", "duis", "aute", "irure", "dolor", "in", "reprehenderit", "in", "voluptate", "velit",
+    "esse", "cillum", "dolore", "eu", "fugiat", "nulla", "pariatur", "excepteur", "sint", "occaecat", "cupidatat",
+    "non", "proident", "sunt", "in", "culpa", "qui", "officia", "deserunt", "mollit", "anim", "id", "est", "laborum"
+];
+
+/// Sections 1.10.32 and 1.10.33 of Cicero's "De finibus bonorum et malorum", the source of
+/// lorem ipsum
+const CICERO_TEXT: &str = "Sed ut perspiciatis unde omnis iste natus error sit voluptatem \
+    accusantium doloremque laudantium, totam rem aperiam, eaque ipsa quae ab illo inventore \
+    veritatis et quasi architecto beatae vitae dicta sunt explicabo. Nemo enim ipsam voluptatem \
+    quia voluptas sit aspernatur aut odit aut fugit, sed quia consequuntur magni dolores eos qui \
+    ratione voluptatem sequi nesciunt. Neque porro quisquam est, qui dolorem ipsum quia dolor sit \
+    amet, consectetur, adipisci velit, sed quia non numquam eius modi tempora incidunt ut labore \
+    et dolore magnam aliquam quaerat voluptatem. Ut enim ad minima veniam, quis nostrum \
+    exercitationem ullam corporis suscipit laboriosam, nisi ut aliquid ex ea commodi consequatur? \
+    Quis autem vel eum iure reprehenderit qui in ea voluptate velit esse quam nihil molestiae \
+    consequatur, vel illum qui dolorem eum fugiat quo voluptas nulla pariatur? At vero eos et \
+    accusamus et iusto odio dignissimos ducimus qui blanditiis praesentium voluptatum deleniti \
+    atque corrupti quos dolores et quas molestias excepturi sint occaecati cupiditate non \
+    provident, similique sunt in culpa qui officia deserunt mollitia animi, id est laborum et \
+    dolorum fuga. Et harum quidem rerum facilis est et expedita distinctio. Nam libero tempore, \
+    cum soluta nobis est eligendi optio cumque nihil impedit quo minus id quod maxime placeat \
+    facere possimus, omnis voluptas assumenda est, omnis dolor repellendus. Temporibus autem \
+    quibusdam et aut officiis debitis aut rerum necessitatibus saepe eveniet ut et voluptates \
+    repudiandae sint et molestiae non recusandae. Itaque earum rerum hic tenetur a sapiente \
+    delectus, ut aut reiciendis voluptatibus maiores alias consequatur aut perferendis doloribus \
+    asperiores repellat.";
+
+#[rustfmt::skip]
+const HIPSTER_WORDS: [&str; 48] = [
+    "artisan", "kombucha", "sriracha", "fixie", "vinyl", "kale", "chips", "sustainable", "beard", "flannel",
+    "cold-pressed", "microdosing", "pour-over", "typewriter", "succulents", "letterpress", "selvage", "quinoa",
+    "gastropub", "tofu", "vegan", "heirloom", "craft", "beer", "mustache", "ethical", "biodiesel", "polaroid",
+    "farm-to-table", "single-origin", "coffee", "bespoke", "umami", "tote", "bag", "chambray", "mixtape",
+    "normcore", "hashtag", "fanny", "pack", "brunch", "avocado", "toast", "lumbersexual", "thundercats",
+    "taxidermy", "meditation"
+];
+
+#[rustfmt::skip]
+const CORPORATE_WORDS: [&str; 48] = [
+    "synergy", "leverage", "paradigm", "stakeholder", "deliverables", "bandwidth", "alignment", "scalable",
+    "holistic", "roadmap", "streamline", "empower", "value-added", "best-of-breed", "mission-critical",
+    "action", "items", "circle", "back", "touch", "base", "offline", "low-hanging", "fruit", "move", "the",
+    "needle", "drill", "down", "core", "competencies", "proactive", "robust", "ecosystem", "visibility",
+    "thought", "leadership", "quarterly", "objectives", "key", "results", "onboarding", "cross-functional",
+    "pivot", "disrupt", "benchmark", "granular", "optimize"
+];
+
+#[rustfmt::skip]
+const TECH_WORDS: [&str; 48] = [
+    "kubernetes", "microservices", "serverless", "pipeline", "container", "cluster", "latency", "throughput",
+    "cache", "endpoint", "payload", "schema", "migration", "deployment", "rollback", "observability",
+    "telemetry", "tracing", "webhook", "middleware", "runtime", "compiler", "refactor", "monorepo", "linter",
+    "dependency", "artifact", "registry", "load", "balancer", "sharding", "replica", "idempotent", "async",
+    "callback", "promise", "thread", "mutex", "queue", "stream", "protocol", "handshake", "certificate",
+    "token", "gateway", "proxy", "terraform", "blockchain"
+];
+
+#[rustfmt::skip]
+const CYRILLIC_WORDS: [&str; 48] = [
+    "время", "человек", "жизнь", "день", "рука", "работа", "слово", "место", "лицо", "друг", "глаз", "вопрос",
+    "дом", "сторона", "страна", "мир", "случай", "голова", "ребёнок", "сила", "конец", "вид", "система",
+    "часть", "город", "отношение", "женщина", "деньги", "земля", "машина", "вода", "отец", "проблема",
+    "час", "право", "нога", "решение", "дверь", "образ", "история", "власть", "закон", "война", "бог",
+    "голос", "тысяча", "книга", "возможность"
+];
+
+#[rustfmt::skip]
+const CJK_WORDS: [&str; 48] = [
+    "我们", "时间", "国家", "工作", "问题", "发展", "社会", "经济", "生活", "世界", "文化", "历史",
+    "学习", "技术", "城市", "朋友", "学校", "老师", "学生", "天气", "电话", "电脑", "音乐", "电影",
+    "春天", "夏天", "秋天", "冬天", "山水", "花园", "早上", "晚上", "中午", "今天", "明天", "昨天",
+    "日本", "東京", "한국", "서울", "사람", "시간", "漢字", "言葉", "新聞", "会社", "大学", "自然"
+];
+
+#[rustfmt::skip]
+const ARABIC_WORDS: [&str; 48] = [
+    "كتاب", "بيت", "مدرسة", "قلم", "شمس", "قمر", "ماء", "سماء", "أرض", "بحر", "جبل", "مدينة", "طريق",
+    "باب", "نافذة", "صديق", "معلم", "طالب", "عمل", "وقت", "يوم", "ليلة", "صباح", "مساء", "سنة", "شهر",
+    "أسبوع", "لغة", "علم", "تاريخ", "عالم", "حياة", "قلب", "عين", "يد", "رأس", "كلمة", "جملة", "سؤال",
+    "جواب", "فكرة", "نور", "زهرة", "شجرة", "طعام", "سوق", "سيارة", "رسالة"
+];
+// cspell:enable
+
+const LATIN_PUNCTUATION: [&str; 3] = [".", "?", "!"];
+
+impl LipsumCorpus {
+    /// Loads the words of the corpus, reading them from disk for user supplied word lists
+    pub fn load(&self) -> Result<Corpus, String> {
+        let corpus = match self {
+            LipsumCorpus::Lorem => latin(&LOREM_WORDS),
+            LipsumCorpus::Cicero => Corpus {
+                words: CICERO_TEXT
+                    .split_whitespace()
+                    .map(|word| word.trim_matches([',', '.', '?']).to_lowercase())
+                    .collect(),
+                ..latin(&[])
+            },
+            LipsumCorpus::Hipster => latin(&HIPSTER_WORDS),
+            LipsumCorpus::Corporate => latin(&CORPORATE_WORDS),
+            LipsumCorpus::Tech => latin(&TECH_WORDS),
+            LipsumCorpus::Cyrillic => latin(&CYRILLIC_WORDS),
+            LipsumCorpus::Cjk => Corpus {
+                words: to_strings(&CJK_WORDS),
+                word_separator: "",
+                punctuation: ["。", "？", "！"],
+                comma: "，",
+            },
+            LipsumCorpus::Arabic => Corpus {
+                words: to_strings(&ARABIC_WORDS),
+                word_separator: " ",
+                punctuation: [".", "؟", "!"],
+                comma: "،",
+            },
+            LipsumCorpus::File(path) => {
+                let contents = fs::read_to_string(path)
+                    .map_err(|e| format!("Failed to read word list: {}", e))?;
+                Corpus {
+                    words: contents.split_whitespace().map(String::from).collect(),
+                    ..latin(&[])
+                }
+            }
+        };
+
+        if corpus.words.is_empty() {
+            return Err("The word list does not contain any words".to_string());
+        }
+
+        Ok(corpus)
+    }
+}
+
+/// A corpus written like Latin, with words separated by spaces and Latin punctuation
+fn latin(words: &[&str]) -> Corpus {
+    Corpus {
+        words: to_strings(words),
+        word_separator: " ",
+        punctuation: LATIN_PUNCTUATION,
+        comma: ",",
+    }
+}
+
+fn to_strings(words: &[&str]) -> Vec<String> {
+    words.iter().map(|word| word.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::NamedTempFile;
+
+    #[test]
+    fn test_loads_built_in_corpora() {
+        let corpora = [
+            LipsumCorpus::Lorem,
+            LipsumCorpus::Cicero,
+            LipsumCorpus::Hipster,
+            LipsumCorpus::Corporate,
+            LipsumCorpus::Tech,
+            LipsumCorpus::Cyrillic,
+            LipsumCorpus::Cjk,
+            LipsumCorpus::Arabic,
+        ];
+
+        for corpus in corpora {
+            let loaded = corpus.load().unwrap();
+            assert!(!loaded.words.is_empty(), "{:?}", corpus);
+            assert!(loaded.words.iter().all(|word| !word.is_empty()));
+        }
+
+        let cicero = LipsumCorpus::Cicero.load().unwrap();
+        assert_eq!(cicero.words[..3], ["sed", "ut", "perspiciatis"]);
+        assert!(cicero.words.iter().all(|word| !word.contains([',', '.'])));
+    }
+
+    #[test]
+    fn test_loads_word_lists_from_files() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "alpha beta\ngamma\n\n  delta").unwrap();
+
+        let corpus = LipsumCorpus::File(temp_file.path().to_path_buf())
+            .load()
+            .unwrap();
+        assert_eq!(corpus.words, ["alpha", "beta", "gamma", "delta"]);
+    }
+
+    #[test]
+    fn test_given_empty_or_missing_word_list_returns_error() {
+        let temp_file = NamedTempFile::new().unwrap();
+        let result = LipsumCorpus::File(temp_file.path().to_path_buf()).load();
+        assert_eq!(
+            result.unwrap_err(),
+            "The word list does not contain any words"
+        );
+
+        let result = LipsumCorpus::File(PathBuf::from("/does/not/exist")).load();
+        assert!(result.unwrap_err().starts_with("Failed to read word list"));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::encoding::web::html_escape;
+use serde::{Deserialize, Serialize};
+
+/// How generated lipsum text is written
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq)]
+pub enum LipsumFormat {
+    #[default]
+    PlainText,
+    Html,
+    Markdown,
+}
+
+/// The structure added to generated lipsum text. Headings and lists are only added between
+/// paragraphs, inline emphasis and links to words of any text.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default)]
+pub struct LipsumMarkup {
+    pub headings: bool,
+    pub lists: bool,
+    pub inline: bool,
+    /// Whether to wrap the text in a complete document with a title
+    pub document: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum InlineStyle {
+    Emphasis,
+    Strong,
+    Link,
+}
+
+const PLACEHOLDER_LINK: &str = "https://example.com";
+
+impl LipsumFormat {
+    /// Escapes a word so it is shown literally
+    pub fn escape(&self, text: &str) -> String {
+        match self {
+            LipsumFormat::PlainText => text.to_string(),
+            LipsumFormat::Html => html_escape(text),
+            LipsumFormat::Markdown => text
+                .chars()
+                .flat_map(|c| {
+                    let escape = "\\`*_[]()#<>!|~".contains(c);
+                    escape.then_some('\\').into_iter().chain([c])
+                })
+                .collect(),
+        }
+    }
+
+    /// Styles already escaped text
+    pub fn inline(&self, style: InlineStyle, text: &str) -> String {
+        match (self, style) {
+            (LipsumFormat::PlainText, _) => text.to_string(),
+            (LipsumFormat::Html, InlineStyle::Emphasis) => format!("<em>{}</em>", text),
+            (LipsumFormat::Html, InlineStyle::Strong) => format!("<strong>{}</strong>", text),
+            (LipsumFormat::Html, InlineStyle::Link) => {
+                format!("<a href=\"{}\">{}</a>", PLACEHOLDER_LINK, text)
+            }
+            (LipsumFormat::Markdown, InlineStyle::Emphasis) => format!("_{}_", text),
+            (LipsumFormat::Markdown, InlineStyle::Strong) => format!("**{}**", text),
+            (LipsumFormat::Markdown, InlineStyle::Link) => {
+                format!("[{}]({})", text, PLACEHOLDER_LINK)
+            }
+        }
+    }
+
+    pub fn paragraph(&self, text: &str) -> String {
+        match self {
+            LipsumFormat::Html => format!("<p>{}</p>", text),
+            _ => text.to_string(),
+        }
+    }
+
+    pub fn heading(&self, level: usize, text: &str) -> String {
+        match self {
+            LipsumFormat::PlainText => text.to_string(),
+            LipsumFormat::Html => format!("<h{}>{}</h{}>", level, text, level),
+            LipsumFormat::Markdown => format!("{} {}", "#".repeat(level), text),
+        }
+    }
+
+    pub fn list(&self, ordered: bool, items: &[String]) -> String {
+        let marker = |index: usize| {
+            if ordered {
+                format!("{}.", index + 1)
+            } else {
+                "-".to_string()
+            }
+        };
+
+        match self {
+            LipsumFormat::Html => {
+                let tag = if ordered { "ol" } else { "ul" };
+                let items = items
+                    .iter()
+                    .map(|item| format!("  <li>{}</li>", item))
+                    .collect::<Vec<String>>();
+                format!("<{}>\n{}\n</{}>", tag, items.join("\n"), tag)
+            }
+            _ => items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| format!("{} {}", marker(index), item))
+                .collect::<Vec<String>>()
+                .join("\n"),
+        }
+    }
+
+    /// Joins blocks (paragraphs, headings and lists), optionally into a complete document
+    pub fn blocks(&self, blocks: &[String], title: Option<&str>) -> String {
+        let separator = match self {
+            LipsumFormat::Html => "\n",
+            _ => "\n\n",
+        };
+        let body = blocks.join(separator);
+
+        match (self, title) {
+            (_, None) => body,
+            (LipsumFormat::Html, Some(title)) => format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}\n{}\n</body>\n</html>",
+                title,
+                self.heading(1, title),
+                body
+            ),
+            (_, Some(title)) => format!("{}{}{}", self.heading(1, title), separator, body),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_renders_html() {
+        let format = LipsumFormat::Html;
+
+        assert_eq!(format.escape("a<b"), "a&lt;b");
+        assert_eq!(
+            format.inline(InlineStyle::Strong, "a"),
+            "<strong>a</strong>"
+        );
+        assert_eq!(format.paragraph("a"), "<p>a</p>");
+        assert_eq!(format.heading(2, "a"), "<h2>a</h2>");
+        assert_eq!(
+            format.list(true, &["a".to_string(), "b".to_string()]),
+            "<ol>\n  <li>a</li>\n  <li>b</li>\n</ol>"
+        );
+        assert!(format
+            .blocks(&["<p>a</p>".to_string()], Some("Title"))
+            .contains("<title>Title</title>"));
+    }
+
+    #[test]
+    fn test_renders_markdown() {
+        let format = LipsumFormat::Markdown;
+
+        assert_eq!(format.escape("a*b_c"), "a\\*b\\_c");
+        assert_eq!(
+            format.inline(InlineStyle::Link, "a"),
+            "[a](https://example.com)"
+        );
+        assert_eq!(format.heading(2, "a"), "## a");
+        assert_eq!(
+            format.list(false, &["a".to_string(), "b".to_string()]),
+            "- a\n- b"
+        );
+        assert_eq!(
+            format.blocks(&["a".to_string(), "b".to_string()], Some("Title")),
+            "# Title\n\na\n\nb"
+        );
+    }
+
+    #[test]
+    fn test_plain_text_is_unchanged() {
+        let format = LipsumFormat::PlainText;
+
+        assert_eq!(format.escape("a<b*"), "a<b*");
+        assert_eq!(format.inline(InlineStyle::Emphasis, "a"), "a");
+        assert_eq!(format.paragraph("a"), "a");
+        assert_eq!(
+            format.blocks(&["a".to_string(), "b".to_string()], None),
+            "a\n\nb"
+        );
+    }
+}
@@ -0,0 +1,834 @@
+use crate::generators::mock_json::{generate_from_pattern, infer_schema};
+use crate::generators::{
+    generate_fake_data, generate_lipsum, seeded_rng, FakeField, LipsumType, UuidGenerator,
+    UuidVersion,
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+use rand::prelude::IndexedRandom;
+use rand::rngs::StdRng;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Number, Value};
+
+/// The deepest nesting generated, which stops recursive schemas
+const MAX_DEPTH: usize = 32;
+
+/// The nesting below which optional properties and array items are left out, so recursive
+/// schemas stay small
+const OPTIONAL_DEPTH: usize = 8;
+
+/// The number of attempts to generate an item that is not already in a unique array
+const UNIQUE_ATTEMPTS: usize = 100;
+
+/// The longest string generated
+const MAX_LENGTH: usize = 10_000;
+
+/// The most items generated in an array
+const MAX_ITEMS: usize = 1000;
+
+/// The most values and string characters generated in total, so nested arrays cannot make
+/// the documents grow without limit
+const MAX_SIZE: usize = 1_000_000;
+
+/// Unix timestamps of 2000-01-01 and 2030-12-31, the range of generated dates and times
+const DATE_TIME_RANGE: (i64, i64) = (946_684_800, 1_924_905_600);
+
+/// What the input of the mock JSON generator is
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum MockJsonSource {
+    /// A JSON Schema the documents must conform to
+    Schema,
+    /// A sample document the documents must look like
+    Sample,
+}
+
+/// Generates mock JSON documents conforming to a JSON Schema or shaped like a sample document.
+///
+/// The documents respect `const`, `enum`, `required`, `minimum`/`maximum` (and their
+/// exclusive variants), `multipleOf`, `minLength`/`maxLength`, `pattern`, `minItems`/
+/// `maxItems` and `uniqueItems`, and follow local `$ref`s, `allOf`, `anyOf` and `oneOf`.
+/// Optional properties are included at random. Strings with a `uuid`, `email`, `ipv4`,
+/// `ipv6`, `date-time`, `date`, `time`, `uri` or `hostname` format use the UUID and fake
+/// data generators, other strings are lorem ipsum.
+/// Strings are at most 10,000 characters long and arrays at most 1,000 items long.
+///
+/// A sample document is turned into a schema first, in which every property is required and
+/// strings keep the format they have in the sample.
+///
+/// The same seed always generates the same documents. Without a seed they are random.
+///
+/// # Arguments
+/// * `count` - The number of documents to generate
+/// * `input` - The JSON Schema or sample document
+/// * `source` - Whether the input is a schema or a sample
+/// * `seed` - The seed of the random number generator, if the output must be reproducible
+///
+/// # Returns
+/// * `Ok(Vec<Value>)` - The generated documents
+/// * `Err(String)` - An error message if the input is not valid JSON or the schema uses
+///   unsupported features or cannot be satisfied
+///
+/// ```
+#[tauri::command]
+pub fn generate_mock_json(
+    count: usize,
+    input: &str,
+    source: MockJsonSource,
+    seed: Option<u64>,
+) -> Result<Vec<Value>, String> {
+    let input: Value =
+        serde_json::from_str(input).map_err(|e| format!("Failed to parse JSON: {}", e))?;
+    let schema = match source {
+        MockJsonSource::Schema => input,
+        MockJsonSource::Sample => infer_schema(&input),
+    };
+
+    let mut generator = MockGenerator {
+        root: &schema,
+        rng: seeded_rng(seed),
+        size: 0,
+    };
+
+    (0..count).map(|_| generator.generate(&schema, 0)).collect()
+}
+
+struct MockGenerator<'a> {
+    /// The schema local `$ref`s point into
+    root: &'a Value,
+    rng: StdRng,
+    /// The number of values and string characters generated so far
+    size: usize,
+}
+
+impl MockGenerator<'_> {
+    fn generate(&mut self, schema: &Value, depth: usize) -> Result<Value, String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("Schema nests deeper than {} levels", MAX_DEPTH));
+        }
+
+        let schema = self.resolve(schema, depth)?;
+
+        if let Some(value) = schema.get("const") {
+            self.add_size(value_size(value))?;
+            return Ok(value.clone());
+        }
+        if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+            let value = values
+                .choose(&mut self.rng)
+                .ok_or_else(|| "Schema has an empty enum".to_string())?;
+            self.add_size(value_size(value))?;
+            return Ok(value.clone());
+        }
+
+        let schema_type = match schema.get("type") {
+            Some(Value::String(schema_type)) => schema_type.as_str(),
+            Some(Value::Array(types)) => types
+                .choose(&mut self.rng)
+                .and_then(Value::as_str)
+                .ok_or_else(|| "Schema has no valid type".to_string())?,
+            _ => infer_type(&schema),
+        };
+
+        let value = match schema_type {
+            "object" => self.generate_object(&schema, depth)?,
+            "array" => self.generate_array(&schema, depth)?,
+            "string" => Value::String(self.generate_string(&schema)?),
+            "integer" => self.generate_integer(&schema)?,
+            "number" => self.generate_number(&schema)?,
+            "boolean" => Value::Bool(self.rng.random()),
+            "null" => Value::Null,
+            other => return Err(format!("Unsupported type: {}", other)),
+        };
+
+        // The items and properties of arrays and objects were counted when generated
+        self.add_size(match &value {
+            Value::String(string) => string.len().max(1),
+            _ => 1,
+        })?;
+
+        Ok(value)
+    }
+
+    fn add_size(&mut self, size: usize) -> Result<(), String> {
+        self.size = self.size.saturating_add(size);
+        if self.size > MAX_SIZE {
+            return Err(format!(
+                "The documents would hold more than {} values and characters",
+                MAX_SIZE
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Follows `$ref`s and merges `allOf` and one of the `anyOf` or `oneOf` subschemas into
+    /// the schema
+    fn resolve(&mut self, schema: &Value, depth: usize) -> Result<Map<String, Value>, String> {
+        if depth > MAX_DEPTH {
+            return Err(format!("Schema nests deeper than {} levels", MAX_DEPTH));
+        }
+
+        let mut resolved = match schema {
+            Value::Object(schema) => schema.clone(),
+            Value::Bool(true) => Map::new(),
+            Value::Bool(false) => return Err("Schema allows no value".to_string()),
+            _ => return Err(format!("Invalid schema: {}", schema)),
+        };
+
+        if let Some(reference) = resolved.remove("$ref") {
+            let target = reference
+                .as_str()
+                .and_then(|reference| reference.strip_prefix('#'))
+                .and_then(|pointer| self.root.pointer(pointer))
+                .ok_or_else(|| format!("Unsupported $ref: {}", reference))?;
+            let target = self.resolve(target, depth + 1)?;
+            merge(&mut resolved, target);
+        }
+
+        if let Some(Value::Array(subschemas)) = resolved.remove("allOf") {
+            for subschema in &subschemas {
+                let subschema = self.resolve(subschema, depth + 1)?;
+                merge(&mut resolved, subschema);
+            }
+        }
+
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(Value::Array(subschemas)) = resolved.remove(keyword) {
+                if let Some(subschema) = subschemas.choose(&mut self.rng) {
+                    let subschema = self.resolve(subschema, depth + 1)?;
+                    merge(&mut resolved, subschema);
+                }
+            }
+        }
+
+        Ok(resolved)
+    }
+
+    fn generate_object(
+        &mut self,
+        schema: &Map<String, Value>,
+        depth: usize,
+    ) -> Result<Value, String> {
+        let empty = Map::new();
+        let properties = schema
+            .get("properties")
+            .and_then(Value::as_object)
+            .unwrap_or(&empty);
+        let required: Vec<&str> = schema
+            .get("required")
+            .and_then(Value::as_array)
+            .map(|required| required.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        let mut object = Map::new();
+
+        for (name, property) in properties {
+            let include = required.contains(&name.as_str())
+                || (depth < OPTIONAL_DEPTH && self.rng.random_bool(0.5));
+            if include {
+                object.insert(name.clone(), self.generate(property, depth + 1)?);
+            }
+        }
+
+        // Required properties without a schema of their own
+        let additional = match schema.get("additionalProperties") {
+            Some(Value::Object(additional)) => Value::Object(additional.clone()),
+            _ => Value::Bool(true),
+        };
+        for name in required {
+            if !object.contains_key(name) {
+                object.insert(name.to_string(), self.generate(&additional, depth + 1)?);
+            }
+        }
+
+        Ok(Value::Object(object))
+    }
+
+    fn generate_array(
+        &mut self,
+        schema: &Map<String, Value>,
+        depth: usize,
+    ) -> Result<Value, String> {
+        // Tuples have a schema per position
+        let tuple = schema
+            .get("prefixItems")
+            .or_else(|| schema.get("items").filter(|items| items.is_array()))
+            .and_then(Value::as_array);
+        if let Some(tuple) = tuple {
+            return tuple
+                .iter()
+                .map(|item| self.generate(item, depth + 1))
+                .collect::<Result<Vec<Value>, String>>()
+                .map(Value::Array);
+        }
+
+        let items = schema.get("items").cloned().unwrap_or(Value::Bool(true));
+        let min_items = get_usize(schema, "minItems");
+        let max_items = get_usize(schema, "maxItems");
+        if min_items.is_some_and(|min_items| min_items > MAX_ITEMS) {
+            return Err(format!("minItems may be at most {}", MAX_ITEMS));
+        }
+
+        let min_items = min_items.unwrap_or_else(|| max_items.unwrap_or(1).min(1));
+        let max_items = max_items.unwrap_or(min_items + 3).min(MAX_ITEMS);
+        if min_items > max_items {
+            return Err(format!(
+                "No array with between {} and {} items",
+                min_items, max_items
+            ));
+        }
+        let count = if depth < OPTIONAL_DEPTH {
+            self.rng.random_range(min_items..=max_items)
+        } else {
+            min_items
+        };
+        let unique = schema.get("uniqueItems") == Some(&Value::Bool(true));
+
+        let mut array = Vec::with_capacity(count);
+        while array.len() < count {
+            let mut item = self.generate(&items, depth + 1)?;
+
+            let mut attempts = 1;
+            while unique && array.contains(&item) {
+                if attempts == UNIQUE_ATTEMPTS {
+                    return Err(format!("Failed to generate {} unique items", count));
+                }
+                item = self.generate(&items, depth + 1)?;
+                attempts += 1;
+            }
+
+            array.push(item);
+        }
+
+        Ok(Value::Array(array))
+    }
+
+    fn generate_string(&mut self, schema: &Map<String, Value>) -> Result<String, String> {
+        let format = schema.get("format").and_then(Value::as_str);
+
+        match format {
+            Some("uuid") => {
+                let seed = self.rng.random();
+                return Ok(UuidGenerator::new(UuidVersion::V4, false, true, Some(seed)).generate());
+            }
+            Some("email") => return self.generate_fake(FakeField::Email),
+            Some("ipv4") => return self.generate_fake(FakeField::Ipv4),
+            Some("ipv6") => return self.generate_fake(FakeField::Ipv6),
+            Some("date-time" | "date" | "time") => {
+                let seconds = self.rng.random_range(DATE_TIME_RANGE.0..DATE_TIME_RANGE.1);
+                let date_time = DateTime::<Utc>::from_timestamp(seconds, 0).unwrap_or_default();
+                return Ok(match format {
+                    Some("date") => date_time.format("%Y-%m-%d").to_string(),
+                    Some("time") => date_time.format("%H:%M:%S").to_string(),
+                    _ => date_time.to_rfc3339_opts(SecondsFormat::Secs, true),
+                });
+            }
+            Some("uri" | "url") => {
+                return Ok(format!("https://example.com/{}", self.generate_words(1)?));
+            }
+            Some("hostname") => return Ok(format!("{}.example.com", self.generate_words(1)?)),
+            // Other formats are only annotations
+            _ => {}
+        }
+
+        let pattern = schema.get("pattern").and_then(Value::as_str);
+        let min_length = get_usize(schema, "minLength");
+        let max_length = get_usize(schema, "maxLength");
+        if pattern.is_none() && min_length.is_none() && max_length.is_none() {
+            let count = self.rng.random_range(2..=6);
+            return self.generate_words(count);
+        }
+        if min_length.is_some_and(|min_length| min_length > MAX_LENGTH) {
+            return Err(format!("minLength may be at most {}", MAX_LENGTH));
+        }
+
+        // Strings are not empty unless a pattern or maxLength allows it
+        let min_length = min_length
+            .unwrap_or_else(|| usize::from(pattern.is_none()).min(max_length.unwrap_or(1)));
+        let max_length = max_length
+            .unwrap_or(match pattern {
+                Some(_) => MAX_LENGTH,
+                None => min_length + 20,
+            })
+            .min(MAX_LENGTH);
+        if min_length > max_length {
+            return Err(format!(
+                "No string with between {} and {} characters",
+                min_length, max_length
+            ));
+        }
+
+        if let Some(pattern) = pattern {
+            return generate_from_pattern(pattern, min_length..=max_length, &mut self.rng);
+        }
+
+        let length = self.rng.random_range(min_length..=max_length);
+        let seed = self.rng.random();
+
+        generate_lipsum(
+            length,
+            LipsumType::Characters,
+            None,
+            None,
+            None,
+            None,
+            Some(seed),
+        )
+    }
+
+    /// Generates lorem ipsum words without the closing punctuation
+    fn generate_words(&mut self, count: usize) -> Result<String, String> {
+        let seed = self.rng.random();
+        let words = generate_lipsum(count, LipsumType::Words, None, None, None, None, Some(seed))?;
+
+        Ok(words
+            .trim_end_matches(|c: char| c.is_ascii_punctuation())
+            .to_lowercase())
+    }
+
+    fn generate_fake(&mut self, field: FakeField) -> Result<String, String> {
+        let seed = self.rng.random();
+        let records = generate_fake_data(1, vec![field], None, Some(seed))?;
+
+        Ok(records.concat().concat())
+    }
+
+    fn generate_integer(&mut self, schema: &Map<String, Value>) -> Result<Value, String> {
+        let multiple_of = match schema.get("multipleOf").and_then(Value::as_f64) {
+            Some(multiple_of) => integer_step(multiple_of)?,
+            None => 1.0,
+        };
+        let multiple = self.generate_multiple(schema, multiple_of)?;
+        if !(i64::MIN as f64..i64::MAX as f64).contains(&multiple) {
+            return Err(format!("Integer out of range: {}", multiple));
+        }
+
+        Ok(Value::Number(Number::from(multiple.round() as i64)))
+    }
+
+    /// Generates a multiple of `multiple_of` within the bounds of the schema
+    fn generate_multiple(
+        &mut self,
+        schema: &Map<String, Value>,
+        multiple_of: f64,
+    ) -> Result<f64, String> {
+        if multiple_of <= 0.0 {
+            return Err(format!("Invalid multipleOf: {}", multiple_of));
+        }
+
+        let (minimum, maximum) = get_bounds(schema);
+        let mut low = (minimum.value / multiple_of).ceil();
+        if minimum.exclusive && low * multiple_of == minimum.value {
+            low += 1.0;
+        }
+        let mut high = (maximum.value / multiple_of).floor();
+        if maximum.exclusive && high * multiple_of == maximum.value {
+            high -= 1.0;
+        }
+        let no_multiple = || {
+            format!(
+                "No multiple of {} between {} and {}",
+                multiple_of, minimum.value, maximum.value
+            )
+        };
+        // Also rejects bounds too far apart to divide by the multiple
+        if !(low.is_finite() && high.is_finite() && low <= high) {
+            return Err(no_multiple());
+        }
+
+        let count = random_between(&mut self.rng, low, high + 1.0)
+            .floor()
+            .clamp(low, high);
+        let multiple = count * multiple_of;
+        if !is_within(multiple, &minimum, &maximum) {
+            return Err(no_multiple());
+        }
+
+        Ok(multiple)
+    }
+
+    fn generate_number(&mut self, schema: &Map<String, Value>) -> Result<Value, String> {
+        let value = match schema.get("multipleOf").and_then(Value::as_f64) {
+            Some(multiple_of) => self.generate_multiple(schema, multiple_of)?,
+            None => self.generate_float(schema)?,
+        };
+
+        Number::from_f64(value)
+            .map(Value::Number)
+            .ok_or_else(|| format!("Invalid number: {}", value))
+    }
+
+    fn generate_float(&mut self, schema: &Map<String, Value>) -> Result<f64, String> {
+        let (minimum, maximum) = get_bounds(schema);
+
+        // Rounded to two decimals, unless that falls outside the bounds
+        let value = random_between(&mut self.rng, minimum.value, maximum.value);
+        let rounded = (value * 100.0).round() / 100.0;
+        let midpoint = minimum.value / 2.0 + maximum.value / 2.0;
+
+        [rounded, value, midpoint]
+            .into_iter()
+            .find(|value| is_within(*value, &minimum, &maximum))
+            .ok_or_else(|| format!("No number between {} and {}", minimum.value, maximum.value))
+    }
+}
+
+struct Bound {
+    value: f64,
+    exclusive: bool,
+}
+
+/// Gets the lower and upper bound of a number, 1000 apart if only one is given and 0 and 1000
+/// if neither is
+fn get_bounds(schema: &Map<String, Value>) -> (Bound, Bound) {
+    // Both keywords may be given, in which case the stricter bound applies
+    let get_bound = |inclusive: &str, exclusive: &str, is_stricter: fn(f64, f64) -> bool| {
+        // Draft 4, where the exclusive keyword is a flag
+        let inclusive_bound = schema
+            .get(inclusive)
+            .and_then(Value::as_f64)
+            .map(|value| Bound {
+                value,
+                exclusive: schema.get(exclusive) == Some(&Value::Bool(true)),
+            });
+        // Draft 6 and later
+        let exclusive_bound = schema
+            .get(exclusive)
+            .and_then(Value::as_f64)
+            .map(|value| Bound {
+                value,
+                exclusive: true,
+            });
+
+        match (inclusive_bound, exclusive_bound) {
+            (Some(inclusive), Some(exclusive)) if is_stricter(inclusive.value, exclusive.value) => {
+                Some(inclusive)
+            }
+            (inclusive, exclusive) => exclusive.or(inclusive),
+        }
+    };
+    let inclusive = |value| Bound {
+        value,
+        exclusive: false,
+    };
+
+    match (
+        get_bound("minimum", "exclusiveMinimum", |inclusive, exclusive| {
+            inclusive > exclusive
+        }),
+        get_bound("maximum", "exclusiveMaximum", |inclusive, exclusive| {
+            inclusive < exclusive
+        }),
+    ) {
+        (Some(minimum), Some(maximum)) => (minimum, maximum),
+        (Some(minimum), None) => {
+            let maximum = inclusive(minimum.value + 1000.0);
+            (minimum, maximum)
+        }
+        (None, Some(maximum)) => (inclusive(maximum.value - 1000.0), maximum),
+        (None, None) => (inclusive(0.0), inclusive(1000.0)),
+    }
+}
+
+fn is_within(value: f64, minimum: &Bound, maximum: &Bound) -> bool {
+    (value > minimum.value || (!minimum.exclusive && value == minimum.value))
+        && (value < maximum.value || (!maximum.exclusive && value == maximum.value))
+}
+
+/// Picks a number between `low` and `high` without overflowing however far apart they are
+fn random_between(rng: &mut impl Rng, low: f64, high: f64) -> f64 {
+    let t: f64 = rng.random();
+    low * (1.0 - t) + high * t
+}
+
+/// Gets the smallest integer that is a multiple of `multiple_of`, their least common multiple
+fn integer_step(multiple_of: f64) -> Result<f64, String> {
+    if multiple_of <= 0.0 {
+        return Err(format!("Invalid multipleOf: {}", multiple_of));
+    }
+    if multiple_of.fract() == 0.0 {
+        return Ok(multiple_of);
+    }
+
+    // A decimal is a fraction n / 10^k, and the least common multiple of it and 1 is
+    // n / gcd(n, 10^k)
+    for decimals in 1..=9 {
+        let denominator = 10u64.pow(decimals);
+        let numerator = multiple_of * denominator as f64;
+        let rounded = numerator.round();
+        if rounded >= 1.0 && rounded < 2f64.powi(53) && (numerator - rounded).abs() < 1e-6 {
+            let numerator = rounded as u64;
+            return Ok((numerator / gcd(numerator, denominator)) as f64);
+        }
+    }
+
+    Err(format!(
+        "multipleOf {} has no integer multiple",
+        multiple_of
+    ))
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// The number of values and string characters in a value
+fn value_size(value: &Value) -> usize {
+    match value {
+        Value::String(string) => string.len().max(1),
+        Value::Array(items) => 1 + items.iter().map(value_size).sum::<usize>(),
+        Value::Object(object) => {
+            1 + object
+                .iter()
+                .map(|(key, value)| key.len() + value_size(value))
+                .sum::<usize>()
+        }
+        _ => 1,
+    }
+}
+
+fn get_usize(schema: &Map<String, Value>, keyword: &str) -> Option<usize> {
+    schema
+        .get(keyword)
+        .and_then(Value::as_u64)
+        .map(|value| value as usize)
+}
+
+/// Guesses the type of a schema without one from its keywords
+fn infer_type(schema: &Map<String, Value>) -> &'static str {
+    let has_any = |keywords: &[&str]| keywords.iter().any(|k| schema.contains_key(*k));
+
+    if has_any(&["properties", "required", "additionalProperties"]) {
+        "object"
+    } else if has_any(&[
+        "items",
+        "prefixItems",
+        "minItems",
+        "maxItems",
+        "uniqueItems",
+    ]) {
+        "array"
+    } else if has_any(&["minimum", "maximum", "exclusiveMinimum", "exclusiveMaximum"]) {
+        "number"
+    } else if has_any(&["multipleOf"]) {
+        "integer"
+    } else {
+        "string"
+    }
+}
+
+/// Merges the keywords of a subschema into a schema, combining properties and required
+/// properties and keeping the schema's own value of other keywords
+fn merge(schema: &mut Map<String, Value>, subschema: Map<String, Value>) {
+    for (keyword, value) in subschema {
+        match (schema.get_mut(&keyword), value) {
+            (Some(Value::Object(properties)), Value::Object(other)) if keyword == "properties" => {
+                for (name, property) in other {
+                    properties.entry(name).or_insert(property);
+                }
+            }
+            (Some(Value::Array(required)), Value::Array(other)) if keyword == "required" => {
+                required.extend(other);
+            }
+            (Some(_), _) => {}
+            (None, value) => {
+                schema.insert(keyword, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    fn generate(schema: Value, count: usize) -> Vec<Value> {
+        generate_mock_json(count, &schema.to_string(), MockJsonSource::Schema, Some(7)).unwrap()
+    }
+
+    #[test]
+    fn test_generates_documents_conforming_to_schema() {
+        let schema = json!({
+            "type": "object",
+            "required": [
+                "id", "email", "age", "level", "ratio", "status", "tags", "price", "code", "created"
+            ],
+            "properties": {
+                "id": { "type": "string", "format": "uuid" },
+                "email": { "type": "string", "format": "email" },
+                "age": { "type": "integer", "minimum": 18, "exclusiveMaximum": 65 },
+                "level": { "type": "integer", "minimum": 5, "exclusiveMinimum": 3, "maximum": 6 },
+                "ratio": {
+                    "type": "number",
+                    "exclusiveMinimum": 0,
+                    "minimum": 0,
+                    "maximum": 1,
+                    "exclusiveMaximum": 2
+                },
+                "status": { "enum": ["active", "inactive"] },
+                "tags": {
+                    "type": "array",
+                    "items": { "type": "string", "maxLength": 10 },
+                    "minItems": 2,
+                    "maxItems": 4,
+                    "uniqueItems": true
+                },
+                "price": { "type": "number", "minimum": 0, "maximum": 5, "multipleOf": 0.5 },
+                "code": { "type": "string", "pattern": "^[A-Z]{2}\\d{3}$" },
+                "created": { "type": "string", "format": "date-time" },
+                "nickname": { "type": "string" }
+            }
+        });
+
+        for document in generate(schema, 50) {
+            let id = document["id"].as_str().unwrap();
+            assert_eq!(Uuid::parse_str(id).unwrap().get_version_num(), 4);
+            assert!(document["email"].as_str().unwrap().contains('@'));
+            assert!((18..65).contains(&document["age"].as_i64().unwrap()));
+            assert!((5..=6).contains(&document["level"].as_i64().unwrap()));
+            let ratio = document["ratio"].as_f64().unwrap();
+            assert!(ratio > 0.0 && ratio <= 1.0);
+            assert!(["active", "inactive"].contains(&document["status"].as_str().unwrap()));
+
+            let tags = document["tags"].as_array().unwrap();
+            assert!((2..=4).contains(&tags.len()));
+            assert!(tags
+                .iter()
+                .all(|tag| tag.as_str().unwrap().chars().count() <= 10));
+            assert!(tags
+                .iter()
+                .enumerate()
+                .all(|(index, tag)| !tags[..index].contains(tag)));
+
+            let price = document["price"].as_f64().unwrap();
+            assert!((0.0..=5.0).contains(&price) && (price * 2.0).fract() == 0.0);
+            assert_eq!(document["code"].as_str().unwrap().len(), 5);
+            assert!(DateTime::parse_from_rfc3339(document["created"].as_str().unwrap()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_includes_optional_properties_at_random() {
+        let schema = json!({ "properties": { "a": { "type": "boolean" } } });
+
+        let documents = generate(schema, 50);
+
+        assert!(documents.iter().any(|document| document.get("a").is_some()));
+        assert!(documents.iter().any(|document| document.get("a").is_none()));
+    }
+
+    #[test]
+    fn test_follows_references_and_combinators() {
+        let schema = json!({
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "required": ["value"],
+                    "properties": {
+                        "value": { "const": 1 },
+                        "children": { "type": "array", "items": { "$ref": "#/$defs/node" } }
+                    }
+                }
+            },
+            "allOf": [
+                { "$ref": "#/$defs/node" },
+                { "required": ["kind"], "properties": { "kind": { "oneOf": [{ "type": "null" }, { "const": "leaf" }] } } }
+            ]
+        });
+
+        for document in generate(schema, 20) {
+            assert_eq!(document["value"], 1);
+            assert!(document["kind"].is_null() || document["kind"] == "leaf");
+        }
+    }
+
+    #[test]
+    fn test_generates_documents_shaped_like_sample() {
+        let sample =
+            json!({ "name": "Jane", "id": "c232ab00-9414-11ec-b3c8-9f6bdeced846", "age": 30 });
+
+        let documents =
+            generate_mock_json(3, &sample.to_string(), MockJsonSource::Sample, None).unwrap();
+
+        for document in documents {
+            assert!(document["name"].is_string());
+            assert!(Uuid::parse_str(document["id"].as_str().unwrap()).is_ok());
+            assert!(document["age"].is_i64());
+        }
+    }
+
+    #[test]
+    fn test_same_seed_generates_same_documents() {
+        let schema = json!({ "type": "array", "items": { "type": "string" } });
+
+        assert_eq!(generate(schema.clone(), 5), generate(schema, 5));
+    }
+
+    #[test]
+    fn test_generates_values_within_extreme_bounds() {
+        let schema = json!({ "type": "number", "minimum": -1.7e308, "maximum": 1.7e308 });
+        for document in generate(schema, 20) {
+            assert!(document.as_f64().unwrap().abs() <= 1.7e308);
+        }
+
+        let schema = json!({ "type": "integer", "minimum": 2.5, "maximum": 3, "multipleOf": 0.5 });
+        for document in generate(schema, 20) {
+            assert_eq!(document, 3);
+        }
+
+        let schema = json!({ "type": "integer", "minimum": 0, "maximum": 100, "multipleOf": 2.5 });
+        for document in generate(schema, 20) {
+            assert_eq!(document.as_i64().unwrap() % 5, 0);
+        }
+    }
+
+    #[test]
+    fn test_generates_patterns_within_length() {
+        let schema =
+            json!({ "type": "string", "pattern": "^[a-z]+$", "minLength": 12, "maxLength": 15 });
+
+        for document in generate(schema, 20) {
+            let string = document.as_str().unwrap();
+            assert!((12..=15).contains(&string.len()));
+            assert!(string.chars().all(|c| c.is_ascii_lowercase()));
+        }
+    }
+
+    #[test]
+    fn test_given_invalid_input_returns_error() {
+        assert!(generate_mock_json(1, "{", MockJsonSource::Schema, None)
+            .unwrap_err()
+            .starts_with("Failed to parse JSON"));
+
+        for schema in [
+            json!({ "type": "integer", "minimum": 5, "maximum": 4 }),
+            json!({ "$ref": "https://example.com/schema.json" }),
+            json!({ "enum": [] }),
+            json!({ "type": "array", "items": { "enum": [1] }, "minItems": 2, "uniqueItems": true }),
+            json!({ "type": "number", "exclusiveMinimum": 1, "exclusiveMaximum": 1 }),
+            json!({ "type": "integer", "multipleOf": 2.5, "minimum": 1, "maximum": 4 }),
+            json!({ "type": "integer", "multipleOf": 1, "minimum": 1e300 }),
+            json!({ "type": "string", "pattern": "a{18446744073709551615,}" }),
+            json!({ "type": "string", "pattern": "^[A-Z]{3}$", "maxLength": 2 }),
+            json!({ "type": "string", "minLength": 18446744073709551615u64 }),
+            json!({ "type": "string", "minLength": 5, "maxLength": 4 }),
+            json!({ "type": "array", "minItems": 18446744073709551615u64 }),
+            json!({
+                "type": "array", "minItems": 1000,
+                "items": { "type": "array", "minItems": 1000, "items": { "type": "array", "minItems": 1000 } }
+            }),
+        ] {
+            assert!(
+                generate_mock_json(1, &schema.to_string(), MockJsonSource::Schema, None).is_err(),
+                "{}",
+                schema
+            );
+        }
+    }
+}
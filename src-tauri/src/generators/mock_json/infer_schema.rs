@@ -0,0 +1,95 @@
+use chrono::{DateTime, NaiveDate};
+use serde_json::{json, Map, Value};
+use uuid::Uuid;
+
+/// Infers a JSON Schema from a sample document.
+///
+/// Every property of a sample object is required and arrays take the schema of their first
+/// element. Strings that look like UUIDs, emails, dates, date times or URLs get the
+/// matching format so mock values look like the sample.
+pub fn infer_schema(sample: &Value) -> Value {
+    match sample {
+        Value::Null => json!({ "type": "null" }),
+        Value::Bool(_) => json!({ "type": "boolean" }),
+        Value::Number(number) if number.is_f64() => json!({ "type": "number" }),
+        Value::Number(_) => json!({ "type": "integer" }),
+        Value::String(text) => match string_format(text) {
+            Some(format) => json!({ "type": "string", "format": format }),
+            None => json!({ "type": "string" }),
+        },
+        Value::Array(items) => {
+            let count = items.len();
+            match items.first() {
+                Some(item) => json!({
+                    "type": "array",
+                    "items": infer_schema(item),
+                    "minItems": count.min(1),
+                    "maxItems": count.max(1),
+                }),
+                None => json!({ "type": "array", "maxItems": 0 }),
+            }
+        }
+        Value::Object(object) => {
+            let properties = object
+                .iter()
+                .map(|(key, value)| (key.clone(), infer_schema(value)))
+                .collect::<Map<String, Value>>();
+
+            json!({
+                "type": "object",
+                "properties": properties,
+                "required": object.keys().collect::<Vec<&String>>(),
+            })
+        }
+    }
+}
+
+fn string_format(text: &str) -> Option<&'static str> {
+    if Uuid::try_parse(text).is_ok() {
+        Some("uuid")
+    } else if DateTime::parse_from_rfc3339(text).is_ok() {
+        Some("date-time")
+    } else if NaiveDate::parse_from_str(text, "%Y-%m-%d").is_ok() {
+        Some("date")
+    } else if text.starts_with("http://") || text.starts_with("https://") {
+        Some("uri")
+    } else if text.split_once('@').is_some_and(|(user, domain)| {
+        !user.is_empty() && domain.contains('.') && !text.contains(char::is_whitespace)
+    }) {
+        Some("email")
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infers_schema_from_sample() {
+        let sample = json!({
+            "id": "c232ab00-9414-11ec-b3c8-9f6bdeced846",
+            "email": "jane@example.com",
+            "created": "2024-01-02T03:04:05Z",
+            "age": 42,
+            "score": 0.5,
+            "tags": ["a", "b"],
+            "active": true,
+        });
+
+        let schema = infer_schema(&sample);
+
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["required"].as_array().unwrap().len(), 7);
+        let properties = &schema["properties"];
+        assert_eq!(properties["id"]["format"], "uuid");
+        assert_eq!(properties["email"]["format"], "email");
+        assert_eq!(properties["created"]["format"], "date-time");
+        assert_eq!(properties["age"]["type"], "integer");
+        assert_eq!(properties["score"]["type"], "number");
+        assert_eq!(properties["tags"]["items"]["type"], "string");
+        assert_eq!(properties["tags"]["maxItems"], 2);
+        assert_eq!(properties["active"]["type"], "boolean");
+    }
+}
@@ -0,0 +1,7 @@
+pub mod generate_mock_json;
+pub mod infer_schema;
+pub mod pattern;
+
+pub use generate_mock_json::*;
+pub use infer_schema::*;
+pub use pattern::*;
@@ -0,0 +1,462 @@
+use rand::prelude::IndexedRandom;
+use rand::Rng;
+use std::ops::RangeInclusive;
+
+/// The most repetitions generated for the unbounded `*`, `+` and `{n,}` quantifiers, beyond
+/// those needed to reach the shortest length
+const MAX_EXTRA_REPETITIONS: usize = 8;
+/// The most repetitions a `{n,m}` quantifier may ask for
+const MAX_REPETITIONS: usize = 1000;
+/// The number of strings generated looking for one with a length in range before giving up
+const LENGTH_ATTEMPTS: usize = 100;
+
+const DIGITS: &[(char, char)] = &[('0', '9')];
+const WORD: &[(char, char)] = &[('a', 'z'), ('A', 'Z'), ('0', '9'), ('_', '_')];
+const SPACE: &[(char, char)] = &[(' ', ' ')];
+const ANY: &[(char, char)] = &[('a', 'z'), ('A', 'Z'), ('0', '9')];
+
+#[derive(Debug)]
+enum Node {
+    Literal(char),
+    Class(Vec<(char, char)>),
+    Group(Vec<Vec<Node>>),
+    /// A repeated node with the fewest and most repetitions, `None` if unbounded
+    Repeat(Box<Node>, usize, Option<usize>),
+}
+
+/// The fewest and most characters a node generates, `None` if there is no most
+type LengthRange = (usize, Option<usize>);
+
+/// Generates a string matching a regular expression with a length in the given range.
+///
+/// Supports the subset of regular expressions commonly used in JSON Schema patterns:
+/// literals, `.`, character classes with ranges, `\d`, `\w` and `\s`, groups with
+/// alternatives and the `?`, `*`, `+` and `{n,m}` quantifiers. Anchors are ignored.
+pub fn generate_from_pattern(
+    pattern: &str,
+    length: RangeInclusive<usize>,
+    rng: &mut impl Rng,
+) -> Result<String, String> {
+    let mut parser = Parser {
+        chars: pattern.chars().collect(),
+        position: 0,
+    };
+
+    let alternatives = parser.parse_alternatives()?;
+    if parser.position < parser.chars.len() {
+        return Err(parser.error("Unmatched ')'"));
+    }
+
+    let root = Node::Group(alternatives);
+    if fits(root.length_range(), &length) {
+        // Lengths are aimed for while generating, but alternatives or repetitions of
+        // different lengths can still miss the range
+        for _ in 0..LENGTH_ATTEMPTS {
+            let mut generated = String::new();
+            if length.contains(&generate(&root, &length, rng, &mut generated)) {
+                return Ok(generated);
+            }
+        }
+    }
+
+    Err(format!(
+        "No string matching {} with between {} and {} characters could be generated",
+        pattern,
+        length.start(),
+        length.end()
+    ))
+}
+
+impl Node {
+    fn length_range(&self) -> LengthRange {
+        match self {
+            Node::Literal(_) | Node::Class(_) => (1, Some(1)),
+            Node::Group(alternatives) => alternatives
+                .iter()
+                .map(|sequence| sequence_length_range(sequence.iter()))
+                .fold((usize::MAX, Some(0)), |(shortest, longest), (min, max)| {
+                    (shortest.min(min), longest.zip(max).map(|(a, b)| a.max(b)))
+                }),
+            Node::Repeat(node, min, max) => {
+                let (shortest, longest) = node.length_range();
+                let longest = match (longest, max) {
+                    (Some(0), _) => Some(0),
+                    (Some(longest), Some(max)) => longest.checked_mul(*max),
+                    _ => None,
+                };
+                (shortest.saturating_mul(*min), longest)
+            }
+        }
+    }
+}
+
+fn sequence_length_range<'a>(nodes: impl Iterator<Item = &'a Node>) -> LengthRange {
+    nodes.fold((0, Some(0)), |(shortest, longest), node| {
+        let (min, max) = node.length_range();
+        (
+            shortest.saturating_add(min),
+            longest.zip(max).and_then(|(a, b)| a.checked_add(b)),
+        )
+    })
+}
+
+fn fits((shortest, longest): LengthRange, length: &RangeInclusive<usize>) -> bool {
+    shortest <= *length.end() && longest.is_none_or(|longest| longest >= *length.start())
+}
+
+/// Generates a string matching the node, aiming for a length in range
+///
+/// # Returns
+/// * `usize` - The number of characters generated
+fn generate(
+    node: &Node,
+    length: &RangeInclusive<usize>,
+    rng: &mut impl Rng,
+    generated: &mut String,
+) -> usize {
+    match node {
+        Node::Literal(c) => {
+            generated.push(*c);
+            1
+        }
+        Node::Class(ranges) => {
+            let c = ranges.choose(rng).and_then(|(start, end)| {
+                char::from_u32(rng.random_range(*start as u32..=*end as u32))
+            });
+            generated.extend(c);
+            usize::from(c.is_some())
+        }
+        Node::Group(alternatives) => {
+            let fitting = alternatives
+                .iter()
+                .filter(|sequence| fits(sequence_length_range(sequence.iter()), length))
+                .collect::<Vec<_>>();
+            match fitting
+                .choose(rng)
+                .copied()
+                .or_else(|| alternatives.choose(rng))
+            {
+                Some(sequence) => {
+                    generate_sequence(&sequence.iter().collect::<Vec<_>>(), length, rng, generated)
+                }
+                None => 0,
+            }
+        }
+        Node::Repeat(node, min, max) => {
+            let (shortest, longest) = node.length_range();
+            // The fewest repetitions reaching the shortest length and the most within the
+            // longest
+            let fewest = match longest {
+                Some(longest) if longest > 0 => (*min).max(length.start().div_ceil(longest)),
+                _ => *min,
+            };
+            let most = length.end().checked_div(shortest).unwrap_or(usize::MAX);
+            let most = match max {
+                Some(max) => (*max).min(most),
+                None => most.min(fewest.saturating_add(MAX_EXTRA_REPETITIONS)),
+            };
+            let count = if fewest <= most {
+                rng.random_range(fewest..=most)
+            } else {
+                (*min).max(most)
+            };
+
+            generate_sequence(&vec![node.as_ref(); count], length, rng, generated)
+        }
+    }
+}
+
+/// Generates a string matching each node in turn, keeping room for the nodes after each one
+fn generate_sequence(
+    nodes: &[&Node],
+    length: &RangeInclusive<usize>,
+    rng: &mut impl Rng,
+    generated: &mut String,
+) -> usize {
+    let mut rest = vec![(0, Some(0)); nodes.len() + 1];
+    for (i, node) in nodes.iter().enumerate().rev() {
+        rest[i] = sequence_length_range([*node].into_iter());
+        rest[i].0 = rest[i].0.saturating_add(rest[i + 1].0);
+        rest[i].1 = rest[i]
+            .1
+            .zip(rest[i + 1].1)
+            .and_then(|(a, b)| a.checked_add(b));
+    }
+
+    let mut generated_length = 0;
+    for (node, (rest_shortest, rest_longest)) in nodes.iter().zip(&rest[1..]) {
+        let shortest = length
+            .start()
+            .saturating_sub(generated_length)
+            .saturating_sub(rest_longest.unwrap_or(usize::MAX));
+        let longest = length
+            .end()
+            .saturating_sub(generated_length)
+            .saturating_sub(*rest_shortest);
+        generated_length += generate(node, &(shortest..=longest), rng, generated);
+    }
+
+    generated_length
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Parser {
+    fn error(&self, message: &str) -> String {
+        format!(
+            "Unsupported pattern: {} at position {}",
+            message, self.position
+        )
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.position += 1;
+        c
+    }
+
+    fn parse_alternatives(&mut self) -> Result<Vec<Vec<Node>>, String> {
+        let mut alternatives = vec![self.parse_sequence()?];
+
+        while self.peek() == Some('|') {
+            self.position += 1;
+            alternatives.push(self.parse_sequence()?);
+        }
+
+        Ok(alternatives)
+    }
+
+    fn parse_sequence(&mut self) -> Result<Vec<Node>, String> {
+        let mut sequence = Vec::new();
+
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            if c == '^' || c == '$' {
+                self.position += 1;
+                continue;
+            }
+
+            let atom = self.parse_atom()?;
+            sequence.push(self.parse_quantifier(atom)?);
+        }
+
+        Ok(sequence)
+    }
+
+    fn parse_atom(&mut self) -> Result<Node, String> {
+        match self.next() {
+            Some('(') => {
+                if self.chars[self.position..].starts_with(&['?', ':']) {
+                    self.position += 2;
+                } else if self.peek() == Some('?') {
+                    return Err(self.error("lookarounds are not supported"));
+                }
+                let alternatives = self.parse_alternatives()?;
+                if self.next() != Some(')') {
+                    return Err(self.error("Missing ')'"));
+                }
+                Ok(Node::Group(alternatives))
+            }
+            Some('[') => self.parse_class(),
+            Some('.') => Ok(Node::Class(ANY.to_vec())),
+            Some('\\') => self.parse_escape().map(Node::Class),
+            Some('?' | '*' | '+' | '{') => Err(self.error("Nothing to repeat")),
+            Some(c) => Ok(Node::Literal(c)),
+            None => Err(self.error("Unexpected end")),
+        }
+    }
+
+    fn parse_escape(&mut self) -> Result<Vec<(char, char)>, String> {
+        match self.next() {
+            Some('d') => Ok(DIGITS.to_vec()),
+            Some('w') => Ok(WORD.to_vec()),
+            Some('s') => Ok(SPACE.to_vec()),
+            Some('n') => Ok(vec![('\n', '\n')]),
+            Some('t') => Ok(vec![('\t', '\t')]),
+            Some(c) if c.is_ascii_alphanumeric() => {
+                Err(self.error(&format!("'\\{}' is not supported", c)))
+            }
+            Some(c) => Ok(vec![(c, c)]),
+            None => Err(self.error("Unexpected end")),
+        }
+    }
+
+    fn parse_class(&mut self) -> Result<Node, String> {
+        if self.peek() == Some('^') {
+            return Err(self.error("negated character classes are not supported"));
+        }
+
+        let mut ranges = Vec::new();
+        loop {
+            let start = match self.next() {
+                Some(']') if !ranges.is_empty() => return Ok(Node::Class(ranges)),
+                Some('\\') => {
+                    ranges.extend(self.parse_escape()?);
+                    continue;
+                }
+                Some(c) => c,
+                None => return Err(self.error("Missing ']'")),
+            };
+
+            let is_range = self.peek() == Some('-')
+                && self.chars.get(self.position + 1).is_some_and(|c| *c != ']');
+            if is_range {
+                self.position += 1;
+                let end = self.next().unwrap_or(start);
+                if end < start {
+                    return Err(self.error("Invalid character range"));
+                }
+                ranges.push((start, end));
+            } else {
+                ranges.push((start, start));
+            }
+        }
+    }
+
+    fn parse_quantifier(&mut self, atom: Node) -> Result<Node, String> {
+        let (min, max) = match self.peek() {
+            Some('?') => (0, Some(1)),
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('{') => {
+                let end = self.chars[self.position..]
+                    .iter()
+                    .position(|c| *c == '}')
+                    .ok_or_else(|| self.error("Missing '}'"))?;
+                let bounds = self.chars[self.position + 1..self.position + end]
+                    .iter()
+                    .collect::<String>();
+                let parse = |bound: &str| match bound.trim().parse::<usize>() {
+                    Ok(bound) if bound <= MAX_REPETITIONS => Ok(bound),
+                    Ok(_) => Err(self.error(&format!(
+                        "quantifiers may repeat at most {} times",
+                        MAX_REPETITIONS
+                    ))),
+                    Err(_) => Err(self.error("Invalid quantifier")),
+                };
+
+                let (min, max) = match bounds.split_once(',') {
+                    None => (parse(&bounds)?, Some(parse(&bounds)?)),
+                    Some((min, "")) => (parse(min)?, None),
+                    Some((min, max)) => (parse(min)?, Some(parse(max)?)),
+                };
+                if max.is_some_and(|max| min > max) {
+                    return Err(self.error("Invalid quantifier"));
+                }
+
+                self.position += end;
+                (min, max)
+            }
+            _ => return Ok(atom),
+        };
+        self.position += 1;
+
+        // Lazy quantifiers generate the same strings
+        if self.peek() == Some('?') {
+            self.position += 1;
+        }
+
+        Ok(Node::Repeat(Box::new(atom), min, max))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::seeded_rng;
+
+    fn matches_shape(pattern: &str, check: impl Fn(&str) -> bool) {
+        let mut rng = seeded_rng(Some(1));
+        for _ in 0..50 {
+            let generated = generate_from_pattern(pattern, 0..=100, &mut rng).unwrap();
+            assert!(check(&generated), "{} generated {:?}", pattern, generated);
+        }
+    }
+
+    #[test]
+    fn test_generates_strings_matching_patterns() {
+        matches_shape("^[A-Z]{3}-\\d{4}$", |s| {
+            s.len() == 8
+                && s[..3].chars().all(|c| c.is_ascii_uppercase())
+                && &s[3..4] == "-"
+                && s[4..].chars().all(|c| c.is_ascii_digit())
+        });
+        matches_shape("^(foo|bar)+baz?$", |s| {
+            let s = s
+                .strip_suffix("baz")
+                .or_else(|| s.strip_suffix("ba"))
+                .unwrap();
+            !s.is_empty() && s.len() % 3 == 0 && s.len() <= 27
+        });
+        matches_shape("[a-c_.]{2,5}", |s| {
+            (2..=5).contains(&s.len()) && s.chars().all(|c| "abc_.".contains(c))
+        });
+        matches_shape("\\w+@example\\.com", |s| s.ends_with("@example.com"));
+    }
+
+    #[test]
+    fn test_given_unsupported_pattern_returns_error() {
+        let mut rng = seeded_rng(None);
+
+        for pattern in [
+            "[^a]",
+            "(?=a)",
+            "*a",
+            "a{3,1}",
+            "(a",
+            "a)",
+            "\\p{L}",
+            "a{1001}",
+            "a{18446744073709551615,}",
+        ] {
+            assert!(
+                generate_from_pattern(pattern, 0..=100, &mut rng)
+                    .unwrap_err()
+                    .starts_with("Unsupported pattern"),
+                "{}",
+                pattern
+            );
+        }
+    }
+
+    #[test]
+    fn test_generates_strings_with_length_in_range() {
+        let mut rng = seeded_rng(Some(1));
+
+        for (pattern, length) in [
+            ("^[a-z]+$", 20..=20),
+            ("[a-z]+[0-9]*", 30..=40),
+            ("(ab)+", 10..=12),
+            ("(a|bcd)x{0,3}", 5..=5),
+            ("\\d{3}-?\\d{4}", 0..=7),
+        ] {
+            for _ in 0..20 {
+                let generated = generate_from_pattern(pattern, length.clone(), &mut rng).unwrap();
+                assert!(
+                    length.contains(&generated.chars().count()),
+                    "{} generated {:?}",
+                    pattern,
+                    generated
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_given_length_out_of_reach_returns_error() {
+        let mut rng = seeded_rng(None);
+
+        assert!(generate_from_pattern("[A-Z]{3}", 4..=10, &mut rng).is_err());
+        assert!(generate_from_pattern("(ab)+", 3..=3, &mut rng).is_err());
+        assert!(generate_from_pattern("(a{1000}){1000}", 0..=10_000, &mut rng).is_err());
+    }
+}
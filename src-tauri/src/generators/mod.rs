@@ -1,5 +1,26 @@
+pub mod fake_locale;
+pub mod generate_fake_data;
+pub mod generate_hash;
 pub mod generate_lipsum;
+pub mod generate_secret;
 pub mod generate_uuid;
+pub mod ids;
+pub mod inspect_uuid;
+pub mod lipsum_corpus;
+pub mod lipsum_format;
+pub mod mock_json;
+pub mod passphrase_words;
+pub mod password_strength;
+pub mod seeded_rng;
 
+pub use fake_locale::*;
+pub use generate_fake_data::*;
+pub use generate_hash::*;
 pub use generate_lipsum::*;
+pub use generate_secret::*;
 pub use generate_uuid::*;
+pub use inspect_uuid::*;
+pub use lipsum_corpus::*;
+pub use lipsum_format::*;
+pub use passphrase_words::*;
+pub use seeded_rng::*;
@@ -0,0 +1,127 @@
+/// The words passphrases are made of. There are 6^4 words of at most six letters, so each
+/// word can be picked with four dice rolls and adds log2(1296) ≈ 10.3 bits of entropy.
+// cspell:disable
+#[rustfmt::skip]
+pub static PASSPHRASE_WORDS: [&str; 1296] = [
+    "able", "acid", "acorn", "acre", "act", "actor", "adapt", "adobe", "adult", "affix", "afoot",
+    "age", "agent", "agile", "aging", "ahead", "aid", "aim", "air", "aisle", "alarm", "album",
+    "alert", "algae", "alias", "alibi", "alien", "align", "alive", "alley", "allow", "alloy",
+    "aloft", "alone", "along", "aloud", "alpha", "altar", "alter", "amber", "amble", "amend",
+    "ample", "amuse", "angel", "angle", "ankle", "apple", "apron", "arbor", "arena", "argue",
+    "arise", "armor", "army", "aroma", "arrow", "art", "ashen", "aside", "ask", "aspen", "asset",
+    "atlas", "atom", "attic", "audio", "audit", "aunt", "avoid", "awake", "award", "aware", "axis",
+    "baby", "bacon", "badge", "bagel", "baker", "balmy", "banjo", "barge", "barn", "basil", "basin",
+    "batch", "bath", "baton", "bay", "beach", "beam", "bean", "bear", "beard", "beast", "bed",
+    "beech", "beef", "begin", "being", "belt", "bench", "berry", "bike", "birch", "bird", "bison",
+    "black", "blade", "blank", "blast", "blaze", "blend", "bless", "blimp", "blink", "bliss",
+    "block", "blond", "bloom", "blue", "bluff", "blunt", "blur", "blush", "board", "boast", "boat",
+    "body", "bolt", "bonus", "book", "boost", "boot", "booth", "boss", "bowl", "box", "brain",
+    "brake", "brand", "brass", "brave", "bread", "break", "brick", "bride", "brief", "bring",
+    "brink", "brisk", "broad", "broil", "brook", "broom", "brush", "buck", "buddy", "buggy",
+    "build", "bulb", "bulk", "bunch", "bunny", "burst", "bush", "cabin", "cable", "cacao", "cadet",
+    "cage", "cake", "calm", "camel", "cameo", "camp", "canal", "candy", "canoe", "canon", "cape",
+    "card", "cargo", "carol", "cart", "carve", "case", "cash", "cast", "catch", "cause", "cedar",
+    "chair", "chalk", "champ", "chant", "charm", "chart", "chase", "cheek", "cheer", "chef",
+    "chess", "chest", "chew", "chick", "chief", "child", "chili", "chime", "chip", "chirp", "choir",
+    "chord", "chore", "chunk", "cider", "cinch", "civic", "civil", "clad", "claim", "clamp", "clap",
+    "clash", "clasp", "class", "clay", "clean", "clear", "clerk", "click", "cliff", "climb",
+    "cling", "clip", "cloak", "clock", "close", "cloth", "cloud", "clove", "clown", "club", "clue",
+    "coach", "coast", "coat", "cobra", "cocoa", "code", "coil", "coin", "cola", "comet", "comic",
+    "coral", "cord", "core", "corn", "couch", "count", "cover", "cozy", "crab", "craft", "crane",
+    "crate", "crawl", "cream", "creek", "crest", "crisp", "crop", "cross", "crowd", "crown",
+    "crumb", "crust", "cube", "curl", "curry", "curve", "cycle", "daily", "dairy", "daisy", "dance",
+    "dandy", "dare", "dash", "data", "dawn", "deal", "debut", "decal", "decoy", "deed", "deer",
+    "delta", "denim", "depot", "depth", "desk", "dial", "diary", "dice", "diet", "dig", "digit",
+    "dime", "diner", "dingo", "dish", "disk", "ditch", "diver", "dizzy", "dock", "dodge", "dog",
+    "doll", "dome", "donor", "donut", "door", "dose", "dove", "down", "dozen", "draft", "drag",
+    "drama", "drape", "draw", "dream", "dress", "drift", "drill", "drink", "drive", "drone", "drum",
+    "dry", "duck", "duet", "dune", "dusk", "dust", "duty", "eager", "eagle", "early", "earth",
+    "easel", "east", "easy", "eat", "echo", "edge", "edit", "eel", "elbow", "elder", "elect", "elf",
+    "elk", "elm", "ember", "empty", "enjoy", "enter", "entry", "envoy", "epic", "equal", "equip",
+    "era", "erase", "essay", "etch", "even", "event", "exact", "exit", "extra", "fable", "face",
+    "fact", "fade", "fairy", "faith", "fall", "fancy", "farm", "fast", "fault", "fauna", "favor",
+    "feast", "fence", "fern", "ferry", "fetch", "fever", "fiber", "field", "fig", "film", "final",
+    "finch", "find", "fire", "firm", "first", "fish", "five", "fixer", "flag", "flair", "flake",
+    "flame", "flash", "flask", "fleet", "flint", "flip", "float", "flock", "flood", "floor",
+    "flora", "flour", "flow", "fluid", "flute", "foam", "focus", "fog", "foil", "folk", "font",
+    "food", "force", "forge", "fork", "form", "fort", "forty", "forum", "foyer", "frame", "fresh",
+    "frog", "frost", "fruit", "fudge", "fuel", "funny", "fur", "fuzzy", "gable", "gala", "gale",
+    "game", "gap", "gauge", "gaze", "gear", "gecko", "gem", "genie", "genre", "giant", "gift",
+    "girth", "glad", "glass", "glaze", "gleam", "glide", "globe", "glory", "glove", "glow", "glue",
+    "gnome", "goal", "goat", "gold", "golf", "good", "goose", "gorge", "grab", "grace", "grade",
+    "grain", "grand", "grant", "grape", "graph", "grasp", "grass", "gravy", "great", "green",
+    "greet", "grid", "grill", "grin", "grip", "grit", "groom", "group", "grove", "growl", "grown",
+    "guard", "guess", "guest", "guide", "guild", "gulf", "gull", "gum", "gust", "habit", "hail",
+    "hair", "half", "hall", "halo", "hand", "happy", "hardy", "harp", "hatch", "haven", "hawk",
+    "hazel", "head", "heap", "heart", "heat", "hedge", "heel", "hello", "helm", "help", "hen",
+    "herb", "herd", "hero", "heron", "hike", "hill", "hinge", "hint", "hippo", "hobby", "hold",
+    "holly", "home", "honey", "hood", "hook", "hope", "horn", "horse", "host", "hotel", "hound",
+    "hour", "house", "hub", "hug", "human", "humor", "hunch", "hurry", "husky", "hut", "hymn",
+    "ice", "icing", "icon", "idea", "idle", "igloo", "image", "imply", "inch", "index", "ink",
+    "inlet", "input", "iris", "iron", "item", "ivory", "ivy", "jade", "jam", "jar", "jazz", "jeans",
+    "jelly", "jet", "jewel", "jiffy", "jog", "join", "joke", "jolly", "joy", "judge", "juice",
+    "jumbo", "jump", "jury", "just", "kale", "kayak", "keen", "kelp", "kept", "key", "kick", "kid",
+    "kind", "king", "kiosk", "kite", "kiwi", "knack", "knee", "knife", "knit", "knob", "knot",
+    "koala", "label", "lace", "lady", "lake", "lamb", "lamp", "land", "lane", "lanky", "laser",
+    "latch", "late", "lava", "lawn", "layer", "lead", "leaf", "lean", "leap", "learn", "lease",
+    "ledge", "legal", "lemon", "lens", "level", "lever", "light", "lilac", "lily", "limb", "lime",
+    "limit", "linen", "lion", "lipid", "list", "liter", "live", "llama", "load", "loaf", "lobby",
+    "local", "lock", "lodge", "loft", "logic", "long", "loop", "lotus", "loud", "loyal", "lucky",
+    "lumen", "lunar", "lunch", "lyric", "macro", "magic", "maid", "major", "mango", "manor",
+    "maple", "march", "mark", "mars", "marsh", "mask", "mason", "match", "mayor", "maze", "meal",
+    "medal", "media", "melon", "melt", "memo", "menu", "merit", "merry", "mesa", "metal", "meter",
+    "metro", "mild", "mile", "milk", "mill", "mimic", "mind", "minor", "mint", "minus", "mist",
+    "mixer", "moat", "mocha", "model", "modem", "mold", "mole", "monk", "month", "moon", "moose",
+    "moss", "motel", "motor", "motto", "mound", "mount", "mouse", "mouth", "movie", "mule", "mural",
+    "muse", "music", "myth", "nacho", "nail", "name", "navy", "near", "neat", "neon", "nerve",
+    "nest", "net", "never", "new", "next", "night", "ninja", "noble", "nod", "noise", "north",
+    "nose", "notch", "note", "novel", "nudge", "nurse", "nut", "nylon", "oak", "oasis", "oat",
+    "ocean", "odd", "offer", "often", "oil", "okay", "olive", "omega", "onion", "open", "opera",
+    "orbit", "order", "organ", "otter", "ounce", "outer", "oval", "oven", "owl", "owner", "oxide",
+    "pace", "pack", "page", "pager", "paint", "palm", "panda", "panel", "paper", "park", "party",
+    "pasta", "patch", "path", "patio", "pause", "peach", "peak", "pearl", "pecan", "pedal", "penny",
+    "perch", "petal", "phone", "photo", "piano", "pick", "piece", "pier", "pig", "pilot", "pine",
+    "pink", "pint", "pipe", "pitch", "pixel", "pizza", "place", "plaid", "plain", "plan", "plank",
+    "plant", "plate", "plaza", "plot", "plow", "plum", "plume", "plus", "poem", "poet", "point",
+    "polar", "pole", "polka", "pond", "pony", "pool", "poppy", "porch", "port", "pose", "post",
+    "pouch", "power", "prank", "press", "price", "pride", "prism", "prize", "probe", "proof",
+    "prose", "proud", "prune", "pulse", "pump", "punch", "pupil", "puppy", "purse", "quack",
+    "quail", "quake", "query", "quest", "queue", "quick", "quiet", "quilt", "quirk", "quota",
+    "quote", "race", "radar", "radio", "raft", "rain", "rake", "rally", "ranch", "range", "rapid",
+    "raven", "ray", "razor", "reach", "ready", "realm", "rebel", "recap", "reef", "reel", "relay",
+    "relic", "remix", "rent", "reply", "rhyme", "rib", "rice", "rider", "ridge", "right", "ring",
+    "rinse", "ripen", "rise", "river", "road", "roast", "robin", "robot", "rock", "rodeo", "roof",
+    "room", "root", "rope", "rose", "rotor", "rough", "round", "route", "rover", "royal", "ruby",
+    "rug", "ruler", "rumba", "rune", "rural", "rust", "saga", "sage", "sail", "salad", "salon",
+    "salsa", "salt", "sand", "satin", "sauce", "sauna", "scale", "scarf", "scene", "scone", "scoop",
+    "scope", "score", "scout", "scrap", "screw", "seal", "seat", "seed", "sepia", "shade", "shaft",
+    "shake", "shape", "share", "shark", "sharp", "shawl", "sheep", "shelf", "shell", "shift",
+    "shine", "ship", "shirt", "shore", "short", "shrub", "sigma", "silk", "siren", "skate", "ski",
+    "skill", "skirt", "sky", "slate", "sled", "sleep", "slice", "slide", "slope", "sloth", "small",
+    "smile", "smoke", "snack", "snail", "snake", "snow", "soap", "sock", "sofa", "solar", "solid",
+    "solo", "sonar", "song", "sonic", "sort", "soup", "south", "space", "spade", "spark", "spear",
+    "spice", "spike", "spine", "spoon", "sport", "spray", "squid", "stack", "staff", "stage",
+    "stair", "stamp", "stand", "star", "start", "steam", "steel", "stem", "step", "stew", "stick",
+    "still", "sting", "stock", "stone", "stool", "storm", "story", "stove", "straw", "stump",
+    "style", "sugar", "suite", "summit", "sun", "super", "surf", "swamp", "swan", "sweet", "swift",
+    "swing", "sword", "syrup", "table", "tablet", "taco", "tail", "talent", "talon", "tango",
+    "tank", "tape", "target", "task", "taste", "tavern", "taxi", "tea", "teach", "team", "teapot",
+    "tempo", "tender", "tennis", "tent", "term", "test", "theme", "thick", "thorn", "thread",
+    "thumb", "ticket", "tide", "tiger", "tile", "timber", "time", "tiny", "titan", "toast", "today",
+    "token", "tomato", "tone", "tongs", "tool", "topaz", "torch", "total", "totem", "tour", "towel",
+    "tower", "town", "toy", "trace", "track", "trade", "trail", "train", "trait", "tram", "tray",
+    "treat", "tree", "trend", "trial", "tribe", "trick", "trio", "trout", "truck", "trunk", "trust",
+    "truth", "tube", "tulip", "tuna", "tune", "tunnel", "turkey", "turtle", "tutor", "tweed",
+    "twig", "twin", "type", "ultra", "umbra", "umpire", "uncle", "under", "union", "unit", "upper",
+    "urban", "usage", "usher", "utmost", "vacuum", "valid", "valley", "value", "valve", "vapor",
+    "vase", "vault", "vector", "velvet", "vendor", "venue", "verb", "verse", "vest", "vial",
+    "video", "view", "villa", "vine", "vinyl", "violet", "violin", "visit", "visor", "vital",
+    "vivid", "vocal", "voice", "volt", "voter", "vowel", "voyage", "wafer", "wage", "wagon",
+    "waist", "walk", "wall", "walnut", "walrus", "wand", "warm", "wasp", "watch", "water", "wave",
+    "wax", "weave", "wedge", "week", "weld", "whale", "wheat", "wheel", "whisk", "white", "wick",
+    "width", "wild", "willow", "wind", "window", "wing", "winter", "wire", "wise", "wish", "witty",
+    "wizard", "wolf", "wonder", "wood", "wool", "word", "work", "world", "worm", "wrap", "wren",
+    "wrist", "yacht", "yard", "yarn", "year", "yeast", "yellow", "yodel", "yoga", "yogurt", "young",
+    "youth", "zebra", "zero", "zest", "zigzag", "zinc", "zipper", "zone", "zoom",
+];
+// cspell:enable
@@ -0,0 +1,380 @@
+use crate::generators::password_strength::{
+    find_long_patterns, find_patterns, get_feedback, most_guessable, rank_user_inputs,
+    sequence_of_patterns_guesses, PasswordPattern, PatternKind, BRUTEFORCE_CARDINALITY,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The most characters matched against every pattern at once, as matching takes time
+/// quadratic in the length
+const MAX_PASSWORD_LENGTH: usize = 100;
+
+/// The fewest characters of a repeat or sequence that splits a longer password into parts
+/// analyzed on their own
+const MIN_SPLITTING_PATTERN_LENGTH: usize = 10;
+
+/// How an attacker guesses passwords, from a throttled login form to a fast hash leaked
+/// from a database
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum AttackScenario {
+    /// 100 guesses per hour against a rate-limited online service
+    OnlineThrottled,
+    /// 10 guesses per second against an online service without rate limiting
+    OnlineUnthrottled,
+    /// 10 thousand guesses per second against a slow hash like bcrypt or Argon2
+    OfflineSlowHash,
+    /// 10 billion guesses per second against a fast hash like SHA-256
+    OfflineFastHash,
+}
+
+impl AttackScenario {
+    pub const ALL: [AttackScenario; 4] = [
+        AttackScenario::OnlineThrottled,
+        AttackScenario::OnlineUnthrottled,
+        AttackScenario::OfflineSlowHash,
+        AttackScenario::OfflineFastHash,
+    ];
+
+    pub fn guesses_per_second(&self) -> f64 {
+        match self {
+            AttackScenario::OnlineThrottled => 100.0 / 3600.0,
+            AttackScenario::OnlineUnthrottled => 10.0,
+            AttackScenario::OfflineSlowHash => 1e4,
+            AttackScenario::OfflineFastHash => 1e10,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CrackTime {
+    pub scenario: AttackScenario,
+    pub seconds: f64,
+    /// The time in words, e.g. "3 hours" or "centuries"
+    pub display: String,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PasswordAnalysis {
+    /// The estimated number of guesses needed to guess the password
+    pub guesses: f64,
+    pub guesses_log10: f64,
+    /// The strength from 0 (too guessable) to 4 (very unguessable)
+    pub score: u8,
+    pub crack_times: Vec<CrackTime>,
+    /// The patterns the password is most easily guessed with, in order
+    pub patterns: Vec<PasswordPattern>,
+    pub warning: Option<String>,
+    pub suggestions: Vec<String>,
+}
+
+/// Estimates how hard a password is to guess, in the style of zxcvbn.
+///
+/// The password is matched against common passwords, English words, names and the given
+/// user inputs (also reversed and with look-alike substitutions like `p@ssw0rd`), keyboard
+/// patterns, sequences, repeats, dates and years. The guesses are those of the sequence of
+/// patterns that is the easiest to guess, from which the crack time in several attack
+/// scenarios and a score are derived. Weak passwords get a warning and suggestions.
+///
+/// Passwords longer than 100 characters are split by their long repeats and sequences, and
+/// only the first 100 characters of the parts in between are matched against patterns. The
+/// rest of those parts is guessed by bruteforce.
+///
+/// The analysis runs entirely offline, so the password never leaves the machine.
+///
+/// # Arguments
+/// * `password` - The password to analyze
+/// * `user_inputs` - Words an attacker may know, like the user's name, username or email
+///
+/// # Returns
+/// * `Ok(PasswordAnalysis)` - The guesses, score, crack times, patterns and feedback
+///
+/// ```
+#[tauri::command]
+pub fn analyze_password(
+    password: &str,
+    user_inputs: Option<Vec<String>>,
+) -> Result<PasswordAnalysis, String> {
+    let password = password.chars().collect::<Vec<char>>();
+    let user_inputs = rank_user_inputs(&user_inputs.unwrap_or_default());
+
+    let (guesses, sequence) = if password.len() <= MAX_PASSWORD_LENGTH {
+        most_guessable(&password, &find_patterns(&password, &user_inputs))
+    } else {
+        most_guessable_long(&password, &user_inputs)
+    };
+
+    let score = get_score(guesses);
+    let feedback = get_feedback(score, &sequence);
+    let crack_times = AttackScenario::ALL
+        .into_iter()
+        .map(|scenario| {
+            let seconds = guesses / scenario.guesses_per_second();
+            CrackTime {
+                scenario,
+                seconds,
+                display: display_time(seconds),
+            }
+        })
+        .collect();
+
+    Ok(PasswordAnalysis {
+        guesses,
+        guesses_log10: guesses.log10(),
+        score,
+        crack_times,
+        patterns: sequence,
+        warning: feedback.warning,
+        suggestions: feedback.suggestions,
+    })
+}
+
+/// Finds the sequence of patterns of a password longer than `MAX_PASSWORD_LENGTH`. Long
+/// repeats and sequences are found in the whole password, the parts between them are
+/// analyzed like shorter passwords
+fn most_guessable_long(
+    password: &[char],
+    user_inputs: &HashMap<String, usize>,
+) -> (f64, Vec<PasswordPattern>) {
+    let long_patterns = find_long_patterns(
+        password,
+        user_inputs,
+        MAX_PASSWORD_LENGTH,
+        MIN_SPLITTING_PATTERN_LENGTH,
+    );
+
+    let mut sequence = Vec::new();
+    let mut start = 0;
+    for pattern in long_patterns {
+        sequence.extend(analyze_part(password, start, pattern.start, user_inputs));
+        start = pattern.end;
+        sequence.push(pattern);
+    }
+    sequence.extend(analyze_part(password, start, password.len(), user_inputs));
+
+    // Very long passwords would need infinitely many guesses otherwise
+    let guesses = sequence_of_patterns_guesses(&sequence, password.len()).min(f64::MAX);
+
+    (guesses, sequence)
+}
+
+/// Finds the sequence of patterns of a part of a password, counting the characters after the
+/// first `MAX_PASSWORD_LENGTH` as bruteforce
+fn analyze_part(
+    password: &[char],
+    start: usize,
+    end: usize,
+    user_inputs: &HashMap<String, usize>,
+) -> Vec<PasswordPattern> {
+    if start == end {
+        return Vec::new();
+    }
+
+    let analyzed_end = end.min(start + MAX_PASSWORD_LENGTH);
+    let part = &password[start..analyzed_end];
+    let (_, sequence) = most_guessable(part, &find_patterns(part, user_inputs));
+
+    let mut patterns = sequence
+        .into_iter()
+        .map(|pattern| PasswordPattern {
+            start: pattern.start + start,
+            end: pattern.end + start,
+            ..pattern
+        })
+        .collect::<Vec<PasswordPattern>>();
+
+    if analyzed_end < end {
+        let rest = &password[analyzed_end..end];
+        patterns.push(PasswordPattern {
+            kind: PatternKind::Bruteforce,
+            token: rest.iter().collect(),
+            start: analyzed_end,
+            end,
+            guesses: BRUTEFORCE_CARDINALITY.powi(rest.len() as i32).min(f64::MAX),
+        });
+    }
+
+    patterns
+}
+
+fn get_score(guesses: f64) -> u8 {
+    // A little more than the threshold, so a password at the threshold has the lower score
+    const DELTA: f64 = 5.0;
+
+    [1e3, 1e6, 1e8, 1e10]
+        .iter()
+        .take_while(|threshold| guesses >= *threshold + DELTA)
+        .count() as u8
+}
+
+fn display_time(seconds: f64) -> String {
+    const MINUTE: f64 = 60.0;
+    const HOUR: f64 = MINUTE * 60.0;
+    const DAY: f64 = HOUR * 24.0;
+    const MONTH: f64 = DAY * 31.0;
+    const YEAR: f64 = MONTH * 12.0;
+    const CENTURY: f64 = YEAR * 100.0;
+
+    let units = [
+        (YEAR, "year"),
+        (MONTH, "month"),
+        (DAY, "day"),
+        (HOUR, "hour"),
+        (MINUTE, "minute"),
+        (1.0, "second"),
+    ];
+
+    if seconds < 1.0 {
+        return "less than a second".to_string();
+    }
+    if seconds >= CENTURY {
+        return "centuries".to_string();
+    }
+
+    let (unit_seconds, unit) = units
+        .iter()
+        .find(|(unit_seconds, _)| seconds >= *unit_seconds)
+        .unwrap_or(&units[units.len() - 1]);
+    let count = (seconds / unit_seconds).round();
+
+    if count == 1.0 {
+        format!("1 {}", unit)
+    } else {
+        format!("{} {}s", count, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generators::password_strength::PatternKind;
+
+    #[test]
+    fn test_common_passwords_are_weak() {
+        let analysis = analyze_password("password", None).unwrap();
+
+        assert_eq!(analysis.score, 0);
+        assert_eq!(analysis.guesses, 3.0);
+        assert_eq!(
+            analysis.warning.as_deref(),
+            Some("This is a top-10 common password")
+        );
+        assert_eq!(analysis.crack_times[3].display, "less than a second");
+    }
+
+    #[test]
+    fn test_patterns_are_weak() {
+        for password in [
+            "qwertyuiop",
+            "abcdefgh",
+            "aaaaaaaaaa",
+            "13/05/1987",
+            "P@ssw0rd",
+        ] {
+            let analysis = analyze_password(password, None).unwrap();
+
+            assert!(analysis.score <= 1, "{}", password);
+            assert!(analysis.warning.is_some(), "{}", password);
+        }
+    }
+
+    #[test]
+    fn test_random_passwords_are_strong() {
+        let analysis = analyze_password("xK#9vQ!mT2$wLp8z", None).unwrap();
+
+        assert_eq!(analysis.score, 4);
+        assert_eq!(analysis.crack_times[0].display, "centuries");
+        assert!(analysis.warning.is_none());
+        assert!(analysis.suggestions.is_empty());
+    }
+
+    #[test]
+    fn test_user_inputs_are_weak() {
+        let user_inputs = Some(vec!["Gandalf1954".to_string()]);
+
+        let analysis = analyze_password("gandalf1954", user_inputs).unwrap();
+
+        assert_eq!(analysis.score, 0);
+        assert!(matches!(
+            analysis.patterns[0].kind,
+            PatternKind::Dictionary { .. }
+        ));
+    }
+
+    #[test]
+    fn test_patterns_cover_password() {
+        let analysis = analyze_password("correcthorse7batterystaple", None).unwrap();
+
+        let tokens = analysis
+            .patterns
+            .iter()
+            .map(|pattern| pattern.token.as_str())
+            .collect::<String>();
+        assert_eq!(tokens, "correcthorse7batterystaple");
+    }
+
+    #[test]
+    fn test_common_words_are_weak() {
+        for password in [
+            "chocolate",
+            "bulldog",
+            "Summer2024!",
+            "sunflower",
+            "skywalker",
+        ] {
+            let analysis = analyze_password(password, None).unwrap();
+
+            assert!(analysis.score <= 1, "{}", password);
+        }
+    }
+
+    #[test]
+    fn test_long_passwords_are_analyzed() {
+        // Characters that are not part of any pattern
+        let password = (0..150)
+            .filter_map(|i| char::from_u32(0x4E00 + i * 7919 % 20000))
+            .collect::<String>();
+
+        let analysis = analyze_password(&password, None).unwrap();
+
+        let tokens = analysis
+            .patterns
+            .iter()
+            .map(|pattern| pattern.token.as_str())
+            .collect::<String>();
+        assert_eq!(tokens, password);
+        let last = analysis.patterns.last().unwrap();
+        assert_eq!(last.kind, PatternKind::Bruteforce);
+        assert_eq!((last.start, last.end), (100, 150));
+        assert_eq!(analysis.score, 4);
+    }
+
+    #[test]
+    fn test_long_repeats_are_weak() {
+        for password in [
+            "a".repeat(101),
+            "a".repeat(1000),
+            "password".repeat(20),
+            format!("{}{}", "qwerty".repeat(30), "abcdefghijklmnopqrstuvwxyz"),
+        ] {
+            let analysis = analyze_password(&password, None).unwrap();
+
+            let tokens = analysis
+                .patterns
+                .iter()
+                .map(|pattern| pattern.token.as_str())
+                .collect::<String>();
+            assert_eq!(tokens, password);
+            assert!(analysis.score <= 1, "{}", password);
+            assert!(analysis.warning.is_some(), "{}", password);
+        }
+    }
+
+    #[test]
+    fn test_displays_times() {
+        assert_eq!(display_time(0.5), "less than a second");
+        assert_eq!(display_time(1.0), "1 second");
+        assert_eq!(display_time(150.0), "3 minutes");
+        assert_eq!(display_time(86400.0 * 2.0), "2 days");
+        assert_eq!(display_time(1e12), "centuries");
+    }
+}
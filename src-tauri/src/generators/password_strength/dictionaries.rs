@@ -0,0 +1,93 @@
+use crate::generators::password_strength::reference_year;
+use crate::generators::{FakeLocale, PASSPHRASE_WORDS};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// The word lists passwords are matched against
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq)]
+pub enum Dictionary {
+    Passwords,
+    English,
+    Names,
+    /// Words the user gave, like their username or email
+    UserInputs,
+}
+
+/// Commonly used passwords, most common first, one per line
+const COMMON_PASSWORDS: &str = include_str!("common_passwords.txt");
+
+/// Common English words, most common first, one per line
+const ENGLISH_WORDS: &str = include_str!("english_words.txt");
+
+/// Seasons that seasonal passwords start with
+const SEASONS: [&str; 5] = ["spring", "summer", "autumn", "fall", "winter"];
+
+static BUILT_IN_DICTIONARIES: LazyLock<Vec<(Dictionary, HashMap<String, usize>)>> =
+    LazyLock::new(|| {
+        let locales = [
+            FakeLocale::EnUs,
+            FakeLocale::EnGb,
+            FakeLocale::DeDe,
+            FakeLocale::FrFr,
+            FakeLocale::EsEs,
+        ];
+        let first_names = locales.iter().flat_map(|locale| locale.data().first_names);
+        let last_names = locales.iter().flat_map(|locale| locale.data().last_names);
+
+        vec![
+            (
+                Dictionary::Passwords,
+                rank(
+                    COMMON_PASSWORDS
+                        .lines()
+                        .map(String::from)
+                        .chain(seasonal_passwords()),
+                ),
+            ),
+            // The passphrase words are less common than the common words
+            (
+                Dictionary::English,
+                rank(
+                    ENGLISH_WORDS
+                        .lines()
+                        .chain(PASSPHRASE_WORDS.iter().copied()),
+                ),
+            ),
+            (Dictionary::Names, rank(first_names.chain(last_names))),
+        ]
+    });
+
+/// Generates passwords like "summer2024" and "summer2024!" for recent years, which are
+/// common where passwords have to be changed every few months
+fn seasonal_passwords() -> impl Iterator<Item = String> {
+    let reference_year = reference_year();
+
+    (reference_year - 10..=reference_year + 1)
+        .rev()
+        .flat_map(|year| SEASONS.map(|season| format!("{}{}", season, year)))
+        .flat_map(|password| [password.clone(), format!("{}!", password)])
+}
+
+/// Ranks words by their position, starting at 1 and keeping the first rank of duplicates
+fn rank(words: impl IntoIterator<Item = impl AsRef<str>>) -> HashMap<String, usize> {
+    let mut ranks = HashMap::new();
+
+    for (index, word) in words.into_iter().enumerate() {
+        ranks
+            .entry(word.as_ref().to_lowercase())
+            .or_insert(index + 1);
+    }
+
+    ranks
+}
+
+/// Gets the built-in dictionaries, each mapping lowercase words to their rank
+pub fn built_in_dictionaries() -> &'static [(Dictionary, HashMap<String, usize>)] {
+    &BUILT_IN_DICTIONARIES
+}
+
+/// Ranks the words the user gave, like their username or email, in the order given
+pub fn rank_user_inputs(user_inputs: &[String]) -> HashMap<String, usize> {
+    rank(user_inputs)
+}
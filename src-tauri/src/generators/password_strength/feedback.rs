@@ -0,0 +1,190 @@
+use crate::generators::password_strength::{
+    reference_year, Dictionary, PasswordPattern, PatternKind,
+};
+
+/// The explanation of why a password is weak and how to improve it
+#[derive(Debug, PartialEq)]
+pub struct Feedback {
+    pub warning: Option<String>,
+    pub suggestions: Vec<String>,
+}
+
+/// Explains the weakest part of a password, the longest of the patterns it was guessed with.
+/// Strong passwords get no feedback.
+pub fn get_feedback(score: u8, sequence: &[PasswordPattern]) -> Feedback {
+    if sequence.is_empty() {
+        return Feedback {
+            warning: None,
+            suggestions: vec![
+                "Use a few words, avoid common phrases".to_string(),
+                "No need for symbols, digits, or uppercase letters".to_string(),
+            ],
+        };
+    }
+    if score > 2 {
+        return Feedback {
+            warning: None,
+            suggestions: Vec::new(),
+        };
+    }
+
+    let longest = sequence
+        .iter()
+        .max_by_key(|pattern| pattern.end - pattern.start)
+        .unwrap_or(&sequence[0]);
+    let (warning, mut suggestions) = get_pattern_feedback(longest, sequence.len() == 1);
+
+    suggestions.insert(0, "Add another word or two. Uncommon words are better.");
+
+    Feedback {
+        warning: warning.map(str::to_string),
+        suggestions: suggestions.into_iter().map(str::to_string).collect(),
+    }
+}
+
+fn get_pattern_feedback(
+    pattern: &PasswordPattern,
+    is_sole_pattern: bool,
+) -> (Option<&'static str>, Vec<&'static str>) {
+    match &pattern.kind {
+        PatternKind::Dictionary {
+            dictionary,
+            rank,
+            reversed,
+            l33t,
+            ..
+        } => {
+            let warning = match dictionary {
+                Dictionary::Passwords if is_sole_pattern && l33t.is_empty() && !reversed => {
+                    if *rank <= 10 {
+                        Some("This is a top-10 common password")
+                    } else if *rank <= 100 {
+                        Some("This is a top-100 common password")
+                    } else {
+                        Some("This is a very common password")
+                    }
+                }
+                Dictionary::Passwords if pattern.guesses.log10() <= 4.0 => {
+                    Some("This is similar to a commonly used password")
+                }
+                Dictionary::English if is_sole_pattern => Some("A word by itself is easy to guess"),
+                Dictionary::Names if is_sole_pattern => {
+                    Some("Names and surnames by themselves are easy to guess")
+                }
+                Dictionary::Names => Some("Common names and surnames are easy to guess"),
+                Dictionary::UserInputs => Some("Your own details are easy to guess"),
+                _ => None,
+            };
+
+            let mut suggestions = Vec::new();
+            let token = pattern.token.chars().collect::<Vec<char>>();
+            let upper = token.iter().filter(|c| c.is_uppercase()).count();
+            let lower = token.iter().filter(|c| c.is_lowercase()).count();
+            if upper > 0 && lower == 0 {
+                suggestions.push("All-uppercase is almost as easy to guess as all-lowercase");
+            } else if upper == 1 && token.first().is_some_and(|c| c.is_uppercase()) {
+                suggestions.push("Capitalization doesn't help very much");
+            }
+            if *reversed && token.len() >= 4 {
+                suggestions.push("Reversed words aren't much harder to guess");
+            }
+            if !l33t.is_empty() {
+                suggestions
+                    .push("Predictable substitutions like '@' instead of 'a' don't help very much");
+            }
+
+            (warning, suggestions)
+        }
+        PatternKind::Spatial { turns, .. } => {
+            let warning = if *turns == 1 {
+                "Straight rows of keys are easy to guess"
+            } else {
+                "Short keyboard patterns are easy to guess"
+            };
+            (
+                Some(warning),
+                vec!["Use a longer keyboard pattern with more turns"],
+            )
+        }
+        PatternKind::Repeat { base, .. } => {
+            let warning = if base.chars().count() == 1 {
+                "Repeats like \"aaa\" are easy to guess"
+            } else {
+                "Repeats like \"abcabcabc\" are only slightly harder to guess than \"abc\""
+            };
+            (Some(warning), vec!["Avoid repeated words and characters"])
+        }
+        PatternKind::Sequence { .. } => (
+            Some("Sequences like abc or 6543 are easy to guess"),
+            vec!["Avoid sequences"],
+        ),
+        PatternKind::Date { .. } => (
+            Some("Dates are often easy to guess"),
+            vec!["Avoid dates and years that are associated with you"],
+        ),
+        PatternKind::Year { year } if (year - reference_year()).abs() <= 20 => (
+            Some("Recent years are easy to guess"),
+            vec![
+                "Avoid recent years",
+                "Avoid years that are associated with you",
+            ],
+        ),
+        PatternKind::Year { .. } | PatternKind::Bruteforce => (None, Vec::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strong_passwords_get_no_feedback() {
+        let pattern = PasswordPattern {
+            kind: PatternKind::Bruteforce,
+            token: "x".to_string(),
+            start: 0,
+            end: 1,
+            guesses: 10.0,
+        };
+
+        assert_eq!(
+            get_feedback(4, &[pattern]),
+            Feedback {
+                warning: None,
+                suggestions: Vec::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_explains_longest_pattern() {
+        let pattern = |kind, token: &str, start, guesses| PasswordPattern {
+            kind,
+            token: token.to_string(),
+            start,
+            end: start + token.len(),
+            guesses,
+        };
+        let sequence = [
+            pattern(PatternKind::Sequence { ascending: true }, "abc", 0, 12.0),
+            pattern(
+                PatternKind::Spatial {
+                    keyboard: "qwerty".to_string(),
+                    turns: 1,
+                    shifted: 0,
+                },
+                "qwerty",
+                3,
+                1000.0,
+            ),
+        ];
+
+        let feedback = get_feedback(1, &sequence);
+
+        assert_eq!(
+            feedback.warning.as_deref(),
+            Some("Straight rows of keys are easy to guess")
+        );
+        assert_eq!(feedback.suggestions.len(), 2);
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+/// The neighbours of a key on a keyboard whose rows are offset by half a key
+const SLANTED: &[(i32, i32)] = &[(-1, 0), (0, -1), (1, -1), (1, 0), (0, 1), (-1, 1)];
+
+/// The neighbours of a key on a keypad whose keys are in a grid
+const ALIGNED: &[(i32, i32)] = &[
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+];
+
+/// The keys of every row, unshifted and shifted, with the offset of the row in keys
+const QWERTY: [(&str, i32); 4] = [
+    ("`~1!2@3#4$5%6^7&8*9(0)-_=+", 0),
+    ("qQwWeErRtTyYuUiIoOpP[{]}\\|", 1),
+    ("aAsSdDfFgGhHjJkKlL;:'\"", 1),
+    ("zZxXcCvVbBnNmM,<.>/?", 1),
+];
+
+/// The keys of every row, with spaces where there is no key
+const KEYPAD: [&str; 5] = [" /*-", "789+", "456", "123", "0 ."];
+
+/// The layout of a keyboard, used to find patterns of adjacent keys
+#[derive(Debug)]
+pub struct Keyboard {
+    pub name: &'static str,
+    /// The position of every character and whether it is typed with shift
+    keys: HashMap<char, (i32, i32, bool)>,
+    directions: &'static [(i32, i32)],
+    /// The number of characters a pattern can start with
+    pub starting_positions: usize,
+    /// The average number of neighbours of a key
+    pub average_degree: f64,
+}
+
+pub static KEYBOARDS: LazyLock<[Keyboard; 2]> = LazyLock::new(|| {
+    let qwerty = QWERTY.iter().enumerate().flat_map(|(y, (row, offset))| {
+        let characters = row.chars().collect::<Vec<char>>();
+        characters
+            .chunks(2)
+            .enumerate()
+            .flat_map(|(x, key)| {
+                let position = (x as i32 + offset, y as i32);
+                [(key[0], position, false), (key[1], position, true)]
+            })
+            .collect::<Vec<(char, (i32, i32), bool)>>()
+    });
+    let keypad = KEYPAD.iter().enumerate().flat_map(|(y, row)| {
+        row.chars()
+            .enumerate()
+            .filter(|(_, key)| *key != ' ')
+            .map(move |(x, key)| (key, (x as i32, y as i32), false))
+    });
+
+    [
+        Keyboard::new("qwerty", qwerty, SLANTED),
+        Keyboard::new("keypad", keypad, ALIGNED),
+    ]
+});
+
+impl Keyboard {
+    fn new(
+        name: &'static str,
+        keys: impl Iterator<Item = (char, (i32, i32), bool)>,
+        directions: &'static [(i32, i32)],
+    ) -> Self {
+        let keys = keys
+            .map(|(key, (x, y), shifted)| (key, (x, y, shifted)))
+            .collect::<HashMap<char, (i32, i32, bool)>>();
+        let positions = keys
+            .values()
+            .map(|(x, y, _)| (*x, *y))
+            .collect::<HashSet<(i32, i32)>>();
+        let degrees = positions
+            .iter()
+            .map(|(x, y)| {
+                directions
+                    .iter()
+                    .filter(|(dx, dy)| positions.contains(&(x + dx, y + dy)))
+                    .count()
+            })
+            .sum::<usize>();
+
+        Keyboard {
+            name,
+            starting_positions: keys.len(),
+            average_degree: degrees as f64 / positions.len() as f64,
+            keys,
+            directions,
+        }
+    }
+
+    /// Gets the direction from one key to an adjacent key, or `None` if they are not adjacent
+    pub fn direction(&self, from: char, to: char) -> Option<usize> {
+        let (from_x, from_y, _) = self.keys.get(&from)?;
+        let (to_x, to_y, _) = self.keys.get(&to)?;
+
+        self.directions
+            .iter()
+            .position(|(dx, dy)| (from_x + dx, from_y + dy) == (*to_x, *to_y))
+    }
+
+    pub fn is_shifted(&self, key: char) -> bool {
+        self.keys.get(&key).is_some_and(|(_, _, shifted)| *shifted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finds_adjacent_keys() {
+        let [qwerty, keypad] = &*KEYBOARDS;
+
+        assert!(qwerty.direction('q', 'w').is_some());
+        assert!(qwerty.direction('q', 'A').is_some());
+        assert!(qwerty.direction('1', 'q').is_some());
+        assert!(qwerty.direction('q', 'e').is_none());
+        assert!(qwerty.is_shifted('!'));
+        assert!(keypad.direction('7', '5').is_some());
+        assert!(keypad.direction('7', '3').is_none());
+    }
+}
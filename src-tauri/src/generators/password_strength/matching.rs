@@ -0,0 +1,647 @@
+use crate::generators::password_strength::{
+    built_in_dictionaries, date_guesses, dictionary_guesses, most_guessable, reference_year,
+    sequence_guesses, spatial_guesses, year_guesses, Dictionary, KEYBOARDS,
+};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+
+/// Characters commonly substituted for letters, with the letters they can stand for
+const L33T_TABLE: [(char, &str); 20] = [
+    ('4', "a"),
+    ('@', "a"),
+    ('8', "b"),
+    ('(', "c"),
+    ('{', "c"),
+    ('[', "c"),
+    ('<', "c"),
+    ('3', "e"),
+    ('6', "g"),
+    ('9', "g"),
+    ('1', "il"),
+    ('!', "i"),
+    ('|', "il"),
+    ('7', "lt"),
+    ('0', "o"),
+    ('$', "s"),
+    ('5', "s"),
+    ('+', "t"),
+    ('%', "x"),
+    ('2', "z"),
+];
+
+/// The most combinations of substitutions tried when a password has ambiguous substitutions
+const MAX_L33T_SUBSTITUTIONS: usize = 64;
+
+/// The largest difference between the characters of a sequence, e.g. 2 in `acegi`
+const MAX_SEQUENCE_DELTA: i64 = 5;
+
+const DATE_SEPARATORS: &str = " /\\_.-";
+
+/// Where to split dates without separators of every length into day, month and year
+const DATE_SPLITS: [&[(usize, usize)]; 5] = [
+    &[(1, 2), (2, 3)],
+    &[(1, 3), (2, 3)],
+    &[(1, 2), (2, 4), (4, 5)],
+    &[(1, 3), (2, 3), (4, 5), (4, 6)],
+    &[(2, 4), (4, 6)],
+];
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub enum PatternKind {
+    /// A word from a dictionary, possibly reversed or with letters substituted by look-alike
+    /// characters (the substituted character and the letter it stands for)
+    Dictionary {
+        dictionary: Dictionary,
+        word: String,
+        rank: usize,
+        reversed: bool,
+        l33t: Vec<(char, char)>,
+    },
+    /// Adjacent keys on a keyboard, with the number of changes of direction and shifted keys
+    Spatial {
+        keyboard: String,
+        turns: usize,
+        shifted: usize,
+    },
+    /// Characters with a constant difference, like `abc` or `9753`
+    Sequence {
+        ascending: bool,
+    },
+    /// A repeated string, like `aaa` or `abcabc`
+    Repeat {
+        base: String,
+        count: usize,
+    },
+    Date {
+        year: i32,
+        month: i32,
+        day: i32,
+        separator: String,
+    },
+    Year {
+        year: i32,
+    },
+    /// Characters that are not part of any other pattern
+    Bruteforce,
+}
+
+/// A pattern found in a password, with the number of guesses needed to guess it
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct PasswordPattern {
+    pub kind: PatternKind,
+    pub token: String,
+    /// The index of the first character of the token in the password
+    pub start: usize,
+    /// The index after the last character of the token in the password
+    pub end: usize,
+    pub guesses: f64,
+}
+
+impl PasswordPattern {
+    fn new(password: &[char], start: usize, end: usize, kind: PatternKind, guesses: f64) -> Self {
+        PasswordPattern {
+            kind,
+            token: password[start..end].iter().collect(),
+            start,
+            end,
+            guesses,
+        }
+    }
+}
+
+/// Finds every dictionary word, keyboard pattern, sequence, repeat and date in a password,
+/// including overlapping ones
+pub fn find_patterns(
+    password: &[char],
+    user_inputs: &HashMap<String, usize>,
+) -> Vec<PasswordPattern> {
+    let mut patterns = Vec::new();
+    patterns.extend(find_dictionary_patterns(password, user_inputs));
+    patterns.extend(find_spatial_patterns(password));
+    patterns.extend(find_repeat_patterns(password, user_inputs, password.len()));
+    patterns.extend(find_sequence_patterns(password));
+    patterns.extend(find_date_patterns(password));
+    patterns.extend(find_year_patterns(password));
+
+    patterns.sort_by_key(|pattern| (pattern.start, pattern.end));
+    patterns
+}
+
+/// Finds the words of every dictionary in lowercase text, as (start, end, dictionary, word,
+/// rank)
+fn find_words(
+    text: &[char],
+    user_inputs: &HashMap<String, usize>,
+) -> Vec<(usize, usize, Dictionary, String, usize)> {
+    let dictionaries = built_in_dictionaries()
+        .iter()
+        .map(|(dictionary, ranks)| (*dictionary, ranks))
+        .chain([(Dictionary::UserInputs, user_inputs)]);
+
+    let mut words = Vec::new();
+    for (dictionary, ranks) in dictionaries {
+        for start in 0..text.len() {
+            for end in start + 1..=text.len() {
+                let word = text[start..end].iter().collect::<String>();
+                if let Some(rank) = ranks.get(&word) {
+                    words.push((start, end, dictionary, word, *rank));
+                }
+            }
+        }
+    }
+
+    words
+}
+
+fn to_lowercase(password: &[char]) -> Vec<char> {
+    password
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect()
+}
+
+fn find_dictionary_patterns(
+    password: &[char],
+    user_inputs: &HashMap<String, usize>,
+) -> Vec<PasswordPattern> {
+    let length = password.len();
+    let lowercase = to_lowercase(password);
+    let mut patterns = Vec::new();
+
+    let mut add =
+        |start, end, dictionary, word: String, rank, reversed, l33t: Vec<(char, char)>| {
+            let token = &password[start..end];
+            let guesses = dictionary_guesses(rank, token, reversed, &l33t);
+            let kind = PatternKind::Dictionary {
+                dictionary,
+                word,
+                rank,
+                reversed,
+                l33t,
+            };
+            patterns.push(PasswordPattern::new(password, start, end, kind, guesses));
+        };
+
+    for (start, end, dictionary, word, rank) in find_words(&lowercase, user_inputs) {
+        add(start, end, dictionary, word, rank, false, Vec::new());
+    }
+
+    let reversed = lowercase.iter().rev().copied().collect::<Vec<char>>();
+    for (start, end, dictionary, word, rank) in find_words(&reversed, user_inputs) {
+        // Palindromes are already found forwards
+        if word.chars().rev().ne(word.chars()) {
+            add(
+                length - end,
+                length - start,
+                dictionary,
+                word,
+                rank,
+                true,
+                Vec::new(),
+            );
+        }
+    }
+
+    let mut found = HashSet::new();
+    for substitutions in get_l33t_substitutions(&lowercase) {
+        let unsubstituted = lowercase
+            .iter()
+            .map(|c| substitutions.get(c).copied().unwrap_or(*c))
+            .collect::<Vec<char>>();
+
+        for (start, end, dictionary, word, rank) in find_words(&unsubstituted, user_inputs) {
+            let mut l33t = lowercase[start..end]
+                .iter()
+                .filter_map(|c| substitutions.get(c).map(|letter| (*c, *letter)))
+                .collect::<Vec<(char, char)>>();
+            l33t.sort();
+            l33t.dedup();
+
+            // Single substituted characters are too short to be meaningful
+            if l33t.is_empty() || end - start == 1 {
+                continue;
+            }
+            if found.insert((start, end, word.clone())) {
+                add(start, end, dictionary, word, rank, false, l33t);
+            }
+        }
+    }
+
+    patterns
+}
+
+/// Gets every combination of letters the substitutable characters of a password can stand
+/// for, up to a limit
+fn get_l33t_substitutions(password: &[char]) -> Vec<HashMap<char, char>> {
+    let mut substitutions = vec![HashMap::new()];
+
+    for (l33t, letters) in L33T_TABLE {
+        if !password.contains(&l33t) {
+            continue;
+        }
+
+        substitutions = substitutions
+            .into_iter()
+            .flat_map(|substitution| {
+                letters.chars().map(move |letter| {
+                    let mut substitution = substitution.clone();
+                    substitution.insert(l33t, letter);
+                    substitution
+                })
+            })
+            .take(MAX_L33T_SUBSTITUTIONS)
+            .collect();
+    }
+
+    substitutions.retain(|substitution| !substitution.is_empty());
+    substitutions
+}
+
+fn find_spatial_patterns(password: &[char]) -> Vec<PasswordPattern> {
+    let mut patterns = Vec::new();
+
+    for keyboard in KEYBOARDS.iter() {
+        let mut start = 0;
+        while start < password.len() {
+            let mut end = start + 1;
+            let mut last_direction = None;
+            let mut turns = 0;
+            let mut shifted = keyboard.is_shifted(password[start]) as usize;
+
+            while let Some(direction) = password
+                .get(end)
+                .and_then(|c| keyboard.direction(password[end - 1], *c))
+            {
+                if last_direction != Some(direction) {
+                    turns += 1;
+                    last_direction = Some(direction);
+                }
+                shifted += keyboard.is_shifted(password[end]) as usize;
+                end += 1;
+            }
+
+            if end - start >= 3 {
+                let guesses = spatial_guesses(keyboard, end - start, turns, shifted);
+                let kind = PatternKind::Spatial {
+                    keyboard: keyboard.name.to_string(),
+                    turns,
+                    shifted,
+                };
+                patterns.push(PasswordPattern::new(password, start, end, kind, guesses));
+            }
+            start = end;
+        }
+    }
+
+    patterns
+}
+
+/// Finds the repeats and sequences of at least `min_length` characters in a password of any
+/// length, without overlaps. Bases of repeats have at most `max_base_length` characters, so
+/// the time taken grows linearly with the length of the password
+pub fn find_long_patterns(
+    password: &[char],
+    user_inputs: &HashMap<String, usize>,
+    max_base_length: usize,
+    min_length: usize,
+) -> Vec<PasswordPattern> {
+    let mut patterns = find_repeat_patterns(password, user_inputs, max_base_length);
+    patterns.extend(find_sequence_patterns(password));
+    patterns.retain(|pattern| pattern.end - pattern.start >= min_length);
+    patterns.sort_by_key(|pattern| (pattern.start, Reverse(pattern.end)));
+
+    let mut end = 0;
+    patterns.retain(|pattern| {
+        let is_after = pattern.start >= end;
+        if is_after {
+            end = pattern.end;
+        }
+        is_after
+    });
+    patterns
+}
+
+fn find_repeat_patterns(
+    password: &[char],
+    user_inputs: &HashMap<String, usize>,
+    max_base_length: usize,
+) -> Vec<PasswordPattern> {
+    let mut patterns = Vec::new();
+    let mut start = 0;
+
+    while start < password.len() {
+        // The repeat covering the most characters, preferring the shortest base
+        let mut longest: Option<(usize, usize)> = None;
+        for base_length in 1..=max_base_length.min((password.len() - start) / 2) {
+            let base = &password[start..start + base_length];
+            let count = password[start..]
+                .chunks(base_length)
+                .take_while(|chunk| *chunk == base)
+                .count();
+
+            let is_longer = longest.is_none_or(|(longest_base_length, longest_count)| {
+                count * base_length > longest_base_length * longest_count
+            });
+            if count >= 2 && is_longer {
+                longest = Some((base_length, count));
+            }
+        }
+
+        match longest {
+            Some((base_length, count)) => {
+                let base = &password[start..start + base_length];
+                let (base_guesses, _) = most_guessable(base, &find_patterns(base, user_inputs));
+                let end = start + base_length * count;
+                let kind = PatternKind::Repeat {
+                    base: base.iter().collect(),
+                    count,
+                };
+                let guesses = base_guesses * count as f64;
+                patterns.push(PasswordPattern::new(password, start, end, kind, guesses));
+                start = end;
+            }
+            None => start += 1,
+        }
+    }
+
+    patterns
+}
+
+fn find_sequence_patterns(password: &[char]) -> Vec<PasswordPattern> {
+    let delta = |index: usize| password[index + 1] as i64 - password[index] as i64;
+    let mut patterns = Vec::new();
+    let mut start = 0;
+
+    while start + 1 < password.len() {
+        let start_delta = delta(start);
+        let mut end = start + 2;
+        while end < password.len() && delta(end - 1) == start_delta {
+            end += 1;
+        }
+
+        if end - start >= 3 && (1..=MAX_SEQUENCE_DELTA).contains(&start_delta.abs()) {
+            let token = &password[start..end];
+            let ascending = start_delta > 0;
+            let guesses = sequence_guesses(token, ascending);
+            let kind = PatternKind::Sequence { ascending };
+            patterns.push(PasswordPattern::new(password, start, end, kind, guesses));
+        }
+        start = end - 1;
+    }
+
+    patterns
+}
+
+fn find_date_patterns(password: &[char]) -> Vec<PasswordPattern> {
+    let mut patterns: Vec<PasswordPattern> = Vec::new();
+
+    for start in 0..password.len() {
+        for end in start + 4..=(start + 10).min(password.len()) {
+            let token = password[start..end].iter().collect::<String>();
+
+            let date = if token.chars().all(|c| c.is_ascii_digit()) {
+                parse_date_without_separator(&token)
+            } else {
+                parse_date_with_separator(&token)
+            };
+
+            if let Some((year, month, day, separator)) = date {
+                let guesses = date_guesses(year, !separator.is_empty());
+                let kind = PatternKind::Date {
+                    year,
+                    month,
+                    day,
+                    separator,
+                };
+                patterns.push(PasswordPattern::new(password, start, end, kind, guesses));
+            }
+        }
+    }
+
+    // Dates within other dates, like the 2010 in 20102011, are not separate dates
+    let dates = patterns.clone();
+    patterns.retain(|pattern| {
+        !dates.iter().any(|other| {
+            other != pattern && other.start <= pattern.start && other.end >= pattern.end
+        })
+    });
+
+    patterns
+}
+
+fn parse_date_without_separator(token: &str) -> Option<(i32, i32, i32, String)> {
+    let splits = DATE_SPLITS.get(token.len().checked_sub(4)?)?;
+    let reference_year = reference_year();
+
+    splits
+        .iter()
+        .filter_map(|(first, second)| {
+            let ints = [
+                token[..*first].parse().ok()?,
+                token[*first..*second].parse().ok()?,
+                token[*second..].parse().ok()?,
+            ];
+            map_ints_to_date(ints)
+        })
+        .min_by_key(|(year, _, _)| (year - reference_year).abs())
+        .map(|(year, month, day)| (year, month, day, String::new()))
+}
+
+fn parse_date_with_separator(token: &str) -> Option<(i32, i32, i32, String)> {
+    let separator = token.chars().find(|c| !c.is_ascii_digit())?;
+    if !DATE_SEPARATORS.contains(separator) {
+        return None;
+    }
+
+    let parts = token.split(separator).collect::<Vec<&str>>();
+    let is_valid_part =
+        |part: &&str| (1..=4).contains(&part.len()) && part.chars().all(|c| c.is_ascii_digit());
+    if parts.len() != 3 || !parts.iter().all(is_valid_part) {
+        return None;
+    }
+
+    let ints = [
+        parts[0].parse().ok()?,
+        parts[1].parse().ok()?,
+        parts[2].parse().ok()?,
+    ];
+    map_ints_to_date(ints).map(|(year, month, day)| (year, month, day, separator.to_string()))
+}
+
+/// Interprets three numbers as a year, month and day in any common order
+fn map_ints_to_date(ints: [i32; 3]) -> Option<(i32, i32, i32)> {
+    if ints[1] > 31 || ints[1] <= 0 {
+        return None;
+    }
+    if ints
+        .iter()
+        .any(|int| (100..1000).contains(int) || *int > 2050)
+    {
+        return None;
+    }
+
+    let over_12 = ints.iter().filter(|int| **int > 12).count();
+    let over_31 = ints.iter().filter(|int| **int > 31).count();
+    let under_1 = ints.iter().filter(|int| **int <= 0).count();
+    if over_31 >= 2 || over_12 == 3 || under_1 >= 2 {
+        return None;
+    }
+
+    let orders = [(ints[2], [ints[0], ints[1]]), (ints[0], [ints[1], ints[2]])];
+
+    for (year, rest) in orders {
+        if (1000..=2050).contains(&year) {
+            return map_ints_to_day_month(rest).map(|(day, month)| (year, month, day));
+        }
+    }
+
+    orders.into_iter().find_map(|(year, rest)| {
+        let year = if year > 50 { year + 1900 } else { year + 2000 };
+        map_ints_to_day_month(rest).map(|(day, month)| (year, month, day))
+    })
+}
+
+fn map_ints_to_day_month([first, second]: [i32; 2]) -> Option<(i32, i32)> {
+    [(first, second), (second, first)]
+        .into_iter()
+        .find(|(day, month)| (1..=31).contains(day) && (1..=12).contains(month))
+}
+
+fn find_year_patterns(password: &[char]) -> Vec<PasswordPattern> {
+    (0..password.len().saturating_sub(3))
+        .filter_map(|start| {
+            let token = password[start..start + 4].iter().collect::<String>();
+            let year = token.parse::<i32>().ok()?;
+            let is_year =
+                token.chars().all(|c| c.is_ascii_digit()) && (1900..=2050).contains(&year);
+
+            is_year.then(|| {
+                let kind = PatternKind::Year { year };
+                PasswordPattern::new(password, start, start + 4, kind, year_guesses(year))
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find(password: &str) -> Vec<PasswordPattern> {
+        let password = password.chars().collect::<Vec<char>>();
+        find_patterns(&password, &HashMap::new())
+    }
+
+    fn has(
+        patterns: &[PasswordPattern],
+        token: &str,
+        check: impl Fn(&PatternKind) -> bool,
+    ) -> bool {
+        patterns
+            .iter()
+            .any(|pattern| pattern.token == token && check(&pattern.kind))
+    }
+
+    #[test]
+    fn test_finds_dictionary_words() {
+        let patterns = find("Dragon!drowssapP4ssw0rd");
+
+        assert!(has(&patterns, "Dragon", |kind| matches!(
+            kind,
+            PatternKind::Dictionary {
+                dictionary: Dictionary::Passwords,
+                reversed: false,
+                ..
+            }
+        )));
+        assert!(has(&patterns, "drowssap", |kind| matches!(
+            kind,
+            PatternKind::Dictionary { reversed: true, .. }
+        )));
+        assert!(has(&patterns, "P4ssw0rd", |kind| matches!(
+            kind,
+            PatternKind::Dictionary { l33t, word, .. } if word == "password" && l33t == &[('0', 'o'), ('4', 'a')]
+        )));
+    }
+
+    #[test]
+    fn test_finds_user_inputs() {
+        let password = "janedoe99".chars().collect::<Vec<char>>();
+        let user_inputs = HashMap::from([("janedoe".to_string(), 1)]);
+
+        let patterns = find_patterns(&password, &user_inputs);
+
+        assert!(has(&patterns, "janedoe", |kind| matches!(
+            kind,
+            PatternKind::Dictionary {
+                dictionary: Dictionary::UserInputs,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_finds_keyboard_patterns() {
+        let patterns = find("xqwertyx7412");
+
+        assert!(has(&patterns, "qwerty", |kind| matches!(
+            kind,
+            PatternKind::Spatial { turns: 1, .. }
+        )));
+        assert!(has(&patterns, "7412", |kind| matches!(
+            kind,
+            PatternKind::Spatial { keyboard, .. } if keyboard == "keypad"
+        )));
+    }
+
+    #[test]
+    fn test_finds_sequences_and_repeats() {
+        let patterns = find("abcdeZ9753zzzzXyzXyz");
+
+        assert!(has(&patterns, "abcde", |kind| matches!(
+            kind,
+            PatternKind::Sequence { ascending: true }
+        )));
+        assert!(has(&patterns, "9753", |kind| matches!(
+            kind,
+            PatternKind::Sequence { ascending: false }
+        )));
+        assert!(has(&patterns, "zzzz", |kind| matches!(
+            kind,
+            PatternKind::Repeat { count: 4, .. }
+        )));
+        assert!(has(&patterns, "XyzXyz", |kind| matches!(
+            kind,
+            PatternKind::Repeat { base, count: 2 } if base == "Xyz"
+        )));
+    }
+
+    #[test]
+    fn test_finds_dates_and_years() {
+        let patterns = find("x13/05/1987y19870513z2019");
+
+        assert!(has(&patterns, "13/05/1987", |kind| matches!(
+            kind,
+            PatternKind::Date {
+                year: 1987,
+                month: 5,
+                day: 13,
+                ..
+            }
+        )));
+        assert!(has(&patterns, "19870513", |kind| matches!(
+            kind,
+            PatternKind::Date {
+                year: 1987,
+                month: 5,
+                day: 13,
+                ..
+            }
+        )));
+        assert!(has(&patterns, "2019", |kind| matches!(
+            kind,
+            PatternKind::Year { year: 2019 }
+        )));
+    }
+}
@@ -0,0 +1,13 @@
+pub mod analyze_password;
+pub mod dictionaries;
+pub mod feedback;
+pub mod keyboard;
+pub mod matching;
+pub mod scoring;
+
+pub use analyze_password::*;
+pub use dictionaries::*;
+pub use feedback::*;
+pub use keyboard::*;
+pub use matching::*;
+pub use scoring::*;
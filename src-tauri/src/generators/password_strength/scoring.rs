@@ -0,0 +1,305 @@
+use crate::generators::password_strength::{Keyboard, PasswordPattern, PatternKind};
+use chrono::{Datelike, Utc};
+use std::collections::HashMap;
+
+/// The guesses added for every extra pattern in a password, so a password is not split into
+/// many short patterns
+const MIN_GUESSES_BEFORE_GROWING_SEQUENCE: f64 = 10_000.0;
+
+const MIN_SUBMATCH_GUESSES_SINGLE_CHAR: f64 = 10.0;
+const MIN_SUBMATCH_GUESSES_MULTI_CHAR: f64 = 50.0;
+
+/// The guesses per character of characters that are not part of a pattern
+pub const BRUTEFORCE_CARDINALITY: f64 = 10.0;
+
+/// The fewest years an attacker tries around the current year when guessing a year
+const MIN_YEAR_SPACE: i32 = 20;
+
+/// The year people pick dates and years around
+pub fn reference_year() -> i32 {
+    Utc::now().year()
+}
+
+/// The patterns ending at a position that a sequence of a given length ends with
+struct Step {
+    /// The product of the guesses of the patterns in the sequence
+    product: f64,
+    /// The guesses of the whole sequence
+    guesses: f64,
+    pattern: PasswordPattern,
+}
+
+/// Finds the sequence of non-overlapping patterns covering a password that is the easiest to
+/// guess, filling gaps between patterns with bruteforce patterns.
+///
+/// Guessing a sequence of `l` patterns takes `l! * product of their guesses` guesses (the
+/// patterns can be in any order) plus a penalty for every extra pattern.
+pub fn most_guessable(
+    password: &[char],
+    patterns: &[PasswordPattern],
+) -> (f64, Vec<PasswordPattern>) {
+    let length = password.len();
+    if length == 0 {
+        return (1.0, Vec::new());
+    }
+
+    // The best sequence ending at every position, by number of patterns
+    let mut optimal: Vec<HashMap<usize, Step>> = (0..length).map(|_| HashMap::new()).collect();
+
+    for end in 1..=length {
+        let bruteforce = (0..end).map(|start| {
+            let kind = PatternKind::Bruteforce;
+            let guesses = BRUTEFORCE_CARDINALITY.powi((end - start) as i32);
+            PasswordPattern {
+                kind,
+                token: password[start..end].iter().collect(),
+                start,
+                end,
+                guesses,
+            }
+        });
+        let candidates = patterns
+            .iter()
+            .filter(|pattern| pattern.end == end)
+            .cloned()
+            .chain(bruteforce)
+            .collect::<Vec<PasswordPattern>>();
+
+        for pattern in candidates {
+            let guesses = minimum_guesses(&pattern, length);
+            let previous = match pattern.start {
+                0 => vec![(0, 1.0)],
+                start => optimal[start - 1]
+                    .iter()
+                    .map(|(count, step)| (*count, step.product))
+                    .collect(),
+            };
+
+            for (count, product) in previous {
+                let count = count + 1;
+                let product = product * guesses;
+                let sequence_guesses = factorial(count) * product
+                    + MIN_GUESSES_BEFORE_GROWING_SEQUENCE.powi(count as i32 - 1);
+
+                // Only keep sequences that are better than any shorter sequence
+                let is_better = optimal[end - 1]
+                    .iter()
+                    .filter(|(other_count, _)| **other_count <= count)
+                    .all(|(_, step)| step.guesses > sequence_guesses);
+                if is_better {
+                    optimal[end - 1].insert(
+                        count,
+                        Step {
+                            product,
+                            guesses: sequence_guesses,
+                            pattern: pattern.clone(),
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    let Some((count, best)) = optimal[length - 1]
+        .iter()
+        .min_by(|(_, a), (_, b)| a.guesses.total_cmp(&b.guesses))
+    else {
+        return (1.0, Vec::new());
+    };
+    let guesses = best.guesses;
+    let mut count = *count;
+
+    let mut sequence = Vec::new();
+    let mut end = length;
+    while end > 0 {
+        let step = &optimal[end - 1][&count];
+        sequence.push(step.pattern.clone());
+        end = step.pattern.start;
+        count -= 1;
+    }
+    sequence.reverse();
+
+    (guesses, sequence)
+}
+
+/// Gets the guesses of a sequence of patterns covering a password, as `most_guessable` counts
+/// them
+pub fn sequence_of_patterns_guesses(patterns: &[PasswordPattern], password_length: usize) -> f64 {
+    let product = patterns
+        .iter()
+        .map(|pattern| minimum_guesses(pattern, password_length))
+        .product::<f64>();
+
+    factorial(patterns.len()) * product
+        + MIN_GUESSES_BEFORE_GROWING_SEQUENCE.powi(patterns.len() as i32 - 1)
+}
+
+/// Gets the guesses of a pattern, at least a minimum when it is part of a longer password so
+/// short patterns are not favoured over bruteforce
+fn minimum_guesses(pattern: &PasswordPattern, password_length: usize) -> f64 {
+    let minimum = if pattern.end - pattern.start == password_length {
+        1.0
+    } else if pattern.end - pattern.start == 1 {
+        MIN_SUBMATCH_GUESSES_SINGLE_CHAR
+    } else {
+        MIN_SUBMATCH_GUESSES_MULTI_CHAR
+    };
+
+    pattern.guesses.max(minimum)
+}
+
+fn factorial(n: usize) -> f64 {
+    (2..=n).map(|n| n as f64).product()
+}
+
+fn binomial(n: usize, k: usize) -> f64 {
+    (1..=k.min(n)).fold(1.0, |result, i| result * (n + 1 - i) as f64 / i as f64)
+}
+
+/// Counts the ways a and b of two kinds of characters can be mixed, with at least one of each
+fn variations(a: usize, b: usize) -> f64 {
+    if a == 0 || b == 0 {
+        2.0
+    } else {
+        (1..=a.min(b)).map(|i| binomial(a + b, i)).sum()
+    }
+}
+
+/// Gets the guesses of a dictionary word, made harder by capitalization, reversal and
+/// substitutions
+pub fn dictionary_guesses(
+    rank: usize,
+    token: &[char],
+    reversed: bool,
+    l33t: &[(char, char)],
+) -> f64 {
+    let upper = token.iter().filter(|c| c.is_uppercase()).count();
+    let lower = token.iter().filter(|c| c.is_lowercase()).count();
+    let is_upper = |index: Option<&char>| index.is_some_and(|c| c.is_uppercase());
+    let uppercase_variations = if upper == 0 {
+        1.0
+    } else if lower == 0 || (upper == 1 && (is_upper(token.first()) || is_upper(token.last()))) {
+        // All uppercase or only the first or last letter
+        2.0
+    } else {
+        variations(upper, lower)
+    };
+
+    let l33t_variations = l33t
+        .iter()
+        .map(|(substituted, letter)| {
+            let substituted_count = token.iter().filter(|c| *c == substituted).count();
+            let letter_count = token
+                .iter()
+                .filter(|c| c.to_lowercase().eq([*letter]))
+                .count();
+            if letter_count == 0 {
+                2.0
+            } else {
+                variations(substituted_count, letter_count)
+            }
+        })
+        .product::<f64>();
+
+    let reversed_variations = if reversed { 2.0 } else { 1.0 };
+
+    rank as f64 * uppercase_variations * l33t_variations * reversed_variations
+}
+
+/// Gets the guesses of a keyboard pattern by counting the patterns of the same length with at
+/// most as many turns, made harder by shifted keys
+pub fn spatial_guesses(keyboard: &Keyboard, length: usize, turns: usize, shifted: usize) -> f64 {
+    let starting_positions = keyboard.starting_positions as f64;
+    let average_degree = keyboard.average_degree;
+
+    let mut guesses = 0.0;
+    for pattern_length in 2..=length {
+        for pattern_turns in 1..=turns.min(pattern_length - 1) {
+            guesses += binomial(pattern_length - 1, pattern_turns - 1)
+                * starting_positions
+                * average_degree.powi(pattern_turns as i32);
+        }
+    }
+
+    if shifted > 0 {
+        guesses *= variations(shifted, length - shifted);
+    }
+
+    guesses
+}
+
+/// Gets the guesses of a sequence, which are fewer when it starts at an obvious character
+pub fn sequence_guesses(token: &[char], ascending: bool) -> f64 {
+    let first = token.first().copied().unwrap_or_default();
+    let base_guesses = if "aAzZ019".contains(first) {
+        4.0
+    } else if first.is_ascii_digit() {
+        10.0
+    } else {
+        26.0
+    };
+    let direction_guesses = if ascending { 1.0 } else { 2.0 };
+
+    base_guesses * direction_guesses * token.len() as f64
+}
+
+/// Gets the guesses of a date, assuming dates close to the current year are tried first
+pub fn date_guesses(year: i32, has_separator: bool) -> f64 {
+    let separator_guesses = if has_separator { 4.0 } else { 1.0 };
+
+    year_guesses(year) * 365.0 * separator_guesses
+}
+
+pub fn year_guesses(year: i32) -> f64 {
+    (year - reference_year()).abs().max(MIN_YEAR_SPACE) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn test_dictionary_guesses_grow_with_variations() {
+        assert_eq!(dictionary_guesses(10, &chars("dragon"), false, &[]), 10.0);
+        assert_eq!(dictionary_guesses(10, &chars("Dragon"), false, &[]), 20.0);
+        assert_eq!(dictionary_guesses(10, &chars("DRAGON"), true, &[]), 40.0);
+        assert_eq!(
+            dictionary_guesses(10, &chars("dRaGon"), false, &[]),
+            variations(2, 4) * 10.0
+        );
+        assert_eq!(
+            dictionary_guesses(10, &chars("dr4gon"), false, &[('4', 'a')]),
+            20.0
+        );
+    }
+
+    #[test]
+    fn test_finds_cheapest_sequence() {
+        let password = chars("ab");
+        let pattern = PasswordPattern {
+            kind: PatternKind::Year { year: 2000 },
+            token: "ab".to_string(),
+            start: 0,
+            end: 2,
+            guesses: 5.0,
+        };
+
+        let (guesses, sequence) = most_guessable(&password, std::slice::from_ref(&pattern));
+
+        assert_eq!(guesses, 6.0);
+        assert_eq!(sequence, vec![pattern]);
+    }
+
+    #[test]
+    fn test_fills_gaps_with_bruteforce() {
+        let (guesses, sequence) = most_guessable(&chars("x7"), &[]);
+
+        assert_eq!(guesses, 101.0);
+        assert_eq!(sequence.len(), 1);
+        assert_eq!(sequence[0].kind, PatternKind::Bruteforce);
+    }
+}
@@ -0,0 +1,11 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Creates a random number generator seeded with `seed`, or from the operating system's
+/// random source when there is no seed
+pub fn seeded_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_os_rng(),
+    }
+}
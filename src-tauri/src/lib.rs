@@ -2,17 +2,60 @@ mod encoding;
 mod generators;
 mod llm;
 
+use llm::chat::ChatGenerations;
+use llm::connection::{ConnectionSettings, OllamaClient};
+use tauri::Manager;
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_opener::init())
+        .setup(|app| {
+            // Fall back to the default settings if the saved ones cannot be read
+            let settings = ConnectionSettings::path(app.handle())
+                .and_then(|path| ConnectionSettings::load(&path))
+                .unwrap_or_default();
+            app.manage(OllamaClient::new(settings));
+            app.manage(ChatGenerations::default());
+
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
+            encoding::decode_data,
             encoding::decode_jwt,
+            encoding::decrypt_jwe,
+            encoding::detect_encoding,
+            encoding::encode_data,
+            encoding::encode_jwt,
+            encoding::inspect_jwe,
+            encoding::validate_jwt_claims,
+            encoding::verify_jwt,
+            encoding::verify_jwt_with_jwks,
+            encoding::web::build_url,
+            encoding::web::html_escape,
+            encoding::web::html_unescape,
+            encoding::web::parse_url,
+            encoding::web::url_decode,
+            encoding::web::url_encode,
+            generators::generate_fake_data,
+            generators::generate_hash,
             generators::generate_lipsum,
+            generators::generate_secret,
             generators::generate_uuid,
+            generators::ids::decode_id,
+            generators::ids::export_ids,
+            generators::ids::format_ids,
+            generators::ids::generate_id,
+            generators::inspect_uuid,
+            generators::mock_json::generate_mock_json,
+            generators::password_strength::analyze_password,
+            llm::chat::cancel_message,
             llm::chat::send_message,
+            llm::connection::get_connection_settings,
+            llm::connection::set_connection_settings,
+            llm::connection::test_connection,
             llm::manage_models::delete_model,
             llm::manage_models::download_model,
             llm::manage_models::list_models,
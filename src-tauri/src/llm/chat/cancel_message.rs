@@ -0,0 +1,27 @@
+use crate::llm::chat::ChatGenerations;
+use ollama_rs::generation::chat::ChatMessage;
+use tauri::State;
+
+/// Stops generating a chat response. The response stream is aborted and `send_message`
+/// returns the response generated so far.
+///
+/// # Arguments
+/// * `generations` - The managed chat responses being generated
+/// * `message_id` - ID of the message passed to `send_message`
+///
+/// # Events Emitted
+/// * "chat-message-cancelled" with (message_id, content generated so far)
+///
+/// # Returns
+/// * `Ok(ChatMessage)` - The response generated before it was cancelled
+/// * `Err(String)` - An error message if the message is not being generated
+///
+/// ```
+
+#[tauri::command]
+pub async fn cancel_message(
+    generations: State<'_, ChatGenerations>,
+    message_id: String,
+) -> Result<ChatMessage, String> {
+    generations.cancel(&message_id).await
+}
@@ -0,0 +1,123 @@
+use ollama_rs::generation::chat::ChatMessage;
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use tokio::sync::oneshot;
+
+/// The channel a cancelled generation sends the response it collected so far through
+pub type CancelReply = oneshot::Sender<ChatMessage>;
+
+/// The chat responses being generated by message ID, managed in Tauri state so a generation
+/// can be cancelled from another command
+#[derive(Default)]
+pub struct ChatGenerations {
+    generations: Mutex<HashMap<String, oneshot::Sender<CancelReply>>>,
+}
+
+/// A registered generation, which is unregistered when dropped
+pub struct Generation<'a> {
+    generations: &'a ChatGenerations,
+    message_id: String,
+    /// Receives the channel to reply to when the generation is cancelled
+    pub cancellation: oneshot::Receiver<CancelReply>,
+}
+
+impl ChatGenerations {
+    /// Registers the generation of a message so it can be cancelled
+    pub fn register(&self, message_id: &str) -> Result<Generation<'_>, String> {
+        let mut generations = self
+            .generations
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if generations.contains_key(message_id) {
+            return Err(format!("Message {} is already being generated", message_id));
+        }
+
+        let (sender, cancellation) = oneshot::channel();
+        generations.insert(message_id.to_string(), sender);
+
+        Ok(Generation {
+            generations: self,
+            message_id: message_id.to_string(),
+            cancellation,
+        })
+    }
+
+    /// Cancels the generation of a message
+    ///
+    /// # Returns
+    /// * `Ok(ChatMessage)` - The response generated before it was cancelled
+    /// * `Err(String)` - An error message if the message is not being generated
+    pub async fn cancel(&self, message_id: &str) -> Result<ChatMessage, String> {
+        let cancellation = self
+            .generations
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(message_id)
+            .ok_or_else(|| format!("Message {} is not being generated", message_id))?;
+
+        let (reply, response) = oneshot::channel();
+        cancellation
+            .send(reply)
+            .map_err(|_| format!("Message {} has already been generated", message_id))?;
+
+        response
+            .await
+            .map_err(|_| format!("Message {} has already been generated", message_id))
+    }
+}
+
+impl Drop for Generation<'_> {
+    fn drop(&mut self) {
+        self.generations
+            .generations
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.message_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ollama_rs::generation::chat::MessageRole;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_cancels_generation() {
+        let generations = Arc::new(ChatGenerations::default());
+        let mut generation = generations.register("message").unwrap();
+
+        let cancel = tokio::spawn({
+            let generations = generations.clone();
+            async move { generations.cancel("message").await }
+        });
+        let reply = (&mut generation.cancellation).await.unwrap();
+        reply
+            .send(ChatMessage::new(
+                MessageRole::Assistant,
+                "partial".to_string(),
+            ))
+            .unwrap();
+
+        assert_eq!(cancel.await.unwrap().unwrap().content, "partial");
+    }
+
+    #[tokio::test]
+    async fn test_given_unknown_message_returns_error() {
+        let generations = ChatGenerations::default();
+
+        assert!(generations.cancel("message").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unregisters_finished_generation() {
+        let generations = ChatGenerations::default();
+
+        let generation = generations.register("message").unwrap();
+        assert!(generations.register("message").is_err());
+        drop(generation);
+
+        assert!(generations.cancel("message").await.is_err());
+        assert!(generations.register("message").is_ok());
+    }
+}
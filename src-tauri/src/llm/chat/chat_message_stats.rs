@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+
+const NANOSECONDS_PER_SECOND: f64 = 1e9;
+
+/// The counts and durations Ollama sends with the last chunk of a chat response. Durations
+/// are in nanoseconds.
+#[derive(Debug, Deserialize, Serialize, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct ChatResponseMetrics {
+    pub total_duration: u64,
+    pub load_duration: u64,
+    pub prompt_eval_count: u64,
+    pub prompt_eval_duration: u64,
+    pub eval_count: u64,
+    pub eval_duration: u64,
+}
+
+/// The statistics of a generated chat response, from the last chunk of the response stream.
+/// Durations are in nanoseconds.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ChatMessageStats {
+    /// Number of tokens in the response
+    pub eval_count: u64,
+    /// Number of tokens in the prompt
+    pub prompt_eval_count: u64,
+    pub total_duration: u64,
+    /// Time spent loading the model
+    pub load_duration: u64,
+    pub prompt_eval_duration: u64,
+    pub eval_duration: u64,
+    /// Tokens generated per second while generating the response
+    pub tokens_per_second: f64,
+}
+
+impl From<ChatResponseMetrics> for ChatMessageStats {
+    fn from(metrics: ChatResponseMetrics) -> Self {
+        let tokens_per_second = if metrics.eval_duration == 0 {
+            0.0
+        } else {
+            metrics.eval_count as f64 * NANOSECONDS_PER_SECOND / metrics.eval_duration as f64
+        };
+
+        ChatMessageStats {
+            eval_count: metrics.eval_count,
+            prompt_eval_count: metrics.prompt_eval_count,
+            total_duration: metrics.total_duration,
+            load_duration: metrics.load_duration,
+            prompt_eval_duration: metrics.prompt_eval_duration,
+            eval_duration: metrics.eval_duration,
+            tokens_per_second,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_response_metrics() {
+        let metrics = ChatResponseMetrics {
+            total_duration: 5_000_000_000,
+            load_duration: 300_000_000,
+            prompt_eval_count: 26,
+            prompt_eval_duration: 500_000_000,
+            eval_count: 100,
+            eval_duration: 4_000_000_000,
+        };
+
+        assert_eq!(
+            ChatMessageStats::from(metrics),
+            ChatMessageStats {
+                eval_count: 100,
+                prompt_eval_count: 26,
+                total_duration: 5_000_000_000,
+                load_duration: 300_000_000,
+                prompt_eval_duration: 500_000_000,
+                eval_duration: 4_000_000_000,
+                tokens_per_second: 25.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_given_no_eval_duration_has_no_tokens_per_second() {
+        let metrics = ChatResponseMetrics {
+            eval_count: 0,
+            eval_duration: 0,
+            ..ChatResponseMetrics::default()
+        };
+
+        let stats = ChatMessageStats::from(metrics);
+
+        assert_eq!(stats.tokens_per_second, 0.0);
+    }
+}
@@ -0,0 +1,257 @@
+use crate::llm::chat::ChatResponseMetrics;
+use crate::llm::connection::ConnectionSettings;
+use ollama_rs::generation::chat::ChatMessage;
+use serde::Deserialize;
+use serde_json::json;
+
+/// A line of the newline-delimited JSON Ollama streams a chat response as
+#[derive(Debug, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum ChatStreamLine {
+    /// Ollama failed to generate the rest of the response
+    Error { error: String },
+    Chunk {
+        message: Option<ChatMessageChunk>,
+        #[serde(default)]
+        done: bool,
+        /// Only sent with the last chunk, when `done` is true
+        #[serde(flatten)]
+        metrics: ChatResponseMetrics,
+    },
+}
+
+/// The part of the response message sent in a chunk
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct ChatMessageChunk {
+    pub content: String,
+}
+
+#[derive(Deserialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Splits bytes received in chunks into lines, as a line can be split across chunks and a
+/// chunk can hold several lines
+#[derive(Debug, Default)]
+pub struct LineBuffer {
+    buffer: Vec<u8>,
+}
+
+impl LineBuffer {
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Takes the next complete line, skipping blank lines
+    pub fn next_line(&mut self) -> Option<Vec<u8>> {
+        while let Some(position) = self.buffer.iter().position(|byte| *byte == b'\n') {
+            let line = self.buffer.drain(..=position).collect::<Vec<u8>>();
+            if !line.trim_ascii().is_empty() {
+                return Some(line);
+            }
+        }
+
+        None
+    }
+
+    /// Takes what is left once no more bytes are coming, as the last line may not end with a
+    /// newline
+    pub fn finish(&mut self) -> Option<Vec<u8>> {
+        let rest = std::mem::take(&mut self.buffer);
+
+        (!rest.trim_ascii().is_empty()).then_some(rest)
+    }
+}
+
+/// A chat response streamed from Ollama's `/api/chat` endpoint
+pub struct ChatStream {
+    response: reqwest::Response,
+    lines: LineBuffer,
+    finished: bool,
+}
+
+impl ChatStream {
+    /// Asks Ollama for a chat response, waiting until it starts streaming
+    ///
+    /// # Arguments
+    /// * `settings` - How to connect to Ollama
+    /// * `model_name` - Model name with its size (e.g. "llama2:7b")
+    /// * `messages` - The chat messages to respond to
+    ///
+    /// # Returns
+    /// * `Ok(ChatStream)` - The response stream
+    /// * `Err(String)` - An error message if Ollama could not be reached or refused the request
+    ///
+    /// ```
+    pub async fn open(
+        settings: &ConnectionSettings,
+        model_name: &str,
+        messages: &[ChatMessage],
+    ) -> Result<Self, String> {
+        let url = settings
+            .url()?
+            .join("api/chat")
+            .map_err(|e| format!("Invalid host: {}", e))?;
+        let http_client = reqwest::Client::builder()
+            .default_headers(settings.headers()?)
+            .build()
+            .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+        let response = http_client
+            .post(url)
+            .json(&json!({ "model": model_name, "messages": messages, "stream": true }))
+            .send()
+            .await
+            .map_err(|e| format!("Failed to reach Ollama: {}", e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            let error = serde_json::from_str::<ErrorResponse>(&body)
+                .map(|response| response.error)
+                .unwrap_or_else(|_| status.to_string());
+            return Err(format!("Ollama responded with an error: {}", error));
+        }
+
+        Ok(ChatStream {
+            response,
+            lines: LineBuffer::default(),
+            finished: false,
+        })
+    }
+
+    /// Reads the next line of the response
+    ///
+    /// # Returns
+    /// * `Ok(Some(ChatStreamLine))` - The next chunk or error sent by Ollama
+    /// * `Ok(None)` - If the response has ended
+    /// * `Err(String)` - An error message if the response could not be read
+    ///
+    /// ```
+    pub async fn next(&mut self) -> Result<Option<ChatStreamLine>, String> {
+        loop {
+            if let Some(line) = self.lines.next_line() {
+                return parse_line(&line).map(Some);
+            }
+            if self.finished {
+                return self
+                    .lines
+                    .finish()
+                    .map(|line| parse_line(&line))
+                    .transpose();
+            }
+
+            match self.response.chunk().await {
+                Ok(Some(bytes)) => self.lines.push(&bytes),
+                Ok(None) => self.finished = true,
+                Err(e) => return Err(format!("Failed to read the response from Ollama: {}", e)),
+            }
+        }
+    }
+}
+
+fn parse_line(line: &[u8]) -> Result<ChatStreamLine, String> {
+    serde_json::from_slice(line)
+        .map_err(|e| format!("Failed to parse the response from Ollama: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_lines(chunks: &[&str]) -> Vec<ChatStreamLine> {
+        let mut lines = LineBuffer::default();
+        let mut parsed = Vec::new();
+        for chunk in chunks {
+            lines.push(chunk.as_bytes());
+            while let Some(line) = lines.next_line() {
+                parsed.push(parse_line(&line).unwrap());
+            }
+        }
+        if let Some(line) = lines.finish() {
+            parsed.push(parse_line(&line).unwrap());
+        }
+
+        parsed
+    }
+
+    fn chunk(content: &str) -> ChatStreamLine {
+        ChatStreamLine::Chunk {
+            message: Some(ChatMessageChunk {
+                content: content.to_string(),
+            }),
+            done: false,
+            metrics: ChatResponseMetrics::default(),
+        }
+    }
+
+    #[test]
+    fn test_given_lines_split_across_chunks_joins_them() {
+        let lines = read_lines(&[
+            r#"{"message":{"role":"assistant","#,
+            r#""content":"Hel"},"done":false}"#,
+            "\n",
+            r#"{"message":{"role":"assistant","content":"lo"},"done":false}"#,
+        ]);
+
+        assert_eq!(lines, vec![chunk("Hel"), chunk("lo")]);
+    }
+
+    #[test]
+    fn test_given_lines_merged_in_a_chunk_splits_them() {
+        let lines = read_lines(&[concat!(
+            r#"{"message":{"role":"assistant","content":"Hel"},"done":false}"#,
+            "\n\n",
+            r#"{"message":{"role":"assistant","content":"lo"},"done":false}"#,
+            "\n",
+        )]);
+
+        assert_eq!(lines, vec![chunk("Hel"), chunk("lo")]);
+    }
+
+    #[test]
+    fn test_parses_metrics_of_last_chunk() {
+        let line = parse_line(
+            br#"{"model":"llama2:7b","message":{"role":"assistant","content":""},"done":true,
+            "total_duration":5000000000,"load_duration":300000000,"prompt_eval_count":26,
+            "prompt_eval_duration":500000000,"eval_count":100,"eval_duration":4000000000}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            line,
+            ChatStreamLine::Chunk {
+                message: Some(ChatMessageChunk {
+                    content: String::new(),
+                }),
+                done: true,
+                metrics: ChatResponseMetrics {
+                    total_duration: 5_000_000_000,
+                    load_duration: 300_000_000,
+                    prompt_eval_count: 26,
+                    prompt_eval_duration: 500_000_000,
+                    eval_count: 100,
+                    eval_duration: 4_000_000_000,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn test_parses_errors() {
+        let line = parse_line(br#"{"error":"model requires more system memory"}"#).unwrap();
+
+        assert_eq!(
+            line,
+            ChatStreamLine::Error {
+                error: "model requires more system memory".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_given_invalid_line_returns_error() {
+        assert!(parse_line(b"not json").is_err());
+    }
+}
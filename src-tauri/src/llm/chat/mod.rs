@@ -1,3 +1,11 @@
+pub mod cancel_message;
+pub mod chat_generations;
+pub mod chat_message_stats;
+pub mod chat_stream;
 pub mod send_message;
 
+pub use cancel_message::*;
+pub use chat_generations::*;
+pub use chat_message_stats::*;
+pub use chat_stream::*;
 pub use send_message::*;
@@ -1,14 +1,17 @@
-use ollama_rs::{
-    generation::chat::{request::ChatMessageRequest, ChatMessage, MessageRole},
-    Ollama,
+use crate::llm::chat::{
+    CancelReply, ChatGenerations, ChatMessageStats, ChatStream, ChatStreamLine,
 };
-use tauri::{AppHandle, Emitter};
-use tokio_stream::StreamExt;
+use crate::llm::connection::OllamaClient;
+use ollama_rs::generation::chat::{ChatMessage, MessageRole};
+use tauri::{AppHandle, Emitter, State};
 
-/// Streams a chat response from Ollama back to the frontend
+/// Streams a chat response from Ollama back to the frontend. The generation can be stopped
+/// with `cancel_message`, in which case the response generated so far is returned.
 ///
 /// # Arguments
 /// * `app_handle` - Tauri app handle for emitting events
+/// * `client` - The managed Ollama client
+/// * `generations` - The managed chat responses being generated
 /// * `model_id` - Model name (e.g. "llama2")
 /// * `parameter_size` - Model size (e.g. "7b")
 /// * `message_id` - Unique ID for the message
@@ -16,6 +19,9 @@ use tokio_stream::StreamExt;
 ///
 /// # Events Emitted
 /// * "chat-message-chunk" with (message_id, chunk)
+/// * "chat-message-cancelled" with (message_id, content generated so far)
+/// * "chat-message-done" with (message_id, stats) once the response is complete
+/// * "chat-message-error" with (message_id, error) if the response could not be generated
 ///
 /// # Returns
 /// * `Result<ChatMessage, String>` - Complete response or error
@@ -24,30 +30,89 @@ use tokio_stream::StreamExt;
 #[tauri::command]
 pub async fn send_message(
     app_handle: AppHandle,
+    client: State<'_, OllamaClient>,
+    generations: State<'_, ChatGenerations>,
     model_id: String,
     parameter_size: String,
     message_id: String,
     messages: Vec<ChatMessage>,
 ) -> Result<ChatMessage, String> {
     let model_name = format!("{}:{}", model_id, parameter_size);
-    let request = ChatMessageRequest::new(model_name, messages);
-    let ollama = Ollama::default();
-
-    let mut stream = ollama
-        .send_chat_messages_stream(request)
-        .await
-        .map_err(|e| e.to_string())?;
+    let settings = client.settings();
+    let mut generation = generations.register(&message_id)?;
 
     let mut response = ChatMessage::new(MessageRole::Assistant, String::new());
 
-    while let Some(Ok(chunk)) = stream.next().await {
-        if let Some(message_chunk) = chunk.message {
-            app_handle
-                .emit("chat-message-chunk", (&message_id, &message_chunk.content))
-                .map_err(|e| e.to_string())?;
-            response.content += &message_chunk.content;
+    // Loading the model can take a while before the response starts streaming
+    let mut stream = tokio::select! {
+        biased;
+        Ok(reply) = &mut generation.cancellation => {
+            return cancel(&app_handle, &message_id, reply, response);
+        }
+        stream = ChatStream::open(&settings, &model_name, &messages) => {
+            stream.map_err(|e| emit_error(&app_handle, &message_id, e))?
+        }
+    };
+
+    let stats = loop {
+        tokio::select! {
+            biased;
+            Ok(reply) = &mut generation.cancellation => {
+                return cancel(&app_handle, &message_id, reply, response);
+            }
+            line = stream.next() => {
+                let (message, done, metrics) = match line {
+                    Ok(Some(ChatStreamLine::Chunk { message, done, metrics })) => {
+                        (message, done, metrics)
+                    }
+                    Ok(Some(ChatStreamLine::Error { error })) => {
+                        return Err(emit_error(&app_handle, &message_id, error));
+                    }
+                    Ok(None) => {
+                        let error = "The response from Ollama ended before it was complete";
+                        return Err(emit_error(&app_handle, &message_id, error.to_string()));
+                    }
+                    Err(e) => return Err(emit_error(&app_handle, &message_id, e)),
+                };
+                if let Some(message_chunk) = message {
+                    app_handle
+                        .emit("chat-message-chunk", (&message_id, &message_chunk.content))
+                        .map_err(|e| e.to_string())?;
+                    response.content += &message_chunk.content;
+                }
+                if done {
+                    break ChatMessageStats::from(metrics);
+                }
+            }
         }
-    }
+    };
+
+    app_handle
+        .emit("chat-message-done", (&message_id, &stats))
+        .map_err(|e| e.to_string())?;
 
     Ok(response)
 }
+
+/// Tells the frontend the response was cancelled and replies with the response generated so far
+fn cancel(
+    app_handle: &AppHandle,
+    message_id: &str,
+    reply: CancelReply,
+    response: ChatMessage,
+) -> Result<ChatMessage, String> {
+    app_handle
+        .emit("chat-message-cancelled", (message_id, &response.content))
+        .map_err(|e| e.to_string())?;
+    // The cancel command may have stopped waiting, the response is returned either way
+    let _ = reply.send(response.clone());
+
+    Ok(response)
+}
+
+/// Tells the frontend a response could not be generated, returning the error to return
+fn emit_error(app_handle: &AppHandle, message_id: &str, error: String) -> String {
+    // The error is returned from the command even if the event cannot be emitted
+    let _ = app_handle.emit("chat-message-error", (message_id, &error));
+    error
+}
@@ -0,0 +1,398 @@
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use ollama_rs::{
+    headers::{HeaderMap, HeaderValue, AUTHORIZATION},
+    Ollama,
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use url::Url;
+
+/// The file in the app config directory the connection settings are persisted to
+const SETTINGS_FILE: &str = "ollama_connection.json";
+
+/// The file next to the settings file the credentials are persisted to, readable only by the
+/// current user
+const CREDENTIALS_FILE: &str = "ollama_credentials.json";
+
+/// The credentials sent in the `Authorization` header, for Ollama servers behind a proxy
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub enum ConnectionAuth {
+    Bearer { token: String },
+    Basic { username: String, password: String },
+}
+
+/// The credentials as shown to the user, without the token or password
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub enum MaskedConnectionAuth {
+    Bearer {},
+    Basic { username: String },
+}
+
+/// The connection settings as shown to the user, without the token or password
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct MaskedConnectionSettings {
+    pub host: String,
+    pub port: u16,
+    pub tls: bool,
+    pub auth: Option<MaskedConnectionAuth>,
+}
+
+/// How to reach the Ollama server, defaulting to a local server on the default port
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ConnectionSettings {
+    /// The host name or IP address, without a scheme
+    pub host: String,
+    pub port: u16,
+    /// Whether to connect with HTTPS rather than HTTP
+    pub tls: bool,
+    pub auth: Option<ConnectionAuth>,
+}
+
+impl Default for ConnectionSettings {
+    fn default() -> Self {
+        ConnectionSettings {
+            host: "127.0.0.1".to_string(),
+            port: 11434,
+            tls: false,
+            auth: None,
+        }
+    }
+}
+
+impl ConnectionSettings {
+    /// Gets the base URL of the Ollama server
+    pub fn url(&self) -> Result<Url, String> {
+        let host = self.host.trim();
+        if host.is_empty() {
+            return Err("Host cannot be empty".to_string());
+        }
+        if host.contains("://") {
+            return Err("Host must not include a scheme, use the TLS setting instead".to_string());
+        }
+
+        let scheme = if self.tls { "https" } else { "http" };
+        let mut url = Url::parse(&format!("{}://{}", scheme, host))
+            .map_err(|e| format!("Invalid host: {}", e))?;
+        if url.path() != "/" || url.query().is_some() || !url.username().is_empty() {
+            return Err(format!("Invalid host: {}", host));
+        }
+        url.set_port(Some(self.port))
+            .map_err(|_| format!("Invalid port: {}", self.port))?;
+
+        Ok(url)
+    }
+
+    /// Gets the headers sent with every request, which hold the credentials if any
+    pub fn headers(&self) -> Result<HeaderMap, String> {
+        let mut headers = HeaderMap::new();
+
+        let authorization = match &self.auth {
+            None => return Ok(headers),
+            Some(ConnectionAuth::Bearer { token }) => format!("Bearer {}", token.trim()),
+            Some(ConnectionAuth::Basic { username, password }) => {
+                let credentials = STANDARD.encode(format!("{}:{}", username, password));
+                format!("Basic {}", credentials)
+            }
+        };
+        let mut value = HeaderValue::from_str(&authorization)
+            .map_err(|e| format!("Invalid credentials: {}", e))?;
+        value.set_sensitive(true);
+        headers.insert(AUTHORIZATION, value);
+
+        Ok(headers)
+    }
+
+    /// Creates an Ollama client that connects with these settings
+    pub fn client(&self) -> Result<Ollama, String> {
+        let mut ollama = Ollama::from_url(self.url()?);
+        ollama.set_headers(Some(self.headers()?));
+
+        Ok(ollama)
+    }
+
+    /// Gets the settings without the token or password, to show them to the user
+    pub fn masked(&self) -> MaskedConnectionSettings {
+        let auth = self.auth.as_ref().map(|auth| match auth {
+            ConnectionAuth::Bearer { .. } => MaskedConnectionAuth::Bearer {},
+            ConnectionAuth::Basic { username, .. } => MaskedConnectionAuth::Basic {
+                username: username.clone(),
+            },
+        });
+
+        MaskedConnectionSettings {
+            host: self.host.clone(),
+            port: self.port,
+            tls: self.tls,
+            auth,
+        }
+    }
+
+    /// Gets the path the settings are persisted to
+    pub fn path(handle: &AppHandle) -> Result<PathBuf, String> {
+        let config_dir = handle
+            .path()
+            .app_config_dir()
+            .map_err(|e| format!("Failed to resolve config directory: {}", e))?;
+
+        Ok(config_dir.join(SETTINGS_FILE))
+    }
+
+    /// Loads the settings from a file and the credentials from the file next to it, or the
+    /// default settings if they were never saved
+    pub fn load(path: &Path) -> Result<ConnectionSettings, String> {
+        if !path.exists() {
+            return Ok(ConnectionSettings::default());
+        }
+
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read connection settings: {}", e))?;
+        let mut settings: ConnectionSettings = serde_json::from_str(&json)
+            .map_err(|e| format!("Failed to parse connection settings: {}", e))?;
+
+        let credentials_path = path.with_file_name(CREDENTIALS_FILE);
+        if settings.auth.is_some() {
+            // Earlier versions saved the credentials with the rest of the settings
+            settings.save(path)?;
+        } else if credentials_path.exists() {
+            let json = fs::read_to_string(&credentials_path)
+                .map_err(|e| format!("Failed to read connection credentials: {}", e))?;
+            settings.auth = serde_json::from_str(&json)
+                .map_err(|e| format!("Failed to parse connection credentials: {}", e))?;
+        }
+
+        Ok(settings)
+    }
+
+    /// Saves the settings to a file and the credentials, if any, to a file next to it that
+    /// only the current user can read
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to create config directory: {}", e))?;
+        }
+
+        // The credentials are saved first, so they are never lost when moving them out of the
+        // settings file
+        let credentials_path = path.with_file_name(CREDENTIALS_FILE);
+        match &self.auth {
+            Some(auth) => {
+                let json = serde_json::to_string_pretty(auth)
+                    .map_err(|e| format!("Failed to serialize connection credentials: {}", e))?;
+                write_private(&credentials_path, &json)
+                    .map_err(|e| format!("Failed to save connection credentials: {}", e))?;
+            }
+            None if credentials_path.exists() => fs::remove_file(&credentials_path)
+                .map_err(|e| format!("Failed to remove connection credentials: {}", e))?,
+            None => {}
+        }
+
+        let settings = ConnectionSettings {
+            auth: None,
+            ..self.clone()
+        };
+        let json = serde_json::to_string_pretty(&settings)
+            .map_err(|e| format!("Failed to serialize connection settings: {}", e))?;
+        fs::write(path, json).map_err(|e| format!("Failed to save connection settings: {}", e))
+    }
+}
+
+/// Writes a file only the current user can read and write
+fn write_private(path: &Path, contents: &str) -> std::io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let mut file = options.open(path)?;
+    // The mode only applies to new files
+    #[cfg(unix)]
+    file.set_permissions(std::os::unix::fs::PermissionsExt::from_mode(0o600))?;
+
+    file.write_all(contents.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn settings(host: &str, port: u16, tls: bool) -> ConnectionSettings {
+        ConnectionSettings {
+            host: host.to_string(),
+            port,
+            tls,
+            auth: None,
+        }
+    }
+
+    #[test]
+    fn test_builds_url() {
+        assert_eq!(
+            ConnectionSettings::default().url().unwrap().as_str(),
+            "http://127.0.0.1:11434/"
+        );
+        assert_eq!(
+            settings("ollama.example.com", 443, true)
+                .url()
+                .unwrap()
+                .as_str(),
+            "https://ollama.example.com/"
+        );
+        assert_eq!(
+            settings("[::1]", 8080, false).url().unwrap().as_str(),
+            "http://[::1]:8080/"
+        );
+    }
+
+    #[test]
+    fn test_given_invalid_host_returns_error() {
+        for host in [
+            "",
+            "http://localhost",
+            "localhost/api",
+            "user@localhost",
+            "a b",
+        ] {
+            assert!(settings(host, 11434, false).url().is_err(), "{}", host);
+        }
+    }
+
+    #[test]
+    fn test_builds_authorization_header() {
+        let mut settings = ConnectionSettings::default();
+        assert!(settings.headers().unwrap().is_empty());
+
+        settings.auth = Some(ConnectionAuth::Bearer {
+            token: "secret".to_string(),
+        });
+        assert_eq!(settings.headers().unwrap()[AUTHORIZATION], "Bearer secret");
+
+        settings.auth = Some(ConnectionAuth::Basic {
+            username: "Aladdin".to_string(),
+            password: "open sesame".to_string(),
+        });
+        assert_eq!(
+            settings.headers().unwrap()[AUTHORIZATION],
+            "Basic QWxhZGRpbjpvcGVuIHNlc2FtZQ=="
+        );
+
+        settings.auth = Some(ConnectionAuth::Bearer {
+            token: "line\nbreak".to_string(),
+        });
+        assert!(settings.headers().is_err());
+    }
+
+    #[test]
+    fn test_saves_and_loads_settings() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("config").join(SETTINGS_FILE);
+        assert_eq!(
+            ConnectionSettings::load(&path).unwrap(),
+            ConnectionSettings::default()
+        );
+
+        let settings = ConnectionSettings {
+            host: "ollama.example.com".to_string(),
+            port: 443,
+            tls: true,
+            auth: Some(ConnectionAuth::Basic {
+                username: "user".to_string(),
+                password: "pass".to_string(),
+            }),
+        };
+        settings.save(&path).unwrap();
+
+        assert_eq!(ConnectionSettings::load(&path).unwrap(), settings);
+
+        let settings = ConnectionSettings {
+            auth: None,
+            ..settings
+        };
+        settings.save(&path).unwrap();
+
+        assert_eq!(ConnectionSettings::load(&path).unwrap(), settings);
+        assert!(!path.with_file_name(CREDENTIALS_FILE).exists());
+    }
+
+    #[test]
+    fn test_saves_credentials_apart_from_settings() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join(SETTINGS_FILE);
+        let settings = ConnectionSettings {
+            auth: Some(ConnectionAuth::Bearer {
+                token: "secret".to_string(),
+            }),
+            ..ConnectionSettings::default()
+        };
+
+        settings.save(&path).unwrap();
+
+        assert!(!fs::read_to_string(&path).unwrap().contains("secret"));
+        let credentials_path = path.with_file_name(CREDENTIALS_FILE);
+        assert!(fs::read_to_string(&credentials_path)
+            .unwrap()
+            .contains("secret"));
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&credentials_path)
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn test_moves_credentials_out_of_settings_saved_by_earlier_versions() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join(SETTINGS_FILE);
+        fs::write(
+            &path,
+            r#"{"host":"127.0.0.1","port":11434,"tls":false,"auth":{"Bearer":{"token":"secret"}}}"#,
+        )
+        .unwrap();
+
+        let settings = ConnectionSettings::load(&path).unwrap();
+
+        assert_eq!(
+            settings.auth,
+            Some(ConnectionAuth::Bearer {
+                token: "secret".to_string()
+            })
+        );
+        assert!(!fs::read_to_string(&path).unwrap().contains("secret"));
+        assert_eq!(ConnectionSettings::load(&path).unwrap(), settings);
+    }
+
+    #[test]
+    fn test_masks_credentials() {
+        let mut settings = ConnectionSettings {
+            auth: Some(ConnectionAuth::Basic {
+                username: "user".to_string(),
+                password: "pass".to_string(),
+            }),
+            ..ConnectionSettings::default()
+        };
+        assert_eq!(
+            settings.masked().auth,
+            Some(MaskedConnectionAuth::Basic {
+                username: "user".to_string()
+            })
+        );
+
+        settings.auth = Some(ConnectionAuth::Bearer {
+            token: "secret".to_string(),
+        });
+        assert_eq!(
+            settings.masked().auth,
+            Some(MaskedConnectionAuth::Bearer {})
+        );
+        assert!(!serde_json::to_string(&settings.masked())
+            .unwrap()
+            .contains("secret"));
+    }
+}
@@ -0,0 +1,19 @@
+use crate::llm::connection::{MaskedConnectionSettings, OllamaClient};
+use tauri::State;
+
+/// Gets the settings used to connect to Ollama, without the token or password so they never
+/// reach the webview
+///
+/// # Arguments
+/// * `client` - The managed Ollama client
+///
+/// # Returns
+/// * `MaskedConnectionSettings` - The host, port, TLS toggle and kind of credentials in use,
+///   with the username for basic authentication
+///
+/// ```
+
+#[tauri::command]
+pub fn get_connection_settings(client: State<'_, OllamaClient>) -> MaskedConnectionSettings {
+    client.settings().masked()
+}
@@ -0,0 +1,11 @@
+pub mod connection_settings;
+pub mod get_connection_settings;
+pub mod ollama_client;
+pub mod set_connection_settings;
+pub mod test_connection;
+
+pub use connection_settings::*;
+pub use get_connection_settings::*;
+pub use ollama_client::*;
+pub use set_connection_settings::*;
+pub use test_connection::*;
@@ -0,0 +1,98 @@
+use crate::llm::connection::ConnectionSettings;
+use ollama_rs::Ollama;
+use std::sync::{PoisonError, RwLock};
+
+struct Connection {
+    settings: ConnectionSettings,
+    ollama: Ollama,
+}
+
+/// The Ollama client shared by every LLM command, managed in Tauri state so a change to the
+/// connection settings applies to all of them
+pub struct OllamaClient {
+    connection: RwLock<Connection>,
+}
+
+impl OllamaClient {
+    /// Creates a client with the given settings, falling back to the default settings if they
+    /// are invalid
+    pub fn new(settings: ConnectionSettings) -> Self {
+        let connection = match settings.client() {
+            Ok(ollama) => Connection { settings, ollama },
+            Err(_) => Connection {
+                settings: ConnectionSettings::default(),
+                ollama: Ollama::default(),
+            },
+        };
+
+        OllamaClient {
+            connection: RwLock::new(connection),
+        }
+    }
+
+    /// Gets the client to send requests with
+    pub fn ollama(&self) -> Ollama {
+        let connection = self
+            .connection
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        connection.ollama.clone()
+    }
+
+    pub fn settings(&self) -> ConnectionSettings {
+        let connection = self
+            .connection
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        connection.settings.clone()
+    }
+
+    /// Replaces the settings and the client, keeping the current ones if the settings are invalid
+    pub fn update(&self, settings: ConnectionSettings) -> Result<(), String> {
+        let ollama = settings.client()?;
+
+        let mut connection = self
+            .connection
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+        *connection = Connection { settings, ollama };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_updates_client() {
+        let client = OllamaClient::new(ConnectionSettings::default());
+        let settings = ConnectionSettings {
+            host: "ollama.example.com".to_string(),
+            port: 443,
+            tls: true,
+            auth: None,
+        };
+
+        client.update(settings.clone()).unwrap();
+
+        assert_eq!(client.settings(), settings);
+        assert_eq!(client.ollama().url_str(), "https://ollama.example.com/");
+    }
+
+    #[test]
+    fn test_given_invalid_settings_keeps_client() {
+        let client = OllamaClient::new(ConnectionSettings::default());
+        let settings = ConnectionSettings {
+            host: String::new(),
+            ..ConnectionSettings::default()
+        };
+
+        assert!(client.update(settings.clone()).is_err());
+        assert_eq!(client.settings(), ConnectionSettings::default());
+
+        let client = OllamaClient::new(settings);
+        assert_eq!(client.settings(), ConnectionSettings::default());
+    }
+}
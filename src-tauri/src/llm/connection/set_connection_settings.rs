@@ -0,0 +1,30 @@
+use crate::llm::connection::{ConnectionSettings, OllamaClient};
+use tauri::State;
+
+/// Changes how to connect to Ollama. The settings are persisted in the app config directory
+/// and used by every LLM command from then on. The credentials are kept apart from the other
+/// settings, in a file only the current user can read.
+///
+/// # Arguments
+/// * `handle` - Tauri app handle used to resolve the config directory
+/// * `client` - The managed Ollama client
+/// * `settings` - The host, port, TLS toggle and optional credentials
+///
+/// # Returns
+/// * `Ok(())` if the settings were saved and applied
+/// * `Err(String)` with error message if the settings are invalid or could not be saved
+///
+/// ```
+
+#[tauri::command]
+pub fn set_connection_settings(
+    handle: tauri::AppHandle,
+    client: State<'_, OllamaClient>,
+    settings: ConnectionSettings,
+) -> Result<(), String> {
+    // Validate before saving, so invalid settings are never persisted
+    settings.client()?;
+    settings.save(&ConnectionSettings::path(&handle)?)?;
+
+    client.update(settings)
+}
@@ -0,0 +1,159 @@
+use crate::llm::connection::{ConnectionSettings, OllamaClient};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tauri::State;
+
+/// How long to wait for Ollama to respond before reporting it unreachable
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Deserialize, Serialize, PartialEq)]
+pub struct ConnectionStatus {
+    pub reachable: bool,
+    /// The version of Ollama, if it was reachable
+    pub version: Option<String>,
+    /// Why Ollama was not reachable
+    pub error: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct VersionResponse {
+    version: String,
+}
+
+/// Checks whether Ollama can be reached, by asking it for its version
+///
+/// # Arguments
+/// * `client` - The managed Ollama client
+/// * `settings` - The settings to test, or the settings in use if not given, so settings can
+///   be tested before they are saved
+///
+/// # Returns
+/// * `Ok(ConnectionStatus)` - Whether Ollama was reachable with its version, or why not
+/// * `Err(String)` - An error message if the settings are invalid
+///
+/// ```
+
+#[tauri::command]
+pub async fn test_connection(
+    client: State<'_, OllamaClient>,
+    settings: Option<ConnectionSettings>,
+) -> Result<ConnectionStatus, String> {
+    let settings = settings.unwrap_or_else(|| client.settings());
+
+    check_connection(&settings).await
+}
+
+pub async fn check_connection(settings: &ConnectionSettings) -> Result<ConnectionStatus, String> {
+    let url = settings
+        .url()?
+        .join("api/version")
+        .map_err(|e| format!("Invalid host: {}", e))?;
+    let http_client = reqwest::Client::builder()
+        .timeout(TIMEOUT)
+        .default_headers(settings.headers()?)
+        .build()
+        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
+
+    let version = match http_client.get(url).send().await {
+        Ok(response) => match response.error_for_status() {
+            Ok(response) => response
+                .json::<VersionResponse>()
+                .await
+                .map(|response| response.version)
+                .map_err(|e| format!("Unexpected response from Ollama: {}", e)),
+            Err(e) => Err(format!("Ollama responded with an error: {}", e)),
+        },
+        Err(e) => Err(format!("Failed to reach Ollama: {}", e)),
+    };
+
+    Ok(match version {
+        Ok(version) => ConnectionStatus {
+            reachable: true,
+            version: Some(version),
+            error: None,
+        },
+        Err(error) => ConnectionStatus {
+            reachable: false,
+            version: None,
+            error: Some(error),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::llm::connection::ConnectionAuth;
+    use std::net::TcpListener;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[tokio::test]
+    async fn test_reports_version() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = vec![0; 4096];
+            let length = socket.read(&mut request).await.unwrap();
+            let body = r#"{"version":"0.5.7"}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8_lossy(&request[..length]).to_string()
+        });
+        let settings = ConnectionSettings {
+            port,
+            auth: Some(ConnectionAuth::Bearer {
+                token: "secret".to_string(),
+            }),
+            ..ConnectionSettings::default()
+        };
+
+        let status = check_connection(&settings).await.unwrap();
+        let request = server.await.unwrap();
+
+        assert_eq!(
+            status,
+            ConnectionStatus {
+                reachable: true,
+                version: Some("0.5.7".to_string()),
+                error: None,
+            }
+        );
+        assert!(request.starts_with("GET /api/version "));
+        assert!(request.contains("authorization: Bearer secret"));
+    }
+
+    #[tokio::test]
+    async fn test_given_unreachable_server_reports_error() {
+        // Bind and drop a listener to find a port nothing listens on
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let settings = ConnectionSettings {
+            port,
+            ..ConnectionSettings::default()
+        };
+
+        let status = check_connection(&settings).await.unwrap();
+
+        assert!(!status.reachable);
+        assert!(status.version.is_none());
+        assert!(status.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_given_invalid_settings_returns_error() {
+        let settings = ConnectionSettings {
+            host: String::new(),
+            ..ConnectionSettings::default()
+        };
+
+        assert!(check_connection(&settings).await.is_err());
+    }
+}
@@ -1,8 +1,10 @@
-use ollama_rs::Ollama;
+use crate::llm::connection::OllamaClient;
+use tauri::State;
 
 /// Deletes a model from Ollama
 ///
 /// # Arguments
+/// * `client` - The managed Ollama client
 /// * `model_id` - ID of the model (e.g. "llama3.1", "gemma2")
 /// * `parameter_size` - Size of the model variant (e.g. "70b", "2b")
 ///
@@ -13,8 +15,12 @@ use ollama_rs::Ollama;
 /// ```
 
 #[tauri::command]
-pub async fn delete_model(model_id: String, parameter_size: String) -> Result<(), String> {
-    let ollama = Ollama::default();
+pub async fn delete_model(
+    client: State<'_, OllamaClient>,
+    model_id: String,
+    parameter_size: String,
+) -> Result<(), String> {
+    let ollama = client.ollama();
 
     ollama
         .delete_model(format!("{}:{}", model_id, parameter_size))
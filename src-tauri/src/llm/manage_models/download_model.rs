@@ -1,5 +1,5 @@
-use ollama_rs::Ollama;
-use tauri::Emitter;
+use crate::llm::connection::OllamaClient;
+use tauri::{Emitter, State};
 use tokio::time::{Duration, Instant};
 use tokio_stream::StreamExt;
 
@@ -7,6 +7,7 @@ use tokio_stream::StreamExt;
 ///
 /// # Arguments
 /// * `handle` - Tauri app handle for emitting events
+/// * `client` - The managed Ollama client
 /// * `model_id` - ID of the model (e.g. "llama3.1", "gemma2")
 /// * `parameter_size` - Size of the model variant (e.g. "70b", "2b")
 ///
@@ -25,10 +26,11 @@ use tokio_stream::StreamExt;
 #[tauri::command]
 pub async fn download_model(
     handle: tauri::AppHandle,
+    client: State<'_, OllamaClient>,
     model_id: String,
     parameter_size: String,
 ) -> Result<(), String> {
-    let ollama = Ollama::default();
+    let ollama = client.ollama();
     let model_name = format!("{}:{}", model_id, parameter_size);
 
     let mut stream = ollama
@@ -1,7 +1,7 @@
+use crate::llm::connection::OllamaClient;
 use crate::llm::manage_models::Model;
-use ollama_rs::Ollama;
 use tauri::path::BaseDirectory;
-use tauri::Manager;
+use tauri::{Manager, State};
 
 /// Lists all available LLM models that can be downloaded and used through Ollama.
 /// The list of models is maintained in a JSON file and includes popular open models like
@@ -16,6 +16,7 @@ use tauri::Manager;
 ///
 /// # Arguments
 /// * `handle` - Tauri app handle used to resolve resource paths
+/// * `client` - The managed Ollama client
 ///
 /// # Returns
 /// * `Ok(Vec<Model>)` containing the list of available models with download status
@@ -24,8 +25,11 @@ use tauri::Manager;
 /// ```
 
 #[tauri::command]
-pub async fn list_models(handle: tauri::AppHandle) -> Result<Vec<Model>, String> {
-    let ollama = Ollama::default();
+pub async fn list_models(
+    handle: tauri::AppHandle,
+    client: State<'_, OllamaClient>,
+) -> Result<Vec<Model>, String> {
+    let ollama = client.ollama();
 
     let local_models = ollama
         .list_local_models()
@@ -1,2 +1,3 @@
 pub mod chat;
+pub mod connection;
 pub mod manage_models;
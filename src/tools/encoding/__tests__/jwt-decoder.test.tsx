@@ -10,12 +10,9 @@ const mockDecodedJwt = {
     typ: 'JWT',
   },
   claims: {
-    registered: {
-      sub: '1234567890',
-    },
-    private: {
-      name: 'John Doe',
-    },
+    sub: '1234567890',
+    aud: ['a', 'b'],
+    name: 'John Doe',
   },
 };
 
@@ -8,12 +8,17 @@ import { useTranslation } from 'react-i18next';
 interface DecodedJwt {
   header: {
     alg: string;
-    typ: string;
+    typ?: string;
+    kid?: string;
+    [parameter: string]: unknown;
   };
-  claims: {
-    registered: Record<string, unknown>;
-    private: Record<string, unknown>;
+  claims: Record<string, unknown>;
+  segments: {
+    header: string;
+    claims: string;
+    signature: string;
   };
+  signature: number[];
 }
 
 export default function JwtDecoder() {